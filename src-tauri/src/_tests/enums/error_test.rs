@@ -54,6 +54,21 @@ mod tests {
         BackendError::NetworkUsageNotAvailable,
         "networkUsageNotAvailable",
       ),
+      (BackendError::DiskUsageNotAvailable, "diskUsageNotAvailable"),
+      (BackendError::CpuTimeNotAvailable, "cpuTimeNotAvailable"),
+      (
+        BackendError::PressureInfoNotAvailable,
+        "pressureInfoNotAvailable",
+      ),
+      (BackendError::CpuPowerNotAvailable, "cpuPowerNotAvailable"),
+      (
+        BackendError::CpuFrequencyNotAvailable,
+        "cpuFrequencyNotAvailable",
+      ),
+      (
+        BackendError::GpuMetricsNotAvailable,
+        "gpuMetricsNotAvailable",
+      ),
       (BackendError::UnexpectedError, "unexpectedError"),
     ];
 
@@ -73,6 +88,12 @@ mod tests {
       BackendError::GraphicInfoNotAvailable,
       BackendError::NetworkInfoNotAvailable,
       BackendError::NetworkUsageNotAvailable,
+      BackendError::DiskUsageNotAvailable,
+      BackendError::CpuTimeNotAvailable,
+      BackendError::PressureInfoNotAvailable,
+      BackendError::CpuPowerNotAvailable,
+      BackendError::CpuFrequencyNotAvailable,
+      BackendError::GpuMetricsNotAvailable,
       BackendError::UnexpectedError,
     ];
 
@@ -101,6 +122,12 @@ mod tests {
       BackendError::GraphicInfoNotAvailable,
       BackendError::NetworkInfoNotAvailable,
       BackendError::NetworkUsageNotAvailable,
+      BackendError::DiskUsageNotAvailable,
+      BackendError::CpuTimeNotAvailable,
+      BackendError::PressureInfoNotAvailable,
+      BackendError::CpuPowerNotAvailable,
+      BackendError::CpuFrequencyNotAvailable,
+      BackendError::GpuMetricsNotAvailable,
       BackendError::UnexpectedError,
     ];
 
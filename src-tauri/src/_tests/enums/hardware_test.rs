@@ -130,4 +130,24 @@ mod tests {
       assert!(serde_json::to_string(&disk_kind).is_ok());
     }
   }
+
+  #[test]
+  fn test_disk_health_status_serialization() {
+    let test_cases = vec![
+      (DiskHealthStatus::Good, "good"),
+      (DiskHealthStatus::Warning, "warning"),
+      (DiskHealthStatus::Critical, "critical"),
+      (DiskHealthStatus::Unknown, "unknown"),
+    ];
+
+    for (status, expected_json) in test_cases {
+      let serialized = serde_json::to_string(&status).unwrap();
+      assert_eq!(serialized, format!("\"{}\"", expected_json));
+    }
+  }
+
+  #[test]
+  fn test_disk_health_status_default() {
+    assert_eq!(DiskHealthStatus::default(), DiskHealthStatus::Unknown);
+  }
 }
@@ -0,0 +1,92 @@
+#[cfg(target_os = "linux")]
+#[cfg(test)]
+mod tests {
+  use crate::infrastructure::providers::linux::amdgpu::{
+    parse_dpm_clock_mhz, read_amd_gpu_metrics,
+  };
+  use crate::models::hardware::GpuMetrics;
+  use std::fs;
+  use std::path::Path;
+
+  fn write(dir: &Path, name: &str, value: &str) {
+    fs::create_dir_all(dir).unwrap();
+    fs::write(dir.join(name), value).unwrap();
+  }
+
+  #[test]
+  fn test_read_amd_gpu_metrics() {
+    let device = tempfile::tempdir().unwrap();
+    let device = device.path();
+    write(
+      device,
+      "pp_dpm_sclk",
+      "0: 500Mhz \n1: 2270Mhz *\n2: 2600Mhz \n",
+    );
+    write(
+      device,
+      "pp_dpm_mclk",
+      "0: 96Mhz \n1: 456Mhz \n2: 1000Mhz *\n",
+    );
+    write(device, "mem_info_vram_used", "2147483648\n");
+    write(device, "mem_info_vram_total", "17163091968\n");
+    write(device, "mem_info_gtt_used", "52428800\n");
+    write(device, "mem_info_gtt_total", "16803930112\n");
+    let hwmon = device.join("hwmon/hwmon4");
+    write(&hwmon, "power1_average", "187000000\n");
+    write(&hwmon, "power1_cap", "272000000\n");
+
+    assert_eq!(
+      read_amd_gpu_metrics("card1", device),
+      GpuMetrics {
+        card: "card1".to_string(),
+        core_clock_mhz: Some(2270),
+        memory_clock_mhz: Some(1000),
+        vram_used_mb: Some(2048.0),
+        vram_total_mb: Some(16368.0),
+        gtt_used_mb: Some(50.0),
+        gtt_total_mb: Some(16025.0),
+        power_watts: Some(187.0),
+        power_cap_watts: Some(272.0),
+        fan_speed_percent: None,
+      }
+    );
+  }
+
+  #[test]
+  fn test_read_amd_gpu_metrics_power_input() {
+    let device = tempfile::tempdir().unwrap();
+    // RDNA3 / APUs expose `power1_input` instead of `power1_average`
+    write(
+      &device.path().join("hwmon/hwmon2"),
+      "power1_input",
+      "15345000\n",
+    );
+
+    let metrics = read_amd_gpu_metrics("card0", device.path());
+
+    assert_eq!(metrics.power_watts, Some(15.3));
+    assert_eq!(metrics.power_cap_watts, None);
+    assert_eq!(metrics.core_clock_mhz, None);
+    assert_eq!(metrics.vram_used_mb, None);
+  }
+
+  #[test]
+  fn test_parse_dpm_clock_mhz() {
+    assert_eq!(
+      parse_dpm_clock_mhz("0: 300Mhz *\n1: 1200Mhz\n2: 2100Mhz\n"),
+      Some(300)
+    );
+    // Deep sleep level
+    assert_eq!(
+      parse_dpm_clock_mhz("S: 19Mhz *\n0: 500Mhz \n1: 2600Mhz \n"),
+      Some(19)
+    );
+    assert_eq!(parse_dpm_clock_mhz("0: 1600MHz *\n"), Some(1600));
+  }
+
+  #[test]
+  fn test_parse_dpm_clock_mhz_without_active_level() {
+    assert_eq!(parse_dpm_clock_mhz("0: 500Mhz\n1: 2600Mhz\n"), None);
+    assert_eq!(parse_dpm_clock_mhz(""), None);
+  }
+}
@@ -0,0 +1,276 @@
+#[cfg(target_os = "linux")]
+#[cfg(test)]
+mod tests {
+  use crate::enums::hardware::BlockDeviceKind;
+  use crate::infrastructure::providers::linux::block_sys::{
+    mount_points_of, parse_scheduler, read_block_devices, read_disk_temperature,
+    read_physical_disks, resolve_device_name,
+  };
+  use crate::models::hardware::MountEntry;
+  use crate::utils::formatter::SizeUnit;
+  use std::fs;
+  use std::os::unix::fs::symlink;
+  use std::path::Path;
+
+  #[test]
+  fn test_read_physical_disks() {
+    let sys_block = tempfile::tempdir().unwrap();
+    for (name, has_device) in [
+      ("nvme0n1", true),
+      ("sda", true),
+      ("sr0", true),
+      ("loop0", false),
+      ("dm-0", false),
+      ("zram0", false),
+    ] {
+      let dir = sys_block.path().join(name);
+      fs::create_dir_all(&dir).unwrap();
+      if has_device {
+        fs::create_dir(dir.join("device")).unwrap();
+      }
+    }
+
+    assert_eq!(
+      read_physical_disks(sys_block.path()),
+      vec!["nvme0n1", "sda"]
+    );
+  }
+
+  #[test]
+  fn test_read_disk_temperature() {
+    let sys_block = tempfile::tempdir().unwrap();
+    // NVMe: hwmon directly under the controller
+    let nvme = sys_block.path().join("nvme0n1/device/hwmon3");
+    fs::create_dir_all(&nvme).unwrap();
+    fs::write(nvme.join("temp1_input"), "40850\n").unwrap();
+    fs::write(nvme.join("temp1_label"), "Composite\n").unwrap();
+    // SATA with drivetemp
+    let sata = sys_block.path().join("sda/device/hwmon/hwmon4");
+    fs::create_dir_all(&sata).unwrap();
+    fs::write(sata.join("temp1_input"), "35000\n").unwrap();
+    fs::create_dir_all(sys_block.path().join("sdb/device")).unwrap();
+
+    assert_eq!(
+      read_disk_temperature(sys_block.path(), "nvme0n1"),
+      Some(40.85)
+    );
+    assert_eq!(read_disk_temperature(sys_block.path(), "sda"), Some(35.0));
+    assert_eq!(read_disk_temperature(sys_block.path(), "sdb"), None);
+  }
+
+  fn write_attributes(dir: &Path, attributes: &[(&str, &str)]) {
+    for (name, value) in attributes {
+      let path = dir.join(name);
+      fs::create_dir_all(path.parent().unwrap()).unwrap();
+      fs::write(path, format!("{value}\n")).unwrap();
+    }
+  }
+
+  ///
+  /// nvme0n1: p1 (ESP), p2 -> dm-0 (LUKS) -> dm-1 / dm-2 (LVM), p10
+  /// sda: whole disk member of md0 (RAID1)
+  ///
+  fn write_stacked_tree(root: &Path) -> (std::path::PathBuf, std::path::PathBuf) {
+    let sys_block = root.join("block");
+    let class_block = root.join("class/block");
+    fs::create_dir_all(&sys_block).unwrap();
+    fs::create_dir_all(&class_block).unwrap();
+
+    let nvme = root.join("devices/nvme0/nvme0n1");
+    let sda = root.join("devices/ata1/sda");
+    let virtual_block = root.join("devices/virtual/block");
+
+    write_attributes(
+      &nvme,
+      &[
+        ("size", "1953525168"),
+        ("device/model", "Samsung SSD 980 PRO 1TB"),
+        ("queue/rotational", "0"),
+        ("queue/scheduler", "[none] mq-deadline"),
+        ("nvme0n1p1/partition", "1"),
+        ("nvme0n1p1/size", "2097152"),
+        ("nvme0n1p2/partition", "2"),
+        ("nvme0n1p2/size", "1949327360"),
+        ("nvme0n1p10/partition", "10"),
+        ("nvme0n1p10/size", "2097152"),
+      ],
+    );
+    write_attributes(
+      &sda,
+      &[
+        ("size", "3907029168"),
+        ("device/model", "WDC WD20EZBX-00A"),
+        ("queue/rotational", "1"),
+        ("queue/scheduler", "mq-deadline kyber [bfq] none"),
+      ],
+    );
+    write_attributes(
+      &virtual_block,
+      &[
+        ("dm-0/dm/name", "cryptroot"),
+        ("dm-0/size", "1949294592"),
+        ("dm-1/dm/name", "vg0-root"),
+        ("dm-1/size", "1941159936"),
+        ("dm-2/dm/name", "vg0-swap"),
+        ("dm-2/size", "8134656"),
+        ("md0/md/level", "raid1"),
+        ("md0/size", "3906764800"),
+      ],
+    );
+    for (dir, holder) in [
+      (nvme.join("nvme0n1p2"), "dm-0"),
+      (virtual_block.join("dm-0"), "dm-1"),
+      (virtual_block.join("dm-0"), "dm-2"),
+      (sda.clone(), "md0"),
+    ] {
+      fs::create_dir_all(dir.join("holders").join(holder)).unwrap();
+    }
+
+    for disk in [&nvme, &sda] {
+      symlink(disk, sys_block.join(disk.file_name().unwrap())).unwrap();
+    }
+    for dir in [
+      nvme.clone(),
+      nvme.join("nvme0n1p1"),
+      nvme.join("nvme0n1p2"),
+      nvme.join("nvme0n1p10"),
+      sda.clone(),
+      virtual_block.join("dm-0"),
+      virtual_block.join("dm-1"),
+      virtual_block.join("dm-2"),
+      virtual_block.join("md0"),
+    ] {
+      symlink(&dir, class_block.join(dir.file_name().unwrap())).unwrap();
+    }
+
+    (sys_block, class_block)
+  }
+
+  fn mount(source: &str, mount_point: &str, file_system: &str) -> MountEntry {
+    MountEntry {
+      source: source.to_string(),
+      mount_point: mount_point.to_string(),
+      file_system: file_system.to_string(),
+    }
+  }
+
+  #[test]
+  fn test_read_block_devices() {
+    let root = tempfile::tempdir().unwrap();
+    let (sys_block, class_block) = write_stacked_tree(root.path());
+    let mounts = [
+      mount("/dev/mapper/vg0-root", "/", "ext4"),
+      mount("/dev/nvme0n1p1", "/boot/efi", "vfat"),
+      mount("/dev/md0", "/srv/my data", "xfs"),
+      mount("tmpfs", "/tmp", "tmpfs"),
+    ];
+
+    let disks = read_block_devices(&sys_block, &class_block, &mounts);
+
+    assert_eq!(disks.len(), 2);
+    let nvme = &disks[0];
+    assert_eq!(nvme.name, "nvme0n1");
+    assert_eq!(nvme.kind, BlockDeviceKind::Disk);
+    assert_eq!(nvme.model, Some("Samsung SSD 980 PRO 1TB".to_string()));
+    assert_eq!(
+      (nvme.size, nvme.size_unit.clone()),
+      (931.51, SizeUnit::GBytes)
+    );
+    assert_eq!(nvme.rotational, Some(false));
+    assert_eq!(nvme.scheduler, Some("none".to_string()));
+    assert!(nvme.mount_points.is_empty());
+
+    // Sorted by partition number, not by name
+    let partitions: Vec<&str> = nvme.children.iter().map(|p| p.name.as_str()).collect();
+    assert_eq!(partitions, vec!["nvme0n1p1", "nvme0n1p2", "nvme0n1p10"]);
+
+    let esp = &nvme.children[0];
+    assert_eq!(esp.kind, BlockDeviceKind::Partition);
+    assert_eq!(esp.mount_points, vec!["/boot/efi"]);
+    assert_eq!(esp.file_system, Some("vfat".to_string()));
+    assert_eq!(esp.model, None);
+
+    let luks = &nvme.children[1].children[0];
+    assert_eq!(luks.name, "dm-0");
+    assert_eq!(luks.kind, BlockDeviceKind::DeviceMapper);
+    assert_eq!(luks.label, Some("cryptroot".to_string()));
+
+    let volumes: Vec<(&str, Option<&str>, Vec<String>)> = luks
+      .children
+      .iter()
+      .map(|lv| {
+        (
+          lv.name.as_str(),
+          lv.label.as_deref(),
+          lv.mount_points.clone(),
+        )
+      })
+      .collect();
+    assert_eq!(
+      volumes,
+      vec![
+        ("dm-1", Some("vg0-root"), vec!["/".to_string()]),
+        ("dm-2", Some("vg0-swap"), vec![]),
+      ]
+    );
+
+    let sda = &disks[1];
+    assert_eq!(sda.rotational, Some(true));
+    assert_eq!(sda.scheduler, Some("bfq".to_string()));
+    let raid = &sda.children[0];
+    assert_eq!(raid.kind, BlockDeviceKind::Raid);
+    assert_eq!(raid.label, Some("raid1".to_string()));
+    assert_eq!(raid.mount_points, vec!["/srv/my data"]);
+    assert_eq!(raid.file_system, Some("xfs".to_string()));
+  }
+
+  #[test]
+  fn test_mount_points_of() {
+    let root = tempfile::tempdir().unwrap();
+    let (sys_block, class_block) = write_stacked_tree(root.path());
+    let mounts = [
+      mount("/dev/mapper/vg0-root", "/", "ext4"),
+      mount("/dev/nvme0n1p1", "/boot/efi", "vfat"),
+      mount("/dev/md0", "/srv/my data", "xfs"),
+      mount("tmpfs", "/tmp", "tmpfs"),
+    ];
+
+    let disks = read_block_devices(&sys_block, &class_block, &mounts);
+
+    // Through partitions and the LUKS / LVM layers
+    assert_eq!(mount_points_of(&disks[0]), vec!["/", "/boot/efi"]);
+    assert_eq!(mount_points_of(&disks[1]), vec!["/srv/my data"]);
+    assert_eq!(mount_points_of(&disks[0].children[2]), Vec::<String>::new());
+  }
+
+  #[test]
+  fn test_resolve_device_name() {
+    let root = tempfile::tempdir().unwrap();
+    let (_, class_block) = write_stacked_tree(root.path());
+
+    assert_eq!(
+      resolve_device_name(&class_block, "/dev/nvme0n1p1"),
+      Some("nvme0n1p1".to_string())
+    );
+    assert_eq!(
+      resolve_device_name(&class_block, "/dev/mapper/vg0-root"),
+      Some("dm-1".to_string())
+    );
+    assert_eq!(
+      resolve_device_name(&class_block, "/dev/mapper/missing"),
+      None
+    );
+    assert_eq!(resolve_device_name(&class_block, "tmpfs"), None);
+  }
+
+  #[test]
+  fn test_parse_scheduler() {
+    assert_eq!(
+      parse_scheduler("none [mq-deadline] kyber"),
+      Some("mq-deadline".to_string())
+    );
+    assert_eq!(parse_scheduler("none"), Some("none".to_string()));
+    assert_eq!(parse_scheduler("none mq-deadline"), None);
+    assert_eq!(parse_scheduler(""), None);
+  }
+}
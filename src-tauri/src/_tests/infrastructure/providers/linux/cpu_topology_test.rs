@@ -0,0 +1,193 @@
+#[cfg(target_os = "linux")]
+#[cfg(test)]
+mod tests {
+  use crate::infrastructure::providers::linux::cpu_topology::{
+    parse_cache_size_kb, parse_cpu_list, parse_isa_flags, read_cpu_topology,
+  };
+  use crate::models::hardware::CpuCache;
+  use std::fs;
+  use std::path::Path;
+
+  fn write(path: &Path, value: &str) {
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+    fs::write(path, format!("{value}\n")).unwrap();
+  }
+
+  fn write_cache(root: &Path, cpu: u32, index: u32, cache: (&str, &str, &str, &str)) {
+    let (level, cache_type, size, shared) = cache;
+    let dir = root.join(format!("devices/system/cpu/cpu{cpu}/cache/index{index}"));
+    write(&dir.join("level"), level);
+    write(&dir.join("type"), cache_type);
+    write(&dir.join("size"), size);
+    write(&dir.join("shared_cpu_list"), shared);
+  }
+
+  ///
+  /// 1 socket, 2 cores with SMT (cpu0/cpu2 and cpu1/cpu3 are siblings)
+  ///
+  fn write_smt_system(root: &Path) {
+    for (cpu, core_id) in [(0, 0), (1, 1), (2, 0), (3, 1)] {
+      let topology = root.join(format!("devices/system/cpu/cpu{cpu}/topology"));
+      write(&topology.join("physical_package_id"), "0");
+      write(&topology.join("core_id"), &core_id.to_string());
+
+      let siblings = if core_id == 0 { "0,2" } else { "1,3" };
+      write_cache(root, cpu, 0, ("1", "Data", "48K", siblings));
+      write_cache(root, cpu, 1, ("1", "Instruction", "32K", siblings));
+      write_cache(root, cpu, 2, ("2", "Unified", "1280K", siblings));
+      write_cache(root, cpu, 3, ("3", "Unified", "12M", "0-3"));
+    }
+    write(&root.join("devices/system/cpu/smt/active"), "1");
+    write(&root.join("devices/system/node/node0/cpulist"), "0-3");
+  }
+
+  const CPUINFO: &str = "processor\t: 0
+vendor_id\t: GenuineIntel
+flags\t\t: fpu vme sse4_2 avx avx2 fma bmi2 aes avx512f avx512bw avx512vl sha_ni
+";
+
+  #[test]
+  fn test_read_cpu_topology_counts() {
+    let root = tempfile::tempdir().unwrap();
+    write_smt_system(root.path());
+
+    let topology = read_cpu_topology(root.path(), CPUINFO).unwrap();
+
+    assert_eq!(topology.sockets, 1);
+    assert_eq!(topology.physical_cores, 2);
+    assert_eq!(topology.logical_cores, 4);
+    assert!(topology.smt_enabled);
+    assert!(topology.core_sets.is_empty());
+  }
+
+  #[test]
+  fn test_read_cpu_topology_caches() {
+    let root = tempfile::tempdir().unwrap();
+    write_smt_system(root.path());
+
+    let caches = read_cpu_topology(root.path(), CPUINFO).unwrap().caches;
+
+    assert_eq!(
+      caches,
+      vec![
+        CpuCache {
+          level: 1,
+          cache_type: "Data".to_string(),
+          size_kb: 48,
+          instances: 2,
+          shared_cpu_count: 2,
+        },
+        CpuCache {
+          level: 1,
+          cache_type: "Instruction".to_string(),
+          size_kb: 32,
+          instances: 2,
+          shared_cpu_count: 2,
+        },
+        CpuCache {
+          level: 2,
+          cache_type: "Unified".to_string(),
+          size_kb: 1280,
+          instances: 2,
+          shared_cpu_count: 2,
+        },
+        CpuCache {
+          level: 3,
+          cache_type: "Unified".to_string(),
+          size_kb: 12288,
+          instances: 1,
+          shared_cpu_count: 4,
+        },
+      ]
+    );
+  }
+
+  #[test]
+  fn test_read_cpu_topology_numa_and_flags() {
+    let root = tempfile::tempdir().unwrap();
+    write_smt_system(root.path());
+
+    let topology = read_cpu_topology(root.path(), CPUINFO).unwrap();
+
+    assert_eq!(topology.numa_nodes.len(), 1);
+    assert_eq!(topology.numa_nodes[0].id, 0);
+    assert_eq!(topology.numa_nodes[0].cpu_list, "0-3");
+    assert_eq!(topology.numa_nodes[0].logical_cores, 4);
+    assert!(topology.isa_flags.contains(&"avx2".to_string()));
+    assert!(topology.isa_flags.contains(&"avx512f".to_string()));
+  }
+
+  #[test]
+  fn test_read_cpu_topology_hybrid_core_sets() {
+    let root = tempfile::tempdir().unwrap();
+    write_smt_system(root.path());
+    write(&root.path().join("devices/cpu_core/cpus"), "0-1");
+    write(&root.path().join("devices/cpu_atom/cpus"), "2-3");
+
+    let core_sets = read_cpu_topology(root.path(), "").unwrap().core_sets;
+
+    assert_eq!(core_sets.len(), 2);
+    assert_eq!(core_sets[0].kind, "Performance");
+    assert_eq!(core_sets[0].cpu_list, "0-1");
+    assert_eq!(core_sets[0].logical_cores, 2);
+    assert_eq!(core_sets[1].kind, "Efficiency");
+  }
+
+  #[test]
+  fn test_read_cpu_topology_without_smt_file() {
+    let root = tempfile::tempdir().unwrap();
+    for cpu in 0..2 {
+      let topology = root
+        .path()
+        .join(format!("devices/system/cpu/cpu{cpu}/topology"));
+      write(&topology.join("physical_package_id"), "-1");
+      write(&topology.join("core_id"), &cpu.to_string());
+    }
+
+    let topology = read_cpu_topology(root.path(), "").unwrap();
+
+    assert_eq!(topology.sockets, 1);
+    assert_eq!(topology.physical_cores, 2);
+    assert!(!topology.smt_enabled);
+    assert!(topology.caches.is_empty());
+    assert!(topology.numa_nodes.is_empty());
+  }
+
+  #[test]
+  fn test_read_cpu_topology_missing() {
+    let root = tempfile::tempdir().unwrap();
+
+    assert!(read_cpu_topology(root.path(), CPUINFO).is_none());
+  }
+
+  #[test]
+  fn test_parse_isa_flags_arm() {
+    let cpuinfo =
+      "processor\t: 0\nFeatures\t: fp asimd evtstrm aes pmull sha1 sha2 crc32 sve\n";
+
+    assert_eq!(
+      parse_isa_flags(cpuinfo),
+      vec!["aes", "asimd", "sve", "sha2"]
+    );
+  }
+
+  #[test]
+  fn test_parse_isa_flags_missing() {
+    assert!(parse_isa_flags("processor\t: 0\n").is_empty());
+  }
+
+  #[test]
+  fn test_parse_cpu_list() {
+    assert_eq!(parse_cpu_list("0-3,8,10-11\n"), vec![0, 1, 2, 3, 8, 10, 11]);
+    assert_eq!(parse_cpu_list("5"), vec![5]);
+    assert!(parse_cpu_list("").is_empty());
+  }
+
+  #[test]
+  fn test_parse_cache_size_kb() {
+    assert_eq!(parse_cache_size_kb("48K"), Some(48));
+    assert_eq!(parse_cache_size_kb("36M"), Some(36864));
+    assert_eq!(parse_cache_size_kb("512"), Some(512));
+    assert_eq!(parse_cache_size_kb("abc"), None);
+  }
+}
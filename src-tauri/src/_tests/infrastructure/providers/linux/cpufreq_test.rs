@@ -0,0 +1,117 @@
+#[cfg(target_os = "linux")]
+#[cfg(test)]
+mod tests {
+  use crate::infrastructure::providers::linux::cpufreq::{
+    read_cpu_frequencies, throttle_events,
+  };
+  use std::fs;
+  use std::path::Path;
+
+  fn write_core(root: &Path, core: u32, cur_khz: &str, throttle: Option<(&str, &str)>) {
+    let cpufreq = root.join(format!("cpu{core}/cpufreq"));
+    fs::create_dir_all(&cpufreq).unwrap();
+    fs::write(cpufreq.join("scaling_cur_freq"), format!("{cur_khz}\n")).unwrap();
+    fs::write(cpufreq.join("scaling_min_freq"), "800000\n").unwrap();
+    fs::write(cpufreq.join("scaling_max_freq"), "4700000\n").unwrap();
+    fs::write(cpufreq.join("scaling_governor"), "powersave\n").unwrap();
+
+    if let Some((core_count, package_count)) = throttle {
+      let dir = root.join(format!("cpu{core}/thermal_throttle"));
+      fs::create_dir_all(&dir).unwrap();
+      fs::write(dir.join("core_throttle_count"), format!("{core_count}\n")).unwrap();
+      fs::write(
+        dir.join("package_throttle_count"),
+        format!("{package_count}\n"),
+      )
+      .unwrap();
+    }
+  }
+
+  #[test]
+  fn test_read_cpu_frequencies() {
+    let root = tempfile::tempdir().unwrap();
+    write_core(root.path(), 0, "3400123", Some(("2", "7")));
+
+    let cores = read_cpu_frequencies(root.path());
+
+    assert_eq!(cores.len(), 1);
+    let core = &cores[0];
+    assert_eq!(core.core, 0);
+    assert_eq!(core.current_mhz, 3400.0);
+    assert_eq!(core.min_mhz, Some(800.0));
+    assert_eq!(core.max_mhz, Some(4700.0));
+    assert_eq!(core.governor.as_deref(), Some("powersave"));
+    assert_eq!(core.core_throttle_count, Some(2));
+    assert_eq!(core.package_throttle_count, Some(7));
+    assert_eq!(core.throttle_events, 0);
+    assert!(!core.is_throttled);
+  }
+
+  #[test]
+  fn test_read_cpu_frequencies_sorted_by_core_index() {
+    let root = tempfile::tempdir().unwrap();
+    for core in [10, 2, 1] {
+      write_core(root.path(), core, "2000000", None);
+    }
+
+    let cores: Vec<u32> = read_cpu_frequencies(root.path())
+      .iter()
+      .map(|core| core.core)
+      .collect();
+
+    assert_eq!(cores, vec![1, 2, 10]);
+  }
+
+  #[test]
+  fn test_read_cpu_frequencies_skips_non_core_entries() {
+    let root = tempfile::tempdir().unwrap();
+    write_core(root.path(), 0, "2000000", None);
+    // Offline core without cpufreq and sibling directories like `cpufreq` / `cpuidle`
+    fs::create_dir_all(root.path().join("cpu1")).unwrap();
+    fs::create_dir_all(root.path().join("cpufreq")).unwrap();
+    fs::create_dir_all(root.path().join("cpuidle")).unwrap();
+
+    let cores = read_cpu_frequencies(root.path());
+
+    assert_eq!(cores.len(), 1);
+    assert_eq!(cores[0].core_throttle_count, None);
+  }
+
+  #[test]
+  fn test_throttle_events() {
+    let root = tempfile::tempdir().unwrap();
+    write_core(root.path(), 0, "2000000", Some(("2", "7")));
+    let prev = read_cpu_frequencies(root.path()).remove(0);
+
+    let mut curr = prev.clone();
+    assert_eq!(throttle_events(&prev, &curr), 0);
+
+    curr.core_throttle_count = Some(5);
+    assert_eq!(throttle_events(&prev, &curr), 3);
+
+    curr.package_throttle_count = Some(8);
+    assert_eq!(throttle_events(&prev, &curr), 4);
+  }
+
+  #[test]
+  fn test_throttle_events_counter_reset() {
+    let root = tempfile::tempdir().unwrap();
+    write_core(root.path(), 0, "2000000", Some(("20", "70")));
+    let prev = read_cpu_frequencies(root.path()).remove(0);
+
+    let mut curr = prev.clone();
+    curr.core_throttle_count = Some(1);
+    curr.package_throttle_count = Some(1);
+
+    assert_eq!(throttle_events(&prev, &curr), 0);
+  }
+
+  #[test]
+  fn test_throttle_events_without_counters() {
+    let root = tempfile::tempdir().unwrap();
+    write_core(root.path(), 0, "2000000", None);
+    let core = read_cpu_frequencies(root.path()).remove(0);
+
+    assert_eq!(throttle_events(&core, &core), 0);
+  }
+}
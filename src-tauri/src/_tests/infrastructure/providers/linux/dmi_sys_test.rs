@@ -0,0 +1,113 @@
+#[cfg(target_os = "linux")]
+#[cfg(test)]
+mod tests {
+  use crate::infrastructure::providers::linux::dmi_sys::{
+    chassis_type_name, is_generic_value, is_placeholder, read_board_info,
+  };
+  use crate::models::hardware::BoardInfo;
+  use std::fs;
+
+  #[test]
+  fn test_read_board_info() {
+    let dir = tempfile::tempdir().unwrap();
+    for (name, value) in [
+      ("board_vendor", "ASUSTeK COMPUTER INC."),
+      ("board_name", "ROG STRIX B650E-F GAMING WIFI"),
+      ("board_version", "Rev 1.xx"),
+      ("bios_vendor", "American Megatrends Inc."),
+      ("bios_version", "1813"),
+      ("bios_date", "10/13/2023"),
+      ("sys_vendor", "ASUS"),
+      ("product_name", "System Product Name"),
+      ("chassis_type", "3"),
+    ] {
+      fs::write(dir.path().join(name), format!("{value}\n")).unwrap();
+    }
+
+    let info = read_board_info(dir.path());
+
+    assert_eq!(
+      info,
+      Some(BoardInfo {
+        board_vendor: Some("ASUSTeK COMPUTER INC.".to_string()),
+        board_name: Some("ROG STRIX B650E-F GAMING WIFI".to_string()),
+        board_version: Some("Rev 1.xx".to_string()),
+        bios_vendor: Some("American Megatrends Inc.".to_string()),
+        bios_version: Some("1813".to_string()),
+        bios_date: Some("10/13/2023".to_string()),
+        system_vendor: Some("ASUS".to_string()),
+        // Placeholder is dropped
+        product_name: None,
+        chassis_type: Some("Desktop".to_string()),
+      })
+    );
+  }
+
+  #[test]
+  fn test_read_board_info_partial() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("bios_version"), "N2HET77W (1.60 )\n").unwrap();
+    fs::write(dir.path().join("board_name"), "\n").unwrap();
+
+    let info = read_board_info(dir.path()).unwrap();
+
+    assert_eq!(info.bios_version.as_deref(), Some("N2HET77W (1.60 )"));
+    assert_eq!(info.board_name, None);
+    assert_eq!(info.chassis_type, None);
+  }
+
+  #[test]
+  fn test_read_board_info_unavailable() {
+    let dir = tempfile::tempdir().unwrap();
+
+    assert_eq!(read_board_info(&dir.path().join("id")), None);
+  }
+
+  #[test]
+  fn test_chassis_type_name() {
+    assert_eq!(chassis_type_name(3), "Desktop");
+    assert_eq!(chassis_type_name(10), "Notebook");
+    assert_eq!(chassis_type_name(23), "Rack Mount Chassis");
+    assert_eq!(chassis_type_name(35), "Mini PC");
+    // Chassis lock bit is ignored
+    assert_eq!(chassis_type_name(0x80 | 9), "Laptop");
+    assert_eq!(chassis_type_name(2), "Unknown");
+    assert_eq!(chassis_type_name(99), "Unknown");
+  }
+
+  #[test]
+  fn test_is_placeholder() {
+    assert!(is_placeholder("To be filled by O.E.M."));
+    assert!(is_placeholder("Default string"));
+    assert!(is_placeholder(" Not Specified "));
+    // dmidecode spellings
+    assert!(is_placeholder("NO DIMM"));
+    assert!(is_placeholder("Not Provided"));
+    assert!(!is_placeholder("X570 AORUS MASTER"));
+    // Generic words are real values in some fields
+    assert!(!is_placeholder("Unknown"));
+    assert!(!is_placeholder("Other"));
+    assert!(!is_placeholder("None"));
+  }
+
+  #[test]
+  fn test_is_generic_value() {
+    assert!(is_generic_value("Unknown"));
+    assert!(is_generic_value(" OTHER "));
+    assert!(is_generic_value("None"));
+    assert!(is_generic_value("Undefined"));
+    assert!(!is_generic_value("Samsung"));
+  }
+
+  #[test]
+  fn test_read_board_info_keeps_generic_words() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("board_name"), "None\n").unwrap();
+    fs::write(dir.path().join("board_version"), "Other\n").unwrap();
+
+    let info = read_board_info(dir.path()).unwrap();
+
+    assert_eq!(info.board_name.as_deref(), Some("None"));
+    assert_eq!(info.board_version.as_deref(), Some("Other"));
+  }
+}
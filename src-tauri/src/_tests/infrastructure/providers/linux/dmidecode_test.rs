@@ -1,7 +1,10 @@
 #[cfg(target_os = "linux")]
 #[cfg(test)]
 mod tests {
-  use crate::infrastructure::providers::linux::dmidecode::parse_dmidecode_memory_info;
+  use crate::infrastructure::providers::linux::dmidecode::{
+    parse_dmidecode_memory_info, parse_dmidecode_memory_modules,
+  };
+  use crate::models::hardware::MemoryModule;
 
   #[test]
   fn test_parse_normal_dmidecode_gb_output() {
@@ -301,4 +304,151 @@ Handle 0x0011, DMI type 17, 40 bytes
     assert_eq!(result.memory_count, 2);
     assert_eq!(result.total_slots, 2);
   }
+
+  const MIXED_KIT: &str = r#"
+# dmidecode 3.5
+Getting SMBIOS data from sysfs.
+SMBIOS 3.5.0 present.
+
+Handle 0x0040, DMI type 17, 92 bytes
+Memory Device
+	Array Handle: 0x003F
+	Total Width: 64 bits
+	Data Width: 64 bits
+	Size: 16 GB
+	Form Factor: DIMM
+	Locator: DIMM_A1
+	Bank Locator: BANK 0
+	Type: DDR5
+	Type Detail: Synchronous Unbuffered (Unregistered)
+	Speed: 5600 MT/s
+	Manufacturer: Kingston
+	Serial Number: 1A2B3C4D
+	Part Number: KF556C40-16         
+	Rank: 1
+	Configured Memory Speed: 4800 MT/s
+
+Handle 0x0041, DMI type 17, 92 bytes
+Memory Device
+	Array Handle: 0x003F
+	Total Width: Unknown
+	Data Width: Unknown
+	Size: No Module Installed
+	Form Factor: Unknown
+	Locator: DIMM_A2
+	Bank Locator: BANK 1
+	Type: Unknown
+	Speed: Unknown
+	Manufacturer: Unknown
+	Serial Number: Unknown
+	Part Number: Unknown
+	Rank: Unknown
+	Configured Memory Speed: Unknown
+
+Handle 0x0042, DMI type 17, 92 bytes
+Memory Device
+	Size: 32768 MB
+	Locator: DIMM_B1
+	Bank Locator: BANK 2
+	Type: DDR5
+	Speed: 4800 MT/s
+	Manufacturer: Samsung
+	Serial Number: Not Specified
+	Part Number: M323R4GA3BB0-CQKOL
+	Rank: 2
+	Configured Memory Speed: 4800 MT/s
+
+Handle 0x0043, DMI type 19, 31 bytes
+Memory Array Mapped Address
+	Starting Address: 0x00000000000
+	Range Size: 48 GB
+"#;
+
+  #[test]
+  fn test_parse_memory_modules_per_slot() {
+    let modules = parse_dmidecode_memory_modules(MIXED_KIT);
+
+    assert_eq!(modules.len(), 3);
+    assert_eq!(
+      modules[0],
+      MemoryModule {
+        locator: "DIMM_A1".to_string(),
+        bank_locator: Some("BANK 0".to_string()),
+        is_empty: false,
+        size_mb: Some(16384),
+        memory_type: Some("DDR5".to_string()),
+        rated_speed_mts: Some(5600),
+        configured_speed_mts: Some(4800),
+        manufacturer: Some("Kingston".to_string()),
+        part_number: Some("KF556C40-16".to_string()),
+        serial_number: Some("1A2B3C4D".to_string()),
+        rank: Some(1),
+      }
+    );
+  }
+
+  #[test]
+  fn test_parse_memory_modules_empty_slot() {
+    let modules = parse_dmidecode_memory_modules(MIXED_KIT);
+
+    assert_eq!(
+      modules[1],
+      MemoryModule {
+        locator: "DIMM_A2".to_string(),
+        bank_locator: Some("BANK 1".to_string()),
+        is_empty: true,
+        ..Default::default()
+      }
+    );
+  }
+
+  #[test]
+  fn test_parse_memory_modules_mixed_kit() {
+    let modules = parse_dmidecode_memory_modules(MIXED_KIT);
+    let b1 = &modules[2];
+
+    assert_eq!(b1.size_mb, Some(32768));
+    assert_eq!(b1.manufacturer.as_deref(), Some("Samsung"));
+    assert_eq!(b1.serial_number, None);
+    assert_eq!(b1.rank, Some(2));
+    assert_ne!(modules[0].part_number, b1.part_number);
+    // Fields of the following Type 19 block are not mixed in
+    assert_eq!(b1.configured_speed_mts, Some(4800));
+  }
+
+  #[test]
+  fn test_parse_memory_info_keeps_aggregate_with_modules() {
+    let result = parse_dmidecode_memory_info(MIXED_KIT);
+
+    assert_eq!(result.size, "48.0 GB");
+    assert_eq!(result.memory_count, 2);
+    assert_eq!(result.total_slots, 3);
+    assert_eq!(result.modules.len(), 3);
+  }
+
+  #[test]
+  fn test_parse_memory_modules_legacy_clock_speed() {
+    let raw = r#"
+Handle 0x0011, DMI type 17, 34 bytes
+Memory Device
+	Size: 4096 MB
+	Locator: ChannelA-DIMM0
+	Type: DDR3
+	Speed: 1600 MHz
+	Configured Clock Speed: 1333 MHz
+"#;
+
+    let modules = parse_dmidecode_memory_modules(raw);
+
+    assert_eq!(modules.len(), 1);
+    assert_eq!(modules[0].size_mb, Some(4096));
+    assert_eq!(modules[0].rated_speed_mts, Some(1600));
+    assert_eq!(modules[0].configured_speed_mts, Some(1333));
+    assert_eq!(modules[0].bank_locator, None);
+  }
+
+  #[test]
+  fn test_parse_memory_modules_no_devices() {
+    assert!(parse_dmidecode_memory_modules("").is_empty());
+  }
 }
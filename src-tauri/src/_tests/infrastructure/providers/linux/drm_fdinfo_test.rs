@@ -0,0 +1,301 @@
+#[cfg(target_os = "linux")]
+#[cfg(test)]
+mod tests {
+  use crate::infrastructure::providers::linux::drm_fdinfo::{
+    engine_busy_percents, gpu_busy_percent, parse_fdinfo, process_gpu_memory_mb,
+    process_gpu_percents, read_drm_clients,
+  };
+  use crate::models::hardware::{DrmClient, DrmEngineCounter};
+  use std::collections::HashMap;
+  use std::fs;
+  use std::os::unix::fs::symlink;
+  use std::time::Duration;
+
+  const I915_FDINFO: &str = "\
+pos:\t0
+flags:\t02100002
+mnt_id:\t26
+ino:\t1077
+drm-driver:\ti915
+drm-client-id:\t7
+drm-pdev:\t0000:00:02.0
+drm-total-system0:\t4096 KiB
+drm-engine-render:\t25662044495 ns
+drm-engine-copy:\t0 ns
+drm-engine-video:\t1000000 ns
+drm-engine-capacity-video:\t2
+drm-engine-video-enhance:\t0 ns
+";
+
+  const XE_FDINFO: &str = "\
+drm-driver:\txe
+drm-client-id:\t4
+drm-pdev:\t0000:03:00.0
+drm-total-cycles-rcs:\t1000000
+drm-cycles-rcs:\t250000
+drm-total-cycles-vcs:\t1000000
+drm-cycles-vcs:\t0
+drm-engine-capacity-vcs:\t2
+drm-total-vram0:\t131072 KiB
+drm-resident-vram0:\t65536 KiB
+drm-resident-gtt:\t8192 KiB
+";
+
+  const AMDGPU_FDINFO: &str = "\
+drm-driver:\tamdgpu
+drm-client-id:\t12
+drm-pdev:\t0000:03:00.0
+drm-memory-vram:\t524288 KiB
+drm-memory-gtt:\t2048 KiB
+drm-memory-cpu:\t0 KiB
+drm-engine-gfx:\t1234567 ns
+";
+
+  fn client(client_id: u64, engines: &[(&str, u64, Option<u64>, u32)]) -> DrmClient {
+    DrmClient {
+      pid: 1,
+      driver: "i915".to_string(),
+      pdev: "0000:00:02.0".to_string(),
+      client_id,
+      engines: engines
+        .iter()
+        .map(|(name, busy, total, capacity)| {
+          (
+            name.to_string(),
+            DrmEngineCounter {
+              busy: *busy,
+              total: *total,
+              capacity: *capacity,
+            },
+          )
+        })
+        .collect(),
+      memory: HashMap::new(),
+    }
+  }
+
+  fn process_client(pid: u32, client_id: u64, pdev: &str, render_ns: u64) -> DrmClient {
+    DrmClient {
+      pid,
+      pdev: pdev.to_string(),
+      ..client(client_id, &[("render", render_ns, None, 1)])
+    }
+  }
+
+  #[test]
+  fn test_parse_fdinfo_i915() {
+    let client = parse_fdinfo(I915_FDINFO).unwrap();
+
+    assert_eq!(client.driver, "i915");
+    assert_eq!(client.pdev, "0000:00:02.0");
+    assert_eq!(client.client_id, 7);
+    assert_eq!(client.engines.len(), 4);
+    assert_eq!(
+      client.engines["render"],
+      DrmEngineCounter {
+        busy: 25662044495,
+        total: None,
+        capacity: 1,
+      }
+    );
+    assert_eq!(client.engines["video"].capacity, 2);
+    assert_eq!(client.engines["video-enhance"].busy, 0);
+  }
+
+  #[test]
+  fn test_parse_fdinfo_xe_cycles() {
+    let client = parse_fdinfo(XE_FDINFO).unwrap();
+
+    assert_eq!(client.driver, "xe");
+    assert_eq!(
+      client.engines["rcs"],
+      DrmEngineCounter {
+        busy: 250000,
+        total: Some(1000000),
+        capacity: 1,
+      }
+    );
+    assert_eq!(client.engines["vcs"].capacity, 2);
+  }
+
+  #[test]
+  fn test_parse_fdinfo_not_drm() {
+    assert_eq!(parse_fdinfo("pos:\t0\nflags:\t02\nmnt_id:\t15\n"), None);
+    // Client id is required to tell clients apart
+    assert_eq!(parse_fdinfo("drm-driver:\ti915\n"), None);
+  }
+
+  #[test]
+  fn test_engine_busy_percents() {
+    let prev = [
+      client(
+        1,
+        &[("render", 1_000_000_000, None, 1), ("video", 0, None, 2)],
+      ),
+      client(2, &[("render", 0, None, 1)]),
+    ];
+    let curr = [
+      client(
+        1,
+        &[
+          ("render", 1_250_000_000, None, 1),
+          ("video", 500_000_000, None, 2),
+        ],
+      ),
+      client(2, &[("render", 250_000_000, None, 1)]),
+      // New client: no previous sample
+      client(3, &[("render", 900_000_000, None, 1)]),
+    ];
+
+    let busy = engine_busy_percents(
+      &prev.iter().collect::<Vec<_>>(),
+      &curr.iter().collect::<Vec<_>>(),
+      Duration::from_secs(1),
+    );
+
+    assert_eq!(
+      busy,
+      HashMap::from([("render".to_string(), 50.0), ("video".to_string(), 25.0)])
+    );
+  }
+
+  #[test]
+  fn test_engine_busy_percents_cycles() {
+    let prev = [client(1, &[("rcs", 100, Some(1_000), 1)])];
+    let curr = [client(1, &[("rcs", 400, Some(2_000), 1)])];
+
+    let busy = engine_busy_percents(
+      &prev.iter().collect::<Vec<_>>(),
+      &curr.iter().collect::<Vec<_>>(),
+      // Ignored when total cycles are reported
+      Duration::from_secs(5),
+    );
+
+    assert_eq!(busy["rcs"], 30.0);
+  }
+
+  #[test]
+  fn test_gpu_busy_percent() {
+    let mut other_gpu = client(1, &[("render", 0, None, 1)]);
+    other_gpu.pdev = "0000:03:00.0".to_string();
+    let mut other_gpu_curr = client(1, &[("render", 1_000_000_000, None, 1)]);
+    other_gpu_curr.pdev = "0000:03:00.0".to_string();
+
+    let prev = [
+      client(1, &[("render", 0, None, 1), ("copy", 0, None, 1)]),
+      other_gpu,
+    ];
+    let curr = [
+      client(
+        1,
+        &[
+          ("render", 100_000_000, None, 1),
+          ("copy", 300_000_000, None, 1),
+        ],
+      ),
+      other_gpu_curr,
+    ];
+
+    // Busiest engine of the requested GPU only
+    assert_eq!(
+      gpu_busy_percent(&prev, &curr, "0000:00:02.0", Duration::from_secs(1)),
+      30.0
+    );
+    // Clamped to 100%
+    assert_eq!(
+      gpu_busy_percent(&prev, &curr, "0000:03:00.0", Duration::from_millis(500)),
+      100.0
+    );
+    assert_eq!(
+      gpu_busy_percent(&prev, &curr, "0000:04:00.0", Duration::from_secs(1)),
+      0.0
+    );
+  }
+
+  #[test]
+  fn test_read_drm_clients() {
+    let proc_root = tempfile::tempdir().unwrap();
+    let write_fd = |pid: &str, fd: &str, target: &str, fdinfo: &str| {
+      let process = proc_root.path().join(pid);
+      fs::create_dir_all(process.join("fd")).unwrap();
+      fs::create_dir_all(process.join("fdinfo")).unwrap();
+      symlink(target, process.join("fd").join(fd)).unwrap();
+      fs::write(process.join("fdinfo").join(fd), fdinfo).unwrap();
+    };
+    write_fd("1234", "0", "/dev/null", "pos:\t0\nflags:\t02\n");
+    write_fd("1234", "5", "/dev/dri/renderD128", I915_FDINFO);
+    // Same client through a duplicated fd
+    write_fd("1234", "6", "/dev/dri/renderD128", I915_FDINFO);
+    // Not a DRM device, fdinfo is not read
+    write_fd("1234", "7", "socket:[4242]", XE_FDINFO);
+    write_fd("5678", "9", "/dev/dri/card1", XE_FDINFO);
+    fs::create_dir_all(proc_root.path().join("self")).unwrap();
+
+    let clients = read_drm_clients(proc_root.path());
+
+    assert_eq!(clients.len(), 2);
+    assert_eq!((clients[0].pid, clients[0].client_id), (1234, 7));
+    assert_eq!((clients[1].pid, clients[1].client_id), (5678, 4));
+  }
+
+  #[test]
+  fn test_parse_fdinfo_memory() {
+    let xe = parse_fdinfo(XE_FDINFO).unwrap();
+    assert_eq!(
+      xe.memory,
+      HashMap::from([
+        ("vram0".to_string(), 64 * 1024 * 1024),
+        ("gtt".to_string(), 8 * 1024 * 1024),
+      ])
+    );
+
+    // Legacy `drm-memory-*` keys
+    let amdgpu = parse_fdinfo(AMDGPU_FDINFO).unwrap();
+    assert_eq!(amdgpu.memory["vram"], 512 * 1024 * 1024);
+    assert_eq!(amdgpu.memory["cpu"], 0);
+    assert_eq!(amdgpu.engines["gfx"].busy, 1234567);
+
+    // `drm-total-*` is not resident memory
+    let i915 = parse_fdinfo(I915_FDINFO).unwrap();
+    assert!(i915.memory.is_empty());
+  }
+
+  #[test]
+  fn test_process_gpu_percents() {
+    let prev = [
+      process_client(100, 1, "0000:00:02.0", 0),
+      process_client(100, 2, "0000:03:00.0", 0),
+      process_client(200, 3, "0000:03:00.0", 0),
+    ];
+    let curr = [
+      process_client(100, 1, "0000:00:02.0", 200_000_000),
+      process_client(100, 2, "0000:03:00.0", 700_000_000),
+      process_client(200, 3, "0000:03:00.0", 100_000_000),
+      // Started since the previous sample
+      process_client(300, 4, "0000:03:00.0", 500_000_000),
+    ];
+
+    let percents = process_gpu_percents(&prev, &curr, Duration::from_secs(1));
+
+    // Busiest GPU of the process, not the sum
+    assert_eq!(percents[&100], 70.0);
+    assert_eq!(percents[&200], 10.0);
+    assert_eq!(percents[&300], 0.0);
+  }
+
+  #[test]
+  fn test_process_gpu_memory_mb() {
+    let mut xe = parse_fdinfo(XE_FDINFO).unwrap();
+    xe.pid = 100;
+    let mut amdgpu = parse_fdinfo(AMDGPU_FDINFO).unwrap();
+    amdgpu.pid = 100;
+    let mut i915 = parse_fdinfo(I915_FDINFO).unwrap();
+    i915.pid = 200;
+
+    let memory = process_gpu_memory_mb(&[xe, amdgpu, i915]);
+
+    // Device-local regions only (GTT is system memory)
+    assert_eq!(memory[&100], 576.0);
+    assert_eq!(memory[&200], 0.0);
+  }
+}
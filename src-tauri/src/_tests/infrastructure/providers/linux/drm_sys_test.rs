@@ -0,0 +1,68 @@
+#[cfg(target_os = "linux")]
+#[cfg(test)]
+mod tests {
+  use crate::infrastructure::providers::linux::drm_sys::{
+    parse_gpu_busy_percent, read_card_ids, read_intel_gt_frequency_mhz,
+  };
+  use std::fs;
+
+  #[test]
+  fn test_parse_gpu_busy_percent() {
+    assert_eq!(parse_gpu_busy_percent("42\n"), Some(42.0));
+    assert_eq!(parse_gpu_busy_percent("0"), Some(0.0));
+    assert_eq!(parse_gpu_busy_percent("100\n"), Some(100.0));
+  }
+
+  #[test]
+  fn test_parse_gpu_busy_percent_invalid() {
+    assert_eq!(parse_gpu_busy_percent(""), None);
+    assert_eq!(parse_gpu_busy_percent("N/A"), None);
+    assert_eq!(parse_gpu_busy_percent("101"), None);
+    assert_eq!(parse_gpu_busy_percent("-1"), None);
+  }
+
+  #[test]
+  fn test_read_intel_gt_frequency_i915() {
+    let card = tempfile::tempdir().unwrap();
+    fs::write(card.path().join("gt_cur_freq_mhz"), "1100\n").unwrap();
+    fs::write(card.path().join("gt_max_freq_mhz"), "1450\n").unwrap();
+
+    assert_eq!(read_intel_gt_frequency_mhz(card.path(), "cur"), Some(1100));
+    assert_eq!(read_intel_gt_frequency_mhz(card.path(), "max"), Some(1450));
+  }
+
+  #[test]
+  fn test_read_intel_gt_frequency_xe() {
+    let card = tempfile::tempdir().unwrap();
+    let freq = card.path().join("device/tile0/gt0/freq0");
+    fs::create_dir_all(&freq).unwrap();
+    fs::write(freq.join("cur_freq"), "2050\n").unwrap();
+
+    assert_eq!(read_intel_gt_frequency_mhz(card.path(), "cur"), Some(2050));
+    assert_eq!(read_intel_gt_frequency_mhz(card.path(), "max"), None);
+  }
+
+  #[test]
+  fn test_read_card_ids() {
+    let dir = tempfile::tempdir().unwrap();
+    for name in [
+      "card0",
+      "card0-eDP-1",
+      "card1",
+      "card1-DP-2",
+      "card12",
+      "renderD128",
+      "version",
+    ] {
+      fs::create_dir(dir.path().join(name)).unwrap();
+    }
+
+    // Not limited to card0..card9
+    assert_eq!(read_card_ids(dir.path()), vec![0, 1, 12]);
+  }
+
+  #[test]
+  fn test_read_card_ids_missing_dir() {
+    assert!(read_card_ids(std::path::Path::new("/nonexistent/drm")).is_empty());
+  }
+}
@@ -0,0 +1,177 @@
+#[cfg(target_os = "linux")]
+#[cfg(test)]
+mod tests {
+  use crate::infrastructure::providers::linux::hwmon::*;
+  use std::fs;
+  use std::path::Path;
+
+  fn write(dir: &Path, name: &str, content: &str) {
+    fs::write(dir.join(name), content).unwrap();
+  }
+
+  #[test]
+  fn test_read_temperatures_with_labels() {
+    let dir = tempfile::tempdir().unwrap();
+    write(dir.path(), "temp1_input", "45000\n");
+    write(dir.path(), "temp1_label", "edge\n");
+    write(dir.path(), "temp2_input", "52500\n");
+    write(dir.path(), "temp2_label", "junction\n");
+    write(dir.path(), "temp3_input", "60000\n");
+    write(dir.path(), "temp3_label", "mem\n");
+    write(dir.path(), "temp1_crit", "100000\n");
+
+    let sensors = read_temperatures(dir.path());
+
+    assert_eq!(
+      sensors,
+      vec![
+        TemperatureSensor {
+          label: "edge".to_string(),
+          value: 45.0
+        },
+        TemperatureSensor {
+          label: "junction".to_string(),
+          value: 52.5
+        },
+        TemperatureSensor {
+          label: "mem".to_string(),
+          value: 60.0
+        },
+      ]
+    );
+  }
+
+  #[test]
+  fn test_read_temperatures_without_label() {
+    let dir = tempfile::tempdir().unwrap();
+    write(dir.path(), "temp1_input", "38000\n");
+
+    let sensors = read_temperatures(dir.path());
+
+    assert_eq!(sensors.len(), 1);
+    assert_eq!(sensors[0].label, "temp1");
+    assert_eq!(sensors[0].value, 38.0);
+  }
+
+  #[test]
+  fn test_read_temperatures_sorted_by_index() {
+    let dir = tempfile::tempdir().unwrap();
+    write(dir.path(), "temp10_input", "10000");
+    write(dir.path(), "temp2_input", "2000");
+
+    let labels: Vec<String> = read_temperatures(dir.path())
+      .into_iter()
+      .map(|sensor| sensor.label)
+      .collect();
+
+    assert_eq!(labels, vec!["temp2", "temp10"]);
+  }
+
+  #[test]
+  fn test_read_temperatures_skips_unreadable_values() {
+    let dir = tempfile::tempdir().unwrap();
+    write(dir.path(), "temp1_input", "N/A");
+    write(dir.path(), "temp2_input", "41000");
+
+    let sensors = read_temperatures(dir.path());
+
+    assert_eq!(sensors.len(), 1);
+    assert_eq!(sensors[0].label, "temp2");
+  }
+
+  #[test]
+  fn test_read_temperatures_missing_dir() {
+    assert!(read_temperatures(Path::new("/nonexistent/hwmon0")).is_empty());
+  }
+
+  #[test]
+  fn test_get_hwmon_dirs() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::create_dir(dir.path().join("hwmon3")).unwrap();
+    fs::create_dir(dir.path().join("hwmon1")).unwrap();
+    fs::create_dir(dir.path().join("power")).unwrap();
+
+    let dirs = get_hwmon_dirs(dir.path());
+
+    assert_eq!(
+      dirs,
+      vec![dir.path().join("hwmon1"), dir.path().join("hwmon3")]
+    );
+  }
+
+  #[test]
+  fn test_parse_millidegrees() {
+    assert_eq!(parse_millidegrees("45000\n"), Some(45.0));
+    assert_eq!(parse_millidegrees("-5000"), Some(-5.0));
+    assert_eq!(parse_millidegrees(""), None);
+  }
+
+  #[test]
+  fn test_read_fans_with_pwm() {
+    let dir = tempfile::tempdir().unwrap();
+    write(dir.path(), "fan1_input", "1200\n");
+    write(dir.path(), "fan1_label", "CPU Fan\n");
+    write(dir.path(), "pwm1", "128\n");
+    write(dir.path(), "pwm1_enable", "2\n");
+    write(dir.path(), "fan2_input", "0\n");
+
+    let fans = read_fans(dir.path());
+
+    assert_eq!(
+      fans,
+      vec![
+        FanSensor {
+          label: "CPU Fan".to_string(),
+          rpm: Some(1200),
+          pwm_percent: Some(50.2),
+        },
+        FanSensor {
+          label: "fan2".to_string(),
+          rpm: Some(0),
+          pwm_percent: None,
+        },
+      ]
+    );
+  }
+
+  #[test]
+  fn test_read_fans_pwm_only() {
+    let dir = tempfile::tempdir().unwrap();
+    write(dir.path(), "pwm3", "255");
+
+    let fans = read_fans(dir.path());
+
+    assert_eq!(fans.len(), 1);
+    assert_eq!(fans[0].label, "fan3");
+    assert_eq!(fans[0].rpm, None);
+    assert_eq!(fans[0].pwm_percent, Some(100.0));
+  }
+
+  #[test]
+  fn test_read_fans_amdgpu_pwm_max() {
+    let dir = tempfile::tempdir().unwrap();
+    write(dir.path(), "fan1_input", "850");
+    write(dir.path(), "pwm1", "50");
+    write(dir.path(), "pwm1_max", "100");
+
+    let fans = read_fans(dir.path());
+
+    assert_eq!(fans[0].pwm_percent, Some(50.0));
+  }
+
+  #[test]
+  fn test_parse_pwm_percent() {
+    assert_eq!(parse_pwm_percent("0", None), Some(0.0));
+    assert_eq!(parse_pwm_percent("255\n", None), Some(100.0));
+    assert_eq!(parse_pwm_percent("64", Some(0)), Some(25.1));
+    assert_eq!(parse_pwm_percent("invalid", None), None);
+  }
+
+  #[test]
+  fn test_is_gpu_driver() {
+    assert!(is_gpu_driver("amdgpu"));
+    assert!(is_gpu_driver("nouveau\n"));
+    assert!(!is_gpu_driver("nct6798"));
+    assert!(!is_gpu_driver("coretemp"));
+  }
+}
@@ -1,11 +1,79 @@
+#[cfg(target_os = "linux")]
+#[cfg(test)]
+pub mod amdgpu_test;
+
+#[cfg(target_os = "linux")]
+#[cfg(test)]
+pub mod block_sys_test;
+
+#[cfg(target_os = "linux")]
+#[cfg(test)]
+pub mod cpu_topology_test;
+
+#[cfg(target_os = "linux")]
+#[cfg(test)]
+pub mod cpufreq_test;
+
+#[cfg(target_os = "linux")]
+#[cfg(test)]
+pub mod dmi_sys_test;
+
 #[cfg(target_os = "linux")]
 #[cfg(test)]
 pub mod dmidecode_test;
 
+#[cfg(target_os = "linux")]
+#[cfg(test)]
+pub mod drm_fdinfo_test;
+
+#[cfg(target_os = "linux")]
+#[cfg(test)]
+pub mod drm_sys_test;
+
+#[cfg(target_os = "linux")]
+#[cfg(test)]
+pub mod hwmon_test;
+
 #[cfg(target_os = "linux")]
 #[cfg(test)]
 pub mod kernel_test;
 
+#[cfg(target_os = "linux")]
+#[cfg(test)]
+pub mod net_sys_test;
+
+#[cfg(target_os = "linux")]
+#[cfg(test)]
+pub mod nvidia_smi_test;
+
+#[cfg(target_os = "linux")]
+#[cfg(test)]
+pub mod pci_sys_test;
+
+#[cfg(target_os = "linux")]
+#[cfg(test)]
+pub mod power_supply_test;
+
+#[cfg(target_os = "linux")]
+#[cfg(test)]
+pub mod powercap_test;
+
 #[cfg(target_os = "linux")]
 #[cfg(test)]
 pub mod procfs_test;
+
+#[cfg(target_os = "linux")]
+#[cfg(test)]
+pub mod smartctl_test;
+
+#[cfg(target_os = "linux")]
+#[cfg(test)]
+pub mod smbios_test;
+
+#[cfg(target_os = "linux")]
+#[cfg(test)]
+pub mod sysfs_test;
+
+#[cfg(target_os = "linux")]
+#[cfg(test)]
+pub mod thermal_sys_test;
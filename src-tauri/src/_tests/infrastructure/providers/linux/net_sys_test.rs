@@ -0,0 +1,73 @@
+#[cfg(target_os = "linux")]
+#[cfg(test)]
+mod tests {
+  use crate::infrastructure::providers::linux::net_sys::read_network_counters;
+  use crate::models::hardware::NetworkCounters;
+  use std::fs;
+
+  #[test]
+  fn test_read_network_counters() {
+    let dir = tempfile::tempdir().unwrap();
+    for (name, value) in [
+      ("rx_bytes", "123456789\n"),
+      ("tx_bytes", "987654\n"),
+      ("rx_packets", "1000\n"),
+      ("tx_packets", "900\n"),
+      ("rx_errors", "2\n"),
+      ("tx_errors", "0\n"),
+      ("rx_dropped", "5\n"),
+      ("tx_dropped", "1\n"),
+    ] {
+      fs::write(dir.path().join(name), value).unwrap();
+    }
+
+    let counters = read_network_counters(dir.path());
+
+    assert_eq!(
+      counters,
+      Some(NetworkCounters {
+        rx_bytes: 123456789,
+        tx_bytes: 987654,
+        rx_packets: 1000,
+        tx_packets: 900,
+        rx_errors: 2,
+        tx_errors: 0,
+        rx_dropped: 5,
+        tx_dropped: 1,
+      })
+    );
+  }
+
+  #[test]
+  fn test_read_network_counters_partial() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("rx_bytes"), "10").unwrap();
+    fs::write(dir.path().join("tx_bytes"), "20").unwrap();
+
+    let counters = read_network_counters(dir.path()).unwrap();
+
+    assert_eq!(counters.rx_bytes, 10);
+    assert_eq!(counters.tx_bytes, 20);
+    assert_eq!(counters.rx_packets, 0);
+    assert_eq!(counters.tx_dropped, 0);
+  }
+
+  #[test]
+  fn test_read_network_counters_missing_bytes() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("rx_bytes"), "10").unwrap();
+
+    assert_eq!(read_network_counters(dir.path()), None);
+  }
+
+  #[test]
+  fn test_read_network_counters_u64_range() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("rx_bytes"), "18446744073709551615").unwrap();
+    fs::write(dir.path().join("tx_bytes"), "0").unwrap();
+
+    let counters = read_network_counters(dir.path()).unwrap();
+
+    assert_eq!(counters.rx_bytes, u64::MAX);
+  }
+}
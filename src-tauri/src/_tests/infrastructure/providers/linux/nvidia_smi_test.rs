@@ -0,0 +1,114 @@
+#[cfg(target_os = "linux")]
+#[cfg(test)]
+mod tests {
+  use crate::infrastructure::providers::linux::nvidia_smi::{
+    normalize_pci_bus_id, parse_query_gpu_csv, to_gpu_metrics,
+  };
+  use crate::models::hardware::NvidiaGpuStatus;
+
+  // nvidia-smi --query-gpu=... --format=csv,noheader,nounits (RTX 4070 + laptop RTX 3050)
+  const NOHEADER_NOUNITS: &str = "\
+00000000:01:00.0, NVIDIA GeForce RTX 4070, 37, 52, 1423, 12282, 2475, 10501, 48.12, 200.00, 30
+00000000:02:00.0, NVIDIA GeForce RTX 3050 Laptop GPU, 0, 41, 5, 4096, 210, 405, [N/A], [N/A], [N/A]
+";
+
+  // nvidia-smi --query-gpu=... --format=csv (datacenter GPU without a fan)
+  const WITH_HEADER_AND_UNITS: &str = "\
+pci.bus_id, name, utilization.gpu [%], temperature.gpu, memory.used [MiB], memory.total [MiB], clocks.current.graphics [MHz], clocks.current.memory [MHz], power.draw [W], power.limit [W], fan.speed [%]
+00000000:3B:00.0, Tesla T4, 100 %, 71, 14208 MiB, 15360 MiB, 1590 MHz, 5000 MHz, 69.83 W, 70.00 W, [Not Supported]
+";
+
+  #[test]
+  fn test_parse_query_gpu_csv() {
+    let gpus = parse_query_gpu_csv(NOHEADER_NOUNITS);
+
+    assert_eq!(
+      gpus,
+      vec![
+        NvidiaGpuStatus {
+          pci_address: "0000:01:00.0".to_string(),
+          name: "NVIDIA GeForce RTX 4070".to_string(),
+          usage: Some(37.0),
+          temperature: Some(52.0),
+          memory_used_mb: Some(1423.0),
+          memory_total_mb: Some(12282.0),
+          core_clock_mhz: Some(2475),
+          memory_clock_mhz: Some(10501),
+          power_watts: Some(48.12),
+          power_limit_watts: Some(200.0),
+          fan_speed_percent: Some(30.0),
+        },
+        NvidiaGpuStatus {
+          pci_address: "0000:02:00.0".to_string(),
+          name: "NVIDIA GeForce RTX 3050 Laptop GPU".to_string(),
+          usage: Some(0.0),
+          temperature: Some(41.0),
+          memory_used_mb: Some(5.0),
+          memory_total_mb: Some(4096.0),
+          core_clock_mhz: Some(210),
+          memory_clock_mhz: Some(405),
+          // Power and fan are managed by the laptop firmware
+          power_watts: None,
+          power_limit_watts: None,
+          fan_speed_percent: None,
+        },
+      ]
+    );
+  }
+
+  #[test]
+  fn test_parse_query_gpu_csv_with_header_and_units() {
+    let gpus = parse_query_gpu_csv(WITH_HEADER_AND_UNITS);
+
+    assert_eq!(gpus.len(), 1);
+    let gpu = &gpus[0];
+    assert_eq!(gpu.pci_address, "0000:3b:00.0");
+    assert_eq!(gpu.name, "Tesla T4");
+    assert_eq!(gpu.usage, Some(100.0));
+    assert_eq!(gpu.memory_used_mb, Some(14208.0));
+    assert_eq!(gpu.core_clock_mhz, Some(1590));
+    assert_eq!(gpu.power_watts, Some(69.83));
+    assert_eq!(gpu.fan_speed_percent, None);
+  }
+
+  #[test]
+  fn test_parse_query_gpu_csv_invalid() {
+    // Error output / wrong column count
+    assert!(parse_query_gpu_csv("").is_empty());
+    assert!(
+      parse_query_gpu_csv(
+        "NVIDIA-SMI has failed because it couldn't communicate with the NVIDIA driver."
+      )
+      .is_empty()
+    );
+    assert!(
+      parse_query_gpu_csv("00000000:01:00.0, NVIDIA GeForce RTX 4070, 37").is_empty()
+    );
+  }
+
+  #[test]
+  fn test_normalize_pci_bus_id() {
+    assert_eq!(
+      normalize_pci_bus_id("00000000:01:00.0"),
+      Some("0000:01:00.0".to_string())
+    );
+    assert_eq!(
+      normalize_pci_bus_id("00000001:AF:00.0"),
+      Some("0001:af:00.0".to_string())
+    );
+    assert_eq!(normalize_pci_bus_id("N/A"), None);
+  }
+
+  #[test]
+  fn test_to_gpu_metrics() {
+    let gpus = parse_query_gpu_csv(NOHEADER_NOUNITS);
+    let metrics = to_gpu_metrics("card1", &gpus[0]);
+
+    assert_eq!(metrics.card, "card1");
+    assert_eq!(metrics.core_clock_mhz, Some(2475));
+    assert_eq!(metrics.vram_total_mb, Some(12282.0));
+    assert_eq!(metrics.power_cap_watts, Some(200.0));
+    assert_eq!(metrics.fan_speed_percent, Some(30.0));
+    assert_eq!(metrics.gtt_used_mb, None);
+  }
+}
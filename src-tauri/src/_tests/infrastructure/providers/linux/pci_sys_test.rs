@@ -0,0 +1,417 @@
+#[cfg(target_os = "linux")]
+#[cfg(test)]
+mod tests {
+  use crate::infrastructure::providers::linux::pci_sys::{
+    PciIds, gpu_display_name, parse_link_speed, pcie_generation, pcie_link_status,
+    read_pci_devices, read_pcie_link_status,
+  };
+  use crate::models::hardware::{PciDevice, PcieLinkStatus};
+  use std::fs;
+  use std::path::Path;
+
+  const PCI_IDS: &str = "\
+# List of PCI ID's
+#
+1002  Advanced Micro Devices, Inc. [AMD/ATI]
+\t73bf  Navi 21 [Radeon RX 6800/6800 XT / 6900 XT]
+\t\t1002 0e3a  Radeon RX 6900 XT
+\t\t1da2 e438  Radeon RX 6800 XT Nitro+
+144d  Samsung Electronics Co Ltd
+\ta80a  NVMe SSD Controller PM9A1/PM9A3/980PRO
+8086  Intel Corporation
+\t4680  AlderLake-S GT1
+\t7a84  Z690 Chipset LPC/eSPI Controller
+C 01  Mass storage controller
+\t08  Non-Volatile memory controller
+\t\t02  NVM Express
+C 03  Display controller
+\t00  VGA compatible controller
+\t\t00  VGA controller
+\t80  Display controller
+C 06  Bridge
+";
+
+  fn write_device(root: &Path, address: &str, attributes: &[(&str, &str)]) {
+    let dir = root.join(address);
+    fs::create_dir_all(&dir).unwrap();
+    for (name, value) in attributes {
+      fs::write(dir.join(name), format!("{value}\n")).unwrap();
+    }
+  }
+
+  #[test]
+  fn test_read_pci_devices() {
+    let root = tempfile::tempdir().unwrap();
+    write_device(
+      root.path(),
+      "0000:03:00.0",
+      &[
+        ("vendor", "0x1002"),
+        ("device", "0x73bf"),
+        ("subsystem_vendor", "0x1da2"),
+        ("subsystem_device", "0xe438"),
+        ("class", "0x030000"),
+        ("current_link_speed", "16.0 GT/s PCIe"),
+        ("max_link_speed", "16.0 GT/s PCIe"),
+        ("current_link_width", "16"),
+        ("max_link_width", "16"),
+      ],
+    );
+    write_device(
+      root.path(),
+      "0000:00:1f.0",
+      &[
+        ("vendor", "0x8086"),
+        ("device", "0x7a84"),
+        ("subsystem_vendor", "0x1043"),
+        ("subsystem_device", "0x8882"),
+        ("class", "0x060100"),
+      ],
+    );
+    let driver = root.path().join("drivers/amdgpu");
+    fs::create_dir_all(&driver).unwrap();
+    std::os::unix::fs::symlink(&driver, root.path().join("0000:03:00.0/driver")).unwrap();
+
+    let devices = read_pci_devices(root.path(), &PciIds::parse(PCI_IDS));
+
+    assert_eq!(
+      devices,
+      vec![
+        PciDevice {
+          address: "0000:00:1f.0".to_string(),
+          vendor_id: "8086".to_string(),
+          device_id: "7a84".to_string(),
+          subsystem_vendor_id: Some("1043".to_string()),
+          subsystem_device_id: Some("8882".to_string()),
+          class_code: "060100".to_string(),
+          // Falls back to the class when the subclass is unknown
+          class_name: Some("Bridge".to_string()),
+          vendor_name: Some("Intel Corporation".to_string()),
+          device_name: Some("Z690 Chipset LPC/eSPI Controller".to_string()),
+          ..Default::default()
+        },
+        PciDevice {
+          address: "0000:03:00.0".to_string(),
+          vendor_id: "1002".to_string(),
+          device_id: "73bf".to_string(),
+          subsystem_vendor_id: Some("1da2".to_string()),
+          subsystem_device_id: Some("e438".to_string()),
+          class_code: "030000".to_string(),
+          class_name: Some("VGA compatible controller".to_string()),
+          vendor_name: Some("Advanced Micro Devices, Inc. [AMD/ATI]".to_string()),
+          device_name: Some("Navi 21 [Radeon RX 6800/6800 XT / 6900 XT]".to_string()),
+          subsystem_name: Some("Radeon RX 6800 XT Nitro+".to_string()),
+          driver: Some("amdgpu".to_string()),
+          current_link_speed: Some(16.0),
+          max_link_speed: Some(16.0),
+          current_link_width: Some(16),
+          max_link_width: Some(16),
+        },
+      ]
+    );
+  }
+
+  #[test]
+  fn test_read_pci_devices_without_pci_ids() {
+    let root = tempfile::tempdir().unwrap();
+    write_device(
+      root.path(),
+      "0000:01:00.0",
+      &[
+        ("vendor", "0x144d"),
+        ("device", "0xa80a"),
+        ("class", "0x010802"),
+        ("current_link_speed", "Unknown"),
+        ("current_link_width", "0"),
+      ],
+    );
+    // Missing `vendor` is not a PCI device
+    write_device(root.path(), "0000:00:00.0", &[("class", "0x060000")]);
+
+    let devices = read_pci_devices(root.path(), &PciIds::default());
+
+    assert_eq!(devices.len(), 1);
+    let device = &devices[0];
+    assert_eq!(device.vendor_id, "144d");
+    assert_eq!(device.vendor_name, None);
+    assert_eq!(device.device_name, None);
+    assert_eq!(device.class_name, None);
+    assert_eq!(device.current_link_speed, None);
+    assert_eq!(device.current_link_width, None);
+    assert_eq!(device.driver, None);
+  }
+
+  #[test]
+  fn test_read_pci_devices_missing_root() {
+    let devices =
+      read_pci_devices(Path::new("/nonexistent/pci/devices"), &PciIds::default());
+    assert!(devices.is_empty());
+  }
+
+  #[test]
+  fn test_parse_link_speed() {
+    assert_eq!(parse_link_speed("2.5 GT/s PCIe"), Some(2.5));
+    assert_eq!(parse_link_speed("8 GT/s"), Some(8.0));
+    assert_eq!(parse_link_speed("32.0 GT/s PCIe"), Some(32.0));
+    assert_eq!(parse_link_speed("Unknown"), None);
+    assert_eq!(parse_link_speed(""), None);
+  }
+
+  #[test]
+  fn test_gpu_display_name() {
+    let device = |name: Option<&str>| PciDevice {
+      device_name: name.map(str::to_string),
+      ..Default::default()
+    };
+
+    assert_eq!(
+      gpu_display_name(&device(Some("Navi 21 [Radeon RX 6800/6800 XT / 6900 XT]"))),
+      Some("Radeon RX 6800/6800 XT / 6900 XT".to_string())
+    );
+    assert_eq!(
+      gpu_display_name(&device(Some("AlderLake-S GT1"))),
+      Some("AlderLake-S GT1".to_string())
+    );
+    assert_eq!(gpu_display_name(&device(None)), None);
+  }
+
+  #[test]
+  fn test_pcie_generation() {
+    assert_eq!(pcie_generation(2.5), Some(1));
+    assert_eq!(pcie_generation(5.0), Some(2));
+    assert_eq!(pcie_generation(8.0), Some(3));
+    assert_eq!(pcie_generation(16.0), Some(4));
+    assert_eq!(pcie_generation(32.0), Some(5));
+    assert_eq!(pcie_generation(64.0), Some(6));
+    assert_eq!(pcie_generation(1.0), None);
+  }
+
+  #[test]
+  fn test_pcie_link_status_slot_limited_width() {
+    // x16 card seated in an x4 slot
+    assert_eq!(
+      pcie_link_status(
+        Some(16.0),
+        Some(16.0),
+        Some(4),
+        Some(16),
+        Some(16.0),
+        Some(4)
+      ),
+      Some(PcieLinkStatus {
+        current_generation: Some(4),
+        max_generation: Some(4),
+        current_width: Some(4),
+        max_width: Some(16),
+        slot_max_generation: Some(4),
+        slot_max_width: Some(4),
+        is_slot_limited: true,
+        is_width_degraded: true,
+        is_speed_degraded: false,
+      })
+    );
+  }
+
+  #[test]
+  fn test_pcie_link_status_slot_limited_generation() {
+    // Gen 4 SSD in a Gen 3 slot
+    let status =
+      pcie_link_status(Some(8.0), Some(16.0), Some(4), Some(4), Some(8.0), Some(4))
+        .unwrap();
+
+    assert_eq!(status.slot_max_generation, Some(3));
+    assert!(status.is_slot_limited);
+    assert!(status.is_speed_degraded);
+  }
+
+  #[test]
+  fn test_pcie_link_status_idle_speed() {
+    // Idle GPU downclocked its link in a slot that supports the full speed
+    let status = pcie_link_status(
+      Some(2.5),
+      Some(16.0),
+      Some(16),
+      Some(16),
+      Some(16.0),
+      Some(16),
+    )
+    .unwrap();
+
+    assert_eq!(status.current_generation, Some(1));
+    assert_eq!(status.max_generation, Some(4));
+    assert!(!status.is_slot_limited);
+    assert!(!status.is_width_degraded);
+    assert!(status.is_speed_degraded);
+  }
+
+  #[test]
+  fn test_pcie_link_status_unknown_slot() {
+    let status =
+      pcie_link_status(Some(16.0), Some(16.0), Some(4), Some(16), None, None).unwrap();
+
+    assert_eq!(status.slot_max_generation, None);
+    assert!(!status.is_slot_limited);
+    assert!(status.is_width_degraded);
+  }
+
+  #[test]
+  fn test_pcie_link_status_no_link() {
+    assert_eq!(pcie_link_status(None, None, None, None, None, None), None);
+  }
+
+  fn write_bridge_chain(
+    root: &Path,
+    chain: &[(&str, &str, &str, &str, &str, &str)],
+  ) -> std::path::PathBuf {
+    let mut dir = root.join("pci0000:00");
+    for (address, vendor, class, speed, width, max_width) in chain {
+      dir = dir.join(address);
+      write_device(
+        dir.parent().unwrap(),
+        address,
+        &[
+          ("vendor", vendor),
+          ("class", class),
+          ("current_link_speed", speed),
+          ("max_link_speed", "16.0 GT/s PCIe"),
+          ("current_link_width", width),
+          ("max_link_width", max_width),
+        ],
+      );
+    }
+    dir
+  }
+
+  #[test]
+  fn test_read_pcie_link_status_through_gpu_switch() {
+    let root = tempfile::tempdir().unwrap();
+    // Root port (CPU) -> upstream / downstream port of the GPU's switch -> GPU
+    let gpu = write_bridge_chain(
+      root.path(),
+      &[
+        (
+          "0000:00:01.1",
+          "0x1022",
+          "0x060400",
+          "8.0 GT/s PCIe",
+          "8",
+          "8",
+        ),
+        (
+          "0000:01:00.0",
+          "0x1002",
+          "0x060400",
+          "8.0 GT/s PCIe",
+          "8",
+          "16",
+        ),
+        (
+          "0000:02:00.0",
+          "0x1002",
+          "0x060400",
+          "16.0 GT/s PCIe",
+          "16",
+          "16",
+        ),
+        (
+          "0000:03:00.0",
+          "0x1002",
+          "0x030000",
+          "16.0 GT/s PCIe",
+          "16",
+          "16",
+        ),
+      ],
+    );
+
+    let status = read_pcie_link_status(&gpu).unwrap();
+
+    // Link of the switch upstream port, not the internal one
+    assert_eq!(status.current_generation, Some(3));
+    assert_eq!(status.current_width, Some(8));
+    // Compared against the root port it is plugged into
+    assert_eq!(status.slot_max_width, Some(8));
+    assert!(status.is_slot_limited);
+    assert!(status.is_width_degraded);
+    assert!(status.is_speed_degraded);
+  }
+
+  #[test]
+  fn test_read_pcie_link_status_direct() {
+    let root = tempfile::tempdir().unwrap();
+    // Root port of the same vendor is not climbed
+    let nvme = write_bridge_chain(
+      root.path(),
+      &[
+        (
+          "0000:00:1d.0",
+          "0x8086",
+          "0x060400",
+          "16.0 GT/s PCIe",
+          "4",
+          "4",
+        ),
+        (
+          "0000:04:00.0",
+          "0x144d",
+          "0x010802",
+          "16.0 GT/s PCIe",
+          "4",
+          "4",
+        ),
+      ],
+    );
+    let arc = write_bridge_chain(
+      root.path(),
+      &[
+        (
+          "0000:00:01.0",
+          "0x8086",
+          "0x060400",
+          "16.0 GT/s PCIe",
+          "16",
+          "16",
+        ),
+        (
+          "0000:05:00.0",
+          "0x8086",
+          "0x060400",
+          "2.5 GT/s PCIe",
+          "1",
+          "16",
+        ),
+        (
+          "0000:06:01.0",
+          "0x8086",
+          "0x060400",
+          "2.5 GT/s PCIe",
+          "1",
+          "16",
+        ),
+        (
+          "0000:07:00.0",
+          "0x8086",
+          "0x030000",
+          "2.5 GT/s PCIe",
+          "1",
+          "16",
+        ),
+      ],
+    );
+
+    let nvme = read_pcie_link_status(&nvme).unwrap();
+    assert_eq!(nvme.current_generation, Some(4));
+    assert_eq!(nvme.current_width, Some(4));
+    assert_eq!(nvme.slot_max_generation, Some(4));
+    assert!(!nvme.is_slot_limited);
+    assert!(!nvme.is_width_degraded);
+
+    // Stops at the switch upstream port below the root port
+    let arc = read_pcie_link_status(&arc).unwrap();
+    assert_eq!(arc.current_generation, Some(1));
+    assert_eq!(arc.current_width, Some(1));
+    // Trained down although both ends support x16
+    assert!(!arc.is_slot_limited);
+    assert!(arc.is_width_degraded);
+  }
+}
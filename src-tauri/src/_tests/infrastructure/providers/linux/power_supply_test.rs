@@ -0,0 +1,164 @@
+#[cfg(target_os = "linux")]
+#[cfg(test)]
+mod tests {
+  use crate::infrastructure::providers::linux::power_supply::{
+    read_battery, read_power_supply_info,
+  };
+  use std::fs;
+  use std::path::Path;
+
+  fn write_supply(root: &Path, name: &str, attributes: &[(&str, &str)]) {
+    let dir = root.join(name);
+    fs::create_dir_all(&dir).unwrap();
+    for (attribute, value) in attributes {
+      fs::write(dir.join(attribute), format!("{value}\n")).unwrap();
+    }
+  }
+
+  #[test]
+  fn test_read_battery_energy_based() {
+    let root = tempfile::tempdir().unwrap();
+    write_supply(
+      root.path(),
+      "BAT0",
+      &[
+        ("type", "Battery"),
+        ("status", "Discharging"),
+        ("capacity", "80"),
+        ("power_now", "12500000"),
+        ("voltage_now", "11850000"),
+        ("cycle_count", "312"),
+        ("energy_now", "40000000"),
+        ("energy_full", "50000000"),
+        ("energy_full_design", "57000000"),
+      ],
+    );
+
+    let battery = read_battery(&root.path().join("BAT0"), "BAT0");
+
+    assert_eq!(battery.name, "BAT0");
+    assert_eq!(battery.status, "Discharging");
+    assert_eq!(battery.percentage, Some(80.0));
+    assert_eq!(battery.power_watts, Some(-12.5));
+    assert_eq!(battery.voltage, Some(11.85));
+    assert_eq!(battery.cycle_count, Some(312));
+    assert_eq!(battery.energy_full_wh, Some(50.0));
+    assert_eq!(battery.energy_full_design_wh, Some(57.0));
+    assert_eq!(battery.health, Some(87.7));
+    // 40 Wh / 12.5 W = 3.2 h
+    assert_eq!(battery.time_to_empty_minutes, Some(192.0));
+  }
+
+  #[test]
+  fn test_read_battery_charge_based() {
+    let root = tempfile::tempdir().unwrap();
+    write_supply(
+      root.path(),
+      "BAT1",
+      &[
+        ("type", "Battery"),
+        ("status", "Charging"),
+        ("current_now", "2000000"),
+        ("voltage_now", "12000000"),
+        ("voltage_min_design", "11000000"),
+        ("charge_now", "2000000"),
+        ("charge_full", "4000000"),
+        ("charge_full_design", "5000000"),
+      ],
+    );
+
+    let battery = read_battery(&root.path().join("BAT1"), "BAT1");
+
+    // 2 A * 12 V
+    assert_eq!(battery.power_watts, Some(24.0));
+    // Ah * design voltage
+    assert_eq!(battery.energy_full_wh, Some(44.0));
+    assert_eq!(battery.energy_full_design_wh, Some(55.0));
+    assert_eq!(battery.health, Some(80.0));
+    // No `capacity`: derived from charge_now / charge_full
+    assert_eq!(battery.percentage, Some(50.0));
+    assert_eq!(battery.time_to_empty_minutes, None);
+  }
+
+  #[test]
+  fn test_read_battery_prefers_reported_time_to_empty() {
+    let root = tempfile::tempdir().unwrap();
+    write_supply(
+      root.path(),
+      "BAT0",
+      &[
+        ("status", "Discharging"),
+        ("power_now", "10000000"),
+        ("energy_now", "40000000"),
+        ("time_to_empty_now", "7200"),
+      ],
+    );
+
+    let battery = read_battery(&root.path().join("BAT0"), "BAT0");
+
+    assert_eq!(battery.time_to_empty_minutes, Some(120.0));
+  }
+
+  #[test]
+  fn test_read_battery_missing_attributes() {
+    let root = tempfile::tempdir().unwrap();
+    write_supply(root.path(), "BAT0", &[("type", "Battery")]);
+
+    let battery = read_battery(&root.path().join("BAT0"), "BAT0");
+
+    assert_eq!(battery.status, "Unknown");
+    assert_eq!(battery.percentage, None);
+    assert_eq!(battery.power_watts, None);
+    assert_eq!(battery.health, None);
+    assert_eq!(battery.time_to_empty_minutes, None);
+  }
+
+  #[test]
+  fn test_read_power_supply_info() {
+    let root = tempfile::tempdir().unwrap();
+    write_supply(root.path(), "AC", &[("type", "Mains"), ("online", "1")]);
+    write_supply(
+      root.path(),
+      "BAT0",
+      &[("type", "Battery"), ("status", "Full"), ("capacity", "100")],
+    );
+    write_supply(
+      root.path(),
+      "hidpp_battery_0",
+      &[("type", "Battery"), ("scope", "Device"), ("capacity", "40")],
+    );
+
+    let info = read_power_supply_info(root.path());
+
+    assert_eq!(info.ac_online, Some(true));
+    assert_eq!(info.batteries.len(), 1);
+    assert_eq!(info.batteries[0].name, "BAT0");
+    assert_eq!(info.batteries[0].percentage, Some(100.0));
+  }
+
+  #[test]
+  fn test_read_power_supply_info_any_adapter_online() {
+    let root = tempfile::tempdir().unwrap();
+    write_supply(root.path(), "AC", &[("type", "Mains"), ("online", "0")]);
+    write_supply(
+      root.path(),
+      "ucsi-source-psy",
+      &[("type", "USB"), ("online", "1")],
+    );
+
+    let info = read_power_supply_info(root.path());
+
+    assert_eq!(info.ac_online, Some(true));
+    assert!(info.batteries.is_empty());
+  }
+
+  #[test]
+  fn test_read_power_supply_info_without_supplies() {
+    let root = tempfile::tempdir().unwrap();
+
+    let info = read_power_supply_info(root.path());
+
+    assert_eq!(info.ac_online, None);
+    assert!(info.batteries.is_empty());
+  }
+}
@@ -0,0 +1,95 @@
+#[cfg(target_os = "linux")]
+#[cfg(test)]
+mod tests {
+  use crate::infrastructure::providers::linux::powercap::{
+    energy_delta_uj, is_package_zone, read_rapl_counters,
+  };
+  use std::fs;
+  use std::path::Path;
+
+  fn write_zone(root: &Path, zone: &str, name: &str, energy_uj: &str) {
+    let dir = root.join(zone);
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("name"), format!("{name}\n")).unwrap();
+    fs::write(dir.join("energy_uj"), format!("{energy_uj}\n")).unwrap();
+    fs::write(dir.join("max_energy_range_uj"), "262143328850\n").unwrap();
+  }
+
+  #[test]
+  fn test_read_rapl_counters() {
+    let root = tempfile::tempdir().unwrap();
+    write_zone(root.path(), "intel-rapl:0", "package-0", "123456789");
+    write_zone(root.path(), "intel-rapl:0:0", "core", "4567");
+    write_zone(root.path(), "intel-rapl:0:2", "dram", "890");
+    // Control type directory and MMIO duplicate of the package are ignored
+    fs::create_dir_all(root.path().join("intel-rapl")).unwrap();
+    write_zone(root.path(), "intel-rapl-mmio:0", "package-0", "1");
+
+    let counters = read_rapl_counters(root.path()).unwrap();
+
+    assert_eq!(counters.len(), 3);
+    let package = &counters["intel-rapl:0"];
+    assert_eq!(package.name, "package-0");
+    assert_eq!(package.energy_uj, 123456789);
+    assert_eq!(package.max_energy_range_uj, 262143328850);
+    assert_eq!(counters["intel-rapl:0:0"].name, "core");
+    assert_eq!(counters["intel-rapl:0:2"].name, "dram");
+  }
+
+  #[test]
+  fn test_read_rapl_counters_missing_energy() {
+    let root = tempfile::tempdir().unwrap();
+    let dir = root.path().join("intel-rapl:0");
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(dir.join("name"), "package-0\n").unwrap();
+    fs::write(dir.join("max_energy_range_uj"), "262143328850\n").unwrap();
+
+    assert!(read_rapl_counters(root.path()).unwrap().is_empty());
+  }
+
+  #[test]
+  fn test_read_rapl_counters_missing_root() {
+    let root = tempfile::tempdir().unwrap();
+
+    assert!(
+      read_rapl_counters(&root.path().join("powercap"))
+        .unwrap()
+        .is_empty()
+    );
+  }
+
+  #[test]
+  fn test_energy_delta_uj() {
+    assert_eq!(
+      energy_delta_uj(1_000, 26_000, 262_143_328_850),
+      Some(25_000)
+    );
+    assert_eq!(energy_delta_uj(5_000, 5_000, 262_143_328_850), Some(0));
+  }
+
+  #[test]
+  fn test_energy_delta_uj_wraparound() {
+    let max = 262_143_328_850;
+
+    // max - 1_000 -> max -> 0 -> 4_000
+    assert_eq!(energy_delta_uj(max - 1_000, 4_000, max), Some(5_001));
+    assert_eq!(energy_delta_uj(max, 0, max), Some(1));
+    assert_eq!(energy_delta_uj(max, max, max), Some(0));
+  }
+
+  #[test]
+  fn test_energy_delta_uj_out_of_range() {
+    // Previous reading above the advertised range
+    assert_eq!(energy_delta_uj(5_000, 1_000, 4_000), None);
+    assert_eq!(energy_delta_uj(5_000, 1_000, 0), None);
+  }
+
+  #[test]
+  fn test_is_package_zone() {
+    assert!(is_package_zone("package-0"));
+    assert!(is_package_zone("package-1"));
+    assert!(!is_package_zone("core"));
+    assert!(!is_package_zone("dram"));
+    assert!(!is_package_zone("psys"));
+  }
+}
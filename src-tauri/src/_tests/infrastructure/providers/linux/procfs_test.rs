@@ -271,4 +271,298 @@ DirectMap1G:     2097152 kB"#;
     let result = parse_mem_total_from_content(content);
     assert_eq!(result.unwrap(), 32768000);
   }
+
+  mod diskstats {
+    use crate::infrastructure::providers::linux::procfs::parse_diskstats;
+
+    const DISKSTATS: &str = r#" 259       0 nvme0n1 120345 2301 9876543 45678 98765 4321 7654321 23456 0 67890 71234 0 0 0 0 1234 2100
+ 259       1 nvme0n1p1 512 0 20480 120 4 0 32 8 0 140 128 0 0 0 0 0 0
+   8       0 sda 5000 10 400000 3000 2000 20 160000 1500 0 4200 4500
+ 253       0 dm-0 110000 0 9500000 44000 97000 0 7600000 25000 0 66000 69000 0 0 0 0 0 0
+   7       0 loop0 64 0 2048 12 0 0 0 0 0 16 12 0 0 0 0 0 0
+   1       0 ram0 0 0 0 0 0 0 0 0 0 0 0
+ 252       0 zram0 100 0 800 1 200 0 1600 2 0 3 3 0 0 0 0 0 0"#;
+
+    #[test]
+    fn test_parse_diskstats_reads_counters() {
+      let counters = parse_diskstats(DISKSTATS);
+      let nvme = &counters["nvme0n1"];
+
+      assert_eq!(nvme.reads_completed, 120345);
+      assert_eq!(nvme.sectors_read, 9876543);
+      assert_eq!(nvme.read_time_ms, 45678);
+      assert_eq!(nvme.writes_completed, 98765);
+      assert_eq!(nvme.sectors_written, 7654321);
+      assert_eq!(nvme.write_time_ms, 23456);
+      assert_eq!(nvme.io_time_ms, 67890);
+    }
+
+    #[test]
+    fn test_parse_diskstats_includes_partitions_and_mapper_devices() {
+      let counters = parse_diskstats(DISKSTATS);
+
+      assert!(counters.contains_key("nvme0n1p1"));
+      assert!(counters.contains_key("dm-0"));
+    }
+
+    #[test]
+    fn test_parse_diskstats_legacy_format() {
+      // Kernels before 4.18 only expose 11 stat fields
+      let counters = parse_diskstats(DISKSTATS);
+      let sda = &counters["sda"];
+
+      assert_eq!(sda.reads_completed, 5000);
+      assert_eq!(sda.sectors_written, 160000);
+      assert_eq!(sda.io_time_ms, 4200);
+    }
+
+    #[test]
+    fn test_parse_diskstats_skips_virtual_devices() {
+      let counters = parse_diskstats(DISKSTATS);
+
+      assert!(!counters.contains_key("loop0"));
+      assert!(!counters.contains_key("ram0"));
+      assert!(!counters.contains_key("zram0"));
+      assert_eq!(counters.len(), 4);
+    }
+
+    #[test]
+    fn test_parse_diskstats_skips_malformed_lines() {
+      let content = "   8       0 sda 1 2 3\n   8      16 sdb a b c d e f g h i j k\n";
+
+      assert!(parse_diskstats(content).is_empty());
+    }
+
+    #[test]
+    fn test_parse_diskstats_empty() {
+      assert!(parse_diskstats("").is_empty());
+    }
+  }
+
+  mod cpu_times {
+    use crate::infrastructure::providers::linux::procfs::parse_cpu_times;
+
+    const STAT: &str = r#"cpu  4705 356 584 3699176 23060 0 277 120 0 0
+cpu0 1393 280 234 1848880 11522 0 146 60 0 0
+cpu1 3312 76 350 1850296 11538 0 131 60 0 0
+intr 114930548 113199788 3 0 5 263 0 4 [...]
+ctxt 1990473
+btime 1062191376
+processes 2915
+procs_running 1
+procs_blocked 0"#;
+
+    #[test]
+    fn test_parse_cpu_times_aggregate_and_cores() {
+      let times = parse_cpu_times(STAT);
+
+      assert_eq!(times.len(), 3);
+      assert!(times.contains_key("cpu"));
+      assert!(times.contains_key("cpu0"));
+      assert!(times.contains_key("cpu1"));
+    }
+
+    #[test]
+    fn test_parse_cpu_times_reads_columns() {
+      let times = parse_cpu_times(STAT);
+      let cpu = &times["cpu"];
+
+      assert_eq!(cpu.user, 4705);
+      assert_eq!(cpu.nice, 356);
+      assert_eq!(cpu.system, 584);
+      assert_eq!(cpu.idle, 3699176);
+      assert_eq!(cpu.iowait, 23060);
+      assert_eq!(cpu.irq, 0);
+      assert_eq!(cpu.softirq, 277);
+      assert_eq!(cpu.steal, 120);
+    }
+
+    #[test]
+    fn test_parse_cpu_times_old_kernel_defaults_missing_columns() {
+      let times = parse_cpu_times("cpu  100 2 30 400\n");
+      let cpu = &times["cpu"];
+
+      assert_eq!(cpu.idle, 400);
+      assert_eq!(cpu.iowait, 0);
+      assert_eq!(cpu.steal, 0);
+    }
+
+    #[test]
+    fn test_parse_cpu_times_skips_malformed_lines() {
+      let times = parse_cpu_times("cpu  1 2 3\ncpu0 a b c d\n");
+
+      assert!(times.is_empty());
+    }
+  }
+
+  mod pressure {
+    use crate::infrastructure::providers::linux::procfs::{
+      parse_loadavg, parse_pressure,
+    };
+
+    #[test]
+    fn test_parse_pressure_some_and_full() {
+      let content = "some avg10=1.53 avg60=0.87 avg300=0.22 total=10203040\n\
+                     full avg10=0.50 avg60=0.25 avg300=0.05 total=4000\n";
+      let pressure = parse_pressure(content).unwrap();
+
+      assert_eq!(pressure.some.avg10, 1.53);
+      assert_eq!(pressure.some.avg60, 0.87);
+      assert_eq!(pressure.some.avg300, 0.22);
+      assert_eq!(pressure.some.total_ms, 10203.04);
+
+      let full = pressure.full.unwrap();
+      assert_eq!(full.avg10, 0.5);
+      assert_eq!(full.total_ms, 4.0);
+    }
+
+    #[test]
+    fn test_parse_pressure_cpu_without_full() {
+      // cpu has no `full` line before Linux 5.13
+      let pressure =
+        parse_pressure("some avg10=0.00 avg60=0.00 avg300=0.00 total=0\n").unwrap();
+
+      assert_eq!(pressure.some.total_ms, 0.0);
+      assert!(pressure.full.is_none());
+    }
+
+    #[test]
+    fn test_parse_pressure_invalid() {
+      assert!(parse_pressure("").is_none());
+      assert!(
+        parse_pressure("some avg10=abc avg60=0.00 avg300=0.00 total=0\n").is_none()
+      );
+      assert!(
+        parse_pressure("full avg10=0.00 avg60=0.00 avg300=0.00 total=0\n").is_none()
+      );
+    }
+
+    #[test]
+    fn test_parse_loadavg() {
+      let load = parse_loadavg("0.52 0.58 0.59 2/1024 12345\n").unwrap();
+
+      assert_eq!(load.one, 0.52);
+      assert_eq!(load.five, 0.58);
+      assert_eq!(load.fifteen, 0.59);
+      assert_eq!(load.running, 2);
+      assert_eq!(load.total, 1024);
+    }
+
+    #[test]
+    fn test_parse_loadavg_invalid() {
+      assert!(parse_loadavg("").is_none());
+      assert!(parse_loadavg("0.52 0.58 0.59\n").is_none());
+      assert!(parse_loadavg("0.52 0.58 0.59 2-1024 12345\n").is_none());
+    }
+  }
+
+  mod meminfo {
+    use crate::infrastructure::providers::linux::procfs::{
+      parse_meminfo, parse_memory_breakdown,
+    };
+
+    const MEMINFO: &str = r#"MemTotal:       16384000 kB
+MemFree:         2048000 kB
+MemAvailable:    8192000 kB
+Buffers:          512000 kB
+Cached:          6144000 kB
+SwapCached:        10240 kB
+Active:          4096000 kB
+Inactive:        1024000 kB
+SwapTotal:       4194304 kB
+SwapFree:        3145728 kB
+Dirty:              2048 kB
+Writeback:             0 kB
+Shmem:            307200 kB
+Slab:             716800 kB
+SReclaimable:     512000 kB
+CommitLimit:    12386304 kB
+Committed_AS:   10240000 kB
+HugePages_Total:       4
+HugePages_Free:        2
+Hugepagesize:       2048 kB"#;
+
+    #[test]
+    fn test_parse_meminfo_entries() {
+      let entries = parse_meminfo(MEMINFO);
+
+      assert_eq!(entries["MemTotal"], 16384000);
+      assert_eq!(entries["Committed_AS"], 10240000);
+      assert_eq!(entries["HugePages_Total"], 4);
+    }
+
+    #[test]
+    fn test_parse_memory_breakdown_in_mib() {
+      let breakdown = parse_memory_breakdown(MEMINFO).unwrap();
+
+      assert_eq!(breakdown.total, 16000.0);
+      assert_eq!(breakdown.free, 2000.0);
+      assert_eq!(breakdown.available, 8000.0);
+      assert_eq!(breakdown.buffers, 500.0);
+      assert_eq!(breakdown.cached, 6000.0);
+      assert_eq!(breakdown.swap_cached, 10.0);
+      assert_eq!(breakdown.dirty, 2.0);
+      assert_eq!(breakdown.writeback, 0.0);
+      assert_eq!(breakdown.slab, 700.0);
+      assert_eq!(breakdown.slab_reclaimable, 500.0);
+      assert_eq!(breakdown.shmem, 300.0);
+      assert_eq!(breakdown.swap_total, 4096.0);
+      assert_eq!(breakdown.swap_free, 3072.0);
+      assert_eq!(breakdown.committed, 10000.0);
+      assert_eq!(breakdown.commit_limit, 12096.0);
+      assert_eq!(breakdown.hugepages_total, 4);
+      assert_eq!(breakdown.hugepages_free, 2);
+      assert_eq!(breakdown.hugepage_size, 2.0);
+    }
+
+    #[test]
+    fn test_parse_memory_breakdown_missing_entries_default_to_zero() {
+      let breakdown = parse_memory_breakdown("MemTotal:       1024 kB\n").unwrap();
+
+      assert_eq!(breakdown.total, 1.0);
+      assert_eq!(breakdown.swap_total, 0.0);
+      assert_eq!(breakdown.hugepages_total, 0);
+    }
+
+    #[test]
+    fn test_parse_memory_breakdown_requires_mem_total() {
+      assert!(parse_memory_breakdown("MemFree:         2048000 kB\n").is_none());
+      assert!(parse_memory_breakdown("").is_none());
+    }
+  }
+  mod mounts {
+    use crate::infrastructure::providers::linux::procfs::parse_mounts;
+
+    const MOUNTS: &str = r#"proc /proc proc rw,nosuid,nodev,noexec,relatime 0 0
+/dev/mapper/vg0-root / ext4 rw,relatime 0 0
+/dev/nvme0n1p1 /boot/efi vfat rw,relatime,fmask=0077,dmask=0077 0 0
+/dev/md0 /srv/my\040data xfs rw,relatime 0 0
+tmpfs /tmp tmpfs rw,nosuid,nodev 0 0
+"#;
+
+    #[test]
+    fn test_parse_mounts() {
+      let mounts = parse_mounts(MOUNTS);
+
+      assert_eq!(mounts.len(), 5);
+      assert_eq!(mounts[1].source, "/dev/mapper/vg0-root");
+      assert_eq!(mounts[1].mount_point, "/");
+      assert_eq!(mounts[1].file_system, "ext4");
+      assert_eq!(mounts[2].file_system, "vfat");
+    }
+
+    #[test]
+    fn test_parse_mounts_unescapes_octal() {
+      let mounts = parse_mounts(MOUNTS);
+
+      assert_eq!(mounts[3].mount_point, "/srv/my data");
+      assert_eq!(parse_mounts("a\\b /x\\1 ext4")[0].mount_point, "/x\\1");
+    }
+
+    #[test]
+    fn test_parse_mounts_skips_incomplete_lines() {
+      assert!(parse_mounts("").is_empty());
+      assert!(parse_mounts("/dev/sda1 /mnt\n").is_empty());
+    }
+  }
 }
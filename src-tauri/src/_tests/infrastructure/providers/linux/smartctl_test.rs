@@ -0,0 +1,229 @@
+#[cfg(target_os = "linux")]
+#[cfg(test)]
+mod tests {
+  use crate::enums::hardware::DiskHealthStatus;
+  use crate::infrastructure::providers::linux::smartctl::{
+    health_status, parse_smartctl_json,
+  };
+  use crate::models::hardware::DiskHealth;
+
+  // smartctl --json -a /dev/nvme0 (trimmed)
+  const NVME_JSON: &str = r#"{
+  "json_format_version": [1, 0],
+  "smartctl": { "version": [7, 4], "exit_status": 0 },
+  "device": { "name": "/dev/nvme0", "type": "nvme", "protocol": "NVMe" },
+  "model_name": "Samsung SSD 980 PRO 1TB",
+  "serial_number": "S5GXNF0R123456A",
+  "firmware_version": "5B2QGXA7",
+  "nvme_total_capacity": 1000204886016,
+  "smart_status": { "passed": true, "nvme": { "value": 0 } },
+  "nvme_smart_health_information_log": {
+    "critical_warning": 0,
+    "temperature": 41,
+    "available_spare": 100,
+    "available_spare_threshold": 10,
+    "percentage_used": 3,
+    "data_units_read": 41867237,
+    "data_units_written": 52393164,
+    "power_cycles": 1492,
+    "power_on_hours": 6311,
+    "unsafe_shutdowns": 61,
+    "media_errors": 0,
+    "num_err_log_entries": 3012
+  },
+  "temperature": { "current": 41 },
+  "power_cycle_count": 1492,
+  "power_on_time": { "hours": 6311 }
+}"#;
+
+  // smartctl --json -a /dev/sda of a failing HDD (trimmed, exit status 0x48)
+  const ATA_JSON: &str = r#"{
+  "smartctl": { "version": [7, 3], "exit_status": 72 },
+  "device": { "name": "/dev/sda", "type": "sat", "protocol": "ATA" },
+  "model_family": "Western Digital Blue",
+  "model_name": "WDC WD10EZEX-08WN4A0",
+  "serial_number": "WD-WCC6Y1234567",
+  "firmware_version": "01.01A01",
+  "smart_status": { "passed": true },
+  "ata_smart_attributes": {
+    "revision": 16,
+    "table": [
+      { "id": 1, "name": "Raw_Read_Error_Rate", "value": 200, "raw": { "value": 12, "string": "12" } },
+      { "id": 5, "name": "Reallocated_Sector_Ct", "value": 198, "raw": { "value": 24, "string": "24" } },
+      { "id": 9, "name": "Power_On_Hours", "value": 41, "raw": { "value": 43420, "string": "43420" } },
+      { "id": 194, "name": "Temperature_Celsius", "value": 112, "raw": { "value": 35, "string": "35" } },
+      { "id": 197, "name": "Current_Pending_Sector", "value": 200, "raw": { "value": 2, "string": "2" } },
+      { "id": 198, "name": "Offline_Uncorrectable", "value": 200, "raw": { "value": 0, "string": "0" } }
+    ]
+  },
+  "power_on_time": { "hours": 43420 },
+  "temperature": { "current": 35 }
+}"#;
+
+  #[test]
+  fn test_parse_smartctl_json_nvme() {
+    let health = parse_smartctl_json("nvme0n1", NVME_JSON).unwrap();
+
+    assert_eq!(
+      health,
+      DiskHealth {
+        device: "nvme0n1".to_string(),
+        model: Some("Samsung SSD 980 PRO 1TB".to_string()),
+        serial: Some("S5GXNF0R123456A".to_string()),
+        firmware: Some("5B2QGXA7".to_string()),
+        mount_points: vec![],
+        smart_passed: Some(true),
+        power_on_hours: Some(6311),
+        temperature: Some(41.0),
+        reallocated_sectors: None,
+        pending_sectors: None,
+        uncorrectable_sectors: None,
+        percentage_used: Some(3),
+        media_errors: Some(0),
+        critical_warning: Some(0),
+        status: DiskHealthStatus::Unknown,
+      }
+    );
+    assert_eq!(health_status(&health), DiskHealthStatus::Good);
+  }
+
+  // smartctl --json -a /dev/sdb of a Seagate HDD (trimmed), with vendor fields packed
+  // into the upper bytes of the raw values and no top-level `temperature`
+  const ATA_PACKED_JSON: &str = r#"{
+  "smartctl": { "version": [7, 4], "exit_status": 0 },
+  "device": { "name": "/dev/sdb", "type": "sat", "protocol": "ATA" },
+  "model_family": "Seagate BarraCuda 3.5",
+  "model_name": "ST2000DM008-2FR102",
+  "serial_number": "ZFL1ABCD",
+  "firmware_version": "0001",
+  "smart_status": { "passed": true },
+  "ata_smart_attributes": {
+    "revision": 10,
+    "table": [
+      { "id": 1, "name": "Raw_Read_Error_Rate", "value": 83, "raw": { "value": 206489016, "string": "206489016" } },
+      { "id": 5, "name": "Reallocated_Sector_Ct", "value": 100, "raw": { "value": 0, "string": "0" } },
+      { "id": 7, "name": "Seek_Error_Rate", "value": 87, "raw": { "value": 476925364, "string": "476925364" } },
+      { "id": 194, "name": "Temperature_Celsius", "value": 34, "raw": { "value": 85899345954, "string": "34 (Min/Max 0/20)" } },
+      { "id": 197, "name": "Current_Pending_Sector", "value": 100, "raw": { "value": 4294967296, "string": "0 (1 0)" } },
+      { "id": 198, "name": "Offline_Uncorrectable", "value": 100, "raw": { "value": 0, "string": "0" } }
+    ]
+  },
+  "power_on_time": { "hours": 12044 }
+}"#;
+
+  #[test]
+  fn test_parse_smartctl_json_ata() {
+    let health = parse_smartctl_json("sda", ATA_JSON).unwrap();
+
+    assert_eq!(health.model, Some("WDC WD10EZEX-08WN4A0".to_string()));
+    assert_eq!(health.power_on_hours, Some(43420));
+    assert_eq!(health.temperature, Some(35.0));
+    assert_eq!(health.reallocated_sectors, Some(24));
+    assert_eq!(health.pending_sectors, Some(2));
+    assert_eq!(health.uncorrectable_sectors, Some(0));
+    assert_eq!(health.percentage_used, None);
+    // Self-assessment still passes, but sectors are being remapped
+    assert_eq!(health_status(&health), DiskHealthStatus::Warning);
+  }
+
+  #[test]
+  fn test_parse_smartctl_json_ata_packed_raw() {
+    let health = parse_smartctl_json("sdb", ATA_PACKED_JSON).unwrap();
+
+    // Low byte of attribute 194
+    assert_eq!(health.temperature, Some(34.0));
+    // Vendor fields in the upper bytes are not counted
+    assert_eq!(health.reallocated_sectors, Some(0));
+    assert_eq!(health.pending_sectors, Some(0));
+    assert_eq!(health.uncorrectable_sectors, Some(0));
+    // Error rates are not part of the verdict
+    assert_eq!(health_status(&health), DiskHealthStatus::Good);
+  }
+
+  #[test]
+  fn test_parse_smartctl_json_without_smart() {
+    // USB bridge that does not pass SMART through
+    let json = r#"{
+  "smartctl": { "exit_status": 4 },
+  "device": { "name": "/dev/sdb", "type": "scsi" },
+  "model_name": "  ",
+  "serial_number": "0123456789AB"
+}"#;
+
+    let health = parse_smartctl_json("sdb", json).unwrap();
+
+    assert_eq!(health.model, None);
+    assert_eq!(health.serial, Some("0123456789AB".to_string()));
+    assert_eq!(health.smart_passed, None);
+    assert_eq!(health_status(&health), DiskHealthStatus::Unknown);
+  }
+
+  #[test]
+  fn test_parse_smartctl_json_invalid() {
+    assert!(parse_smartctl_json("sda", "").is_err());
+    assert!(parse_smartctl_json("sda", "smartctl: command not found").is_err());
+  }
+
+  #[test]
+  fn test_health_status_critical() {
+    let passed = DiskHealth {
+      smart_passed: Some(true),
+      ..Default::default()
+    };
+
+    assert_eq!(
+      health_status(&DiskHealth {
+        smart_passed: Some(false),
+        ..Default::default()
+      }),
+      DiskHealthStatus::Critical
+    );
+    // Available spare below threshold
+    assert_eq!(
+      health_status(&DiskHealth {
+        critical_warning: Some(0x01),
+        ..passed.clone()
+      }),
+      DiskHealthStatus::Critical
+    );
+    assert_eq!(
+      health_status(&DiskHealth {
+        percentage_used: Some(104),
+        ..passed.clone()
+      }),
+      DiskHealthStatus::Critical
+    );
+  }
+
+  #[test]
+  fn test_health_status_warning() {
+    let passed = DiskHealth {
+      smart_passed: Some(true),
+      percentage_used: Some(10),
+      ..Default::default()
+    };
+
+    assert_eq!(health_status(&passed), DiskHealthStatus::Good);
+    assert_eq!(
+      health_status(&DiskHealth {
+        percentage_used: Some(92),
+        ..passed.clone()
+      }),
+      DiskHealthStatus::Warning
+    );
+    assert_eq!(
+      health_status(&DiskHealth {
+        uncorrectable_sectors: Some(3),
+        ..passed.clone()
+      }),
+      DiskHealthStatus::Warning
+    );
+    assert_eq!(
+      health_status(&DiskHealth {
+        media_errors: Some(1),
+        ..passed.clone()
+      }),
+      DiskHealthStatus::Warning
+    );
+  }
+}
@@ -0,0 +1,580 @@
+#[cfg(target_os = "linux")]
+#[cfg(test)]
+mod tests {
+  use crate::infrastructure::providers::linux::smbios::{
+    SmbiosEntryPoint, SmbiosStructure, memory_type_name, parse_board_info,
+    parse_entry_point, parse_memory_info, parse_structures, read_smbios_structures,
+  };
+  use crate::models::hardware::{BoardInfo, MemoryModule};
+  use std::fs;
+
+  /// Encode one structure: header, formatted area and strings section
+  fn structure(kind: u8, handle: u16, formatted: &[u8], strings: &[&str]) -> Vec<u8> {
+    let mut bytes = vec![kind, (formatted.len() + 4) as u8];
+    bytes.extend_from_slice(&handle.to_le_bytes());
+    bytes.extend_from_slice(formatted);
+
+    if strings.is_empty() {
+      bytes.extend_from_slice(&[0, 0]);
+    } else {
+      for string in strings {
+        bytes.extend_from_slice(string.as_bytes());
+        bytes.push(0);
+      }
+      bytes.push(0);
+    }
+    bytes
+  }
+
+  /// Physical Memory Array (type 16, SMBIOS 2.7 layout)
+  fn memory_array(handle: u16, usage: u8, devices: u16) -> Vec<u8> {
+    let mut formatted = vec![0x03, usage, 0x03];
+    formatted.extend_from_slice(&0x0200_0000u32.to_le_bytes()); // Maximum Capacity
+    formatted.extend_from_slice(&0xfffeu16.to_le_bytes()); // Error Information Handle
+    formatted.extend_from_slice(&devices.to_le_bytes());
+    formatted.extend_from_slice(&0u64.to_le_bytes()); // Extended Maximum Capacity
+    structure(16, handle, &formatted, &[])
+  }
+
+  struct Device {
+    array: u16,
+    size: u16,
+    extended_size: u32,
+    memory_type: u8,
+    speed: u16,
+    configured_speed: u16,
+    attributes: u8,
+  }
+
+  /// Memory Device (type 17, SMBIOS 3.2 layout: 0x54 bytes)
+  ///
+  /// Strings: 1 locator, 2 bank locator, 3 manufacturer, 4 serial, 5 asset tag, 6 part
+  fn memory_device(handle: u16, device: Device, strings: &[&str]) -> Vec<u8> {
+    let mut formatted = vec![0u8; 0x54 - 4];
+    let mut put = |offset: usize, bytes: &[u8]| {
+      formatted[offset - 4..offset - 4 + bytes.len()].copy_from_slice(bytes);
+    };
+
+    put(0x04, &device.array.to_le_bytes());
+    put(0x06, &0xfffeu16.to_le_bytes());
+    put(0x08, &64u16.to_le_bytes());
+    put(0x0a, &64u16.to_le_bytes());
+    put(0x0c, &device.size.to_le_bytes());
+    put(0x0e, &[0x09]); // DIMM
+    put(0x10, &[1, 2, device.memory_type]);
+    put(0x13, &0x0080u16.to_le_bytes()); // Synchronous
+    put(0x15, &device.speed.to_le_bytes());
+    put(0x17, &[3, 4, 5, 6, device.attributes]);
+    put(0x1c, &device.extended_size.to_le_bytes());
+    put(0x20, &device.configured_speed.to_le_bytes());
+    structure(17, handle, &formatted, strings)
+  }
+
+  /// Two populated DDR5 DIMMs, one empty slot and a flash array device
+  fn desktop_table() -> Vec<u8> {
+    let mut table = Vec::new();
+
+    // BIOS: vendor, version, starting segment, release date
+    let mut bios = vec![1, 2, 0x00, 0xf0, 3, 0xff];
+    bios.extend_from_slice(&[0u8; 8]);
+    table.extend(structure(
+      0,
+      0x0000,
+      &bios,
+      &["American Megatrends Inc.", "1813", "10/13/2023"],
+    ));
+    table.extend(structure(
+      1,
+      0x0001,
+      &[1, 2, 3, 4],
+      &[
+        "ASUS",
+        "System Product Name",
+        "System Version",
+        "System Serial Number",
+      ],
+    ));
+    table.extend(structure(
+      2,
+      0x0002,
+      &[1, 2, 3, 4, 5],
+      &[
+        "ASUSTeK COMPUTER INC.",
+        "ROG STRIX B650E-F GAMING WIFI",
+        "Rev 1.xx",
+        "230912345678901",
+        "Default string",
+      ],
+    ));
+    table.extend(structure(
+      3,
+      0x0003,
+      &[1, 0x03, 2, 3],
+      &["Default string", "Default string", "Default string"],
+    ));
+
+    table.extend(memory_array(0x0010, 0x03, 3));
+    table.extend(memory_device(
+      0x0011,
+      Device {
+        array: 0x0010,
+        size: 0x7fff,
+        extended_size: 32768,
+        memory_type: 0x22,
+        speed: 6000,
+        configured_speed: 4800,
+        attributes: 0x02,
+      },
+      &[
+        "DIMM_A1",
+        "BANK 0",
+        "Kingston",
+        "1A2B3C4D",
+        "Not Specified",
+        "KF560C36-32",
+      ],
+    ));
+    table.extend(memory_device(
+      0x0012,
+      Device {
+        array: 0x0010,
+        size: 0,
+        extended_size: 0,
+        memory_type: 0x02,
+        speed: 0,
+        configured_speed: 0,
+        attributes: 0,
+      },
+      &[
+        "DIMM_A2",
+        "BANK 1",
+        "Unknown",
+        "Unknown",
+        "Not Specified",
+        "Unknown",
+      ],
+    ));
+    table.extend(memory_device(
+      0x0013,
+      Device {
+        array: 0x0010,
+        size: 16384,
+        extended_size: 0,
+        memory_type: 0x22,
+        speed: 0xffff,
+        configured_speed: 4800,
+        attributes: 0x01,
+      },
+      &[
+        "DIMM_B1",
+        "BANK 2",
+        "Samsung",
+        "Not Specified",
+        "Not Specified",
+        "M323R2GA3BB0-CQKOL",
+      ],
+    ));
+
+    // Flash array of the firmware (not system memory)
+    table.extend(memory_array(0x0020, 0x07, 1));
+    table.extend(memory_device(
+      0x0021,
+      Device {
+        array: 0x0020,
+        size: 0x8000 | 8192,
+        extended_size: 0,
+        memory_type: 0x09,
+        speed: 0,
+        configured_speed: 0,
+        attributes: 0,
+      },
+      &["SPI", "BIOS", "Winbond", "-", "-", "W25Q256"],
+    ));
+
+    table.extend(structure(127, 0xfeff, &[], &[]));
+    table
+  }
+
+  /// 64-bit (`_SM3_`) entry point of SMBIOS 3.5
+  fn entry_point_v3(table_length: u32) -> Vec<u8> {
+    let mut bytes = b"_SM3_".to_vec();
+    bytes.extend_from_slice(&[0x00, 0x18, 3, 5, 0, 0x01, 0x00]);
+    bytes.extend_from_slice(&table_length.to_le_bytes());
+    bytes.extend_from_slice(&0x7a8f_3000u64.to_le_bytes());
+    bytes
+  }
+
+  #[test]
+  fn test_parse_entry_point_v3() {
+    assert_eq!(
+      parse_entry_point(&entry_point_v3(0x1234)),
+      Some(SmbiosEntryPoint {
+        major: 3,
+        minor: 5,
+        table_length: 0x1234,
+      })
+    );
+  }
+
+  #[test]
+  fn test_parse_entry_point_v2() {
+    let mut bytes = b"_SM_".to_vec();
+    bytes.extend_from_slice(&[0x00, 0x1f, 2, 8, 0xff, 0x00]);
+    bytes.extend_from_slice(&[0u8; 6]);
+    bytes.extend_from_slice(b"_DMI_");
+    bytes.push(0x00);
+    bytes.extend_from_slice(&0x0b4du16.to_le_bytes());
+    bytes.extend_from_slice(&0x000e_b000u32.to_le_bytes());
+    bytes.extend_from_slice(&[0x40, 0x00, 0x28]);
+
+    assert_eq!(
+      parse_entry_point(&bytes),
+      Some(SmbiosEntryPoint {
+        major: 2,
+        minor: 8,
+        table_length: 0x0b4d,
+      })
+    );
+  }
+
+  #[test]
+  fn test_parse_entry_point_invalid() {
+    assert_eq!(parse_entry_point(b"_DMI_"), None);
+    assert_eq!(parse_entry_point(b"_SM3_"), None);
+  }
+
+  #[test]
+  fn test_parse_structures() {
+    let structures = parse_structures(&desktop_table());
+
+    let kinds: Vec<u8> = structures.iter().map(|s| s.kind).collect();
+    assert_eq!(kinds, vec![0, 1, 2, 3, 16, 17, 17, 17, 16, 17, 127]);
+    assert_eq!(structures[0].strings[0], "American Megatrends Inc.");
+    assert!(structures[4].strings.is_empty());
+  }
+
+  #[test]
+  fn test_parse_structures_truncated() {
+    let table = desktop_table();
+    // Cut in the middle of the second structure
+    let structures = parse_structures(&table[..70]);
+
+    assert_eq!(structures.len(), 1);
+    assert_eq!(structures[0].kind, 0);
+  }
+
+  #[test]
+  fn test_parse_memory_info() {
+    let info = parse_memory_info(&parse_structures(&desktop_table())).unwrap();
+
+    assert!(info.is_detailed);
+    assert_eq!(info.size, "48.0 GB");
+    assert_eq!(info.clock, 2400);
+    assert_eq!(info.clock_unit, "MHz");
+    assert_eq!(info.memory_count, 2);
+    // The flash device is not counted
+    assert_eq!(info.total_slots, 3);
+    assert_eq!(info.memory_type, "DDR5");
+  }
+
+  #[test]
+  fn test_parse_memory_modules() {
+    let info = parse_memory_info(&parse_structures(&desktop_table())).unwrap();
+
+    assert_eq!(
+      info.modules,
+      vec![
+        MemoryModule {
+          locator: "DIMM_A1".to_string(),
+          bank_locator: Some("BANK 0".to_string()),
+          is_empty: false,
+          // Extended Size
+          size_mb: Some(32768),
+          memory_type: Some("DDR5".to_string()),
+          rated_speed_mts: Some(6000),
+          configured_speed_mts: Some(4800),
+          manufacturer: Some("Kingston".to_string()),
+          part_number: Some("KF560C36-32".to_string()),
+          serial_number: Some("1A2B3C4D".to_string()),
+          rank: Some(2),
+        },
+        MemoryModule {
+          locator: "DIMM_A2".to_string(),
+          bank_locator: Some("BANK 1".to_string()),
+          is_empty: true,
+          ..Default::default()
+        },
+        MemoryModule {
+          locator: "DIMM_B1".to_string(),
+          bank_locator: Some("BANK 2".to_string()),
+          is_empty: false,
+          size_mb: Some(16384),
+          memory_type: Some("DDR5".to_string()),
+          // 0xFFFF points to Extended Speed, which a 3.2 structure lacks
+          rated_speed_mts: None,
+          configured_speed_mts: Some(4800),
+          manufacturer: Some("Samsung".to_string()),
+          part_number: Some("M323R2GA3BB0-CQKOL".to_string()),
+          serial_number: None,
+          rank: Some(1),
+        },
+      ]
+    );
+  }
+
+  #[test]
+  fn test_parse_memory_info_short_structure() {
+    // SMBIOS 2.3 Memory Device (0x1B bytes): no rank, extended size or configured speed
+    let mut formatted = vec![0u8; 0x1b - 4];
+    formatted[0x0c - 4..0x0e - 4].copy_from_slice(&2048u16.to_le_bytes());
+    formatted[0x10 - 4] = 1;
+    formatted[0x12 - 4] = 0x13;
+    formatted[0x15 - 4..0x17 - 4].copy_from_slice(&667u16.to_le_bytes());
+
+    let mut table = structure(17, 0x0011, &formatted, &["DIMM0"]);
+    table.extend(structure(127, 0xfeff, &[], &[]));
+
+    let info = parse_memory_info(&parse_structures(&table)).unwrap();
+
+    assert_eq!(info.size, "2.0 GB");
+    assert_eq!(info.clock, 0);
+    assert_eq!(
+      info.modules,
+      vec![MemoryModule {
+        locator: "DIMM0".to_string(),
+        is_empty: false,
+        size_mb: Some(2048),
+        memory_type: Some("DDR2".to_string()),
+        rated_speed_mts: Some(667),
+        ..Default::default()
+      }]
+    );
+  }
+
+  #[test]
+  fn test_parse_memory_info_without_devices() {
+    let table = structure(127, 0xfeff, &[], &[]);
+    assert!(parse_memory_info(&parse_structures(&table)).is_none());
+  }
+
+  #[test]
+  fn test_parse_board_info() {
+    let info = parse_board_info(&parse_structures(&desktop_table()));
+
+    assert_eq!(
+      info,
+      Some(BoardInfo {
+        board_vendor: Some("ASUSTeK COMPUTER INC.".to_string()),
+        board_name: Some("ROG STRIX B650E-F GAMING WIFI".to_string()),
+        board_version: Some("Rev 1.xx".to_string()),
+        bios_vendor: Some("American Megatrends Inc.".to_string()),
+        bios_version: Some("1813".to_string()),
+        bios_date: Some("10/13/2023".to_string()),
+        system_vendor: Some("ASUS".to_string()),
+        product_name: None,
+        chassis_type: Some("Desktop".to_string()),
+      })
+    );
+  }
+
+  #[test]
+  fn test_memory_type_name() {
+    assert_eq!(memory_type_name(0x1a), Some("DDR4"));
+    assert_eq!(memory_type_name(0x23), Some("LPDDR5"));
+    assert_eq!(memory_type_name(0x02), None);
+    assert_eq!(memory_type_name(0x07), None);
+  }
+
+  #[test]
+  fn test_read_smbios_structures() {
+    let dir = tempfile::tempdir().unwrap();
+    let table = desktop_table();
+    fs::write(
+      dir.path().join("smbios_entry_point"),
+      entry_point_v3(table.len() as u32),
+    )
+    .unwrap();
+    fs::write(dir.path().join("DMI"), &table).unwrap();
+
+    let structures = read_smbios_structures(dir.path()).unwrap();
+
+    assert_eq!(structures.len(), 11);
+  }
+
+  #[test]
+  fn test_read_smbios_structures_unreadable() {
+    let dir = tempfile::tempdir().unwrap();
+    assert!(read_smbios_structures(dir.path()).is_err());
+
+    fs::write(dir.path().join("smbios_entry_point"), b"garbage").unwrap();
+    fs::write(dir.path().join("DMI"), desktop_table()).unwrap();
+    assert!(read_smbios_structures(dir.path()).is_err());
+  }
+
+  // Fixture tables (see `_tests/fixtures/smbios/README.md`)
+  const I440FX_ENTRY_POINT: &[u8] =
+    include_bytes!("../../../fixtures/smbios/qemu-pc-i440fx-2.8/smbios_entry_point");
+  const I440FX_DMI: &[u8] =
+    include_bytes!("../../../fixtures/smbios/qemu-pc-i440fx-2.8/DMI");
+  const Q35_ENTRY_POINT: &[u8] =
+    include_bytes!("../../../fixtures/smbios/qemu-q35-3.0/smbios_entry_point");
+  const Q35_DMI: &[u8] = include_bytes!("../../../fixtures/smbios/qemu-q35-3.0/DMI");
+
+  fn read_fixture(entry_point: &[u8], dmi: &[u8]) -> Vec<SmbiosStructure> {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("smbios_entry_point"), entry_point).unwrap();
+    fs::write(dir.path().join("DMI"), dmi).unwrap();
+
+    read_smbios_structures(dir.path()).unwrap()
+  }
+
+  #[test]
+  fn test_fixture_v2_entry_point() {
+    assert_eq!(
+      parse_entry_point(I440FX_ENTRY_POINT),
+      Some(SmbiosEntryPoint {
+        major: 2,
+        minor: 8,
+        table_length: I440FX_DMI.len() as u32,
+      })
+    );
+  }
+
+  #[test]
+  fn test_fixture_v2_structures() {
+    let structures = read_fixture(I440FX_ENTRY_POINT, I440FX_DMI);
+
+    let headers: Vec<(u8, u16, usize)> = structures
+      .iter()
+      .map(|s| (s.kind, s.handle, s.data.len()))
+      .collect();
+    assert_eq!(
+      headers,
+      vec![
+        (0, 0x0000, 24),
+        (1, 0x0100, 27),
+        (3, 0x0300, 22),
+        (4, 0x0400, 42),
+        (16, 0x1000, 23),
+        (17, 0x1100, 40),
+        (19, 0x1300, 31),
+        (32, 0x2000, 11),
+        (127, 0x7f00, 4),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_fixture_v2_board_info() {
+    let info = parse_board_info(&read_fixture(I440FX_ENTRY_POINT, I440FX_DMI));
+
+    // No Base Board Information (type 2) on this machine
+    assert_eq!(
+      info,
+      Some(BoardInfo {
+        board_vendor: None,
+        board_name: None,
+        board_version: None,
+        bios_vendor: Some("SeaBIOS".to_string()),
+        bios_version: Some("rel-1.16.3-0-ga6ed6b701f0a-prebuilt.qemu.org".to_string()),
+        bios_date: Some("04/01/2014".to_string()),
+        system_vendor: Some("QEMU".to_string()),
+        product_name: Some("Standard PC (i440FX + PIIX, 1996)".to_string()),
+        chassis_type: Some("Other".to_string()),
+      })
+    );
+  }
+
+  #[test]
+  fn test_fixture_v2_memory_info() {
+    let info = parse_memory_info(&read_fixture(I440FX_ENTRY_POINT, I440FX_DMI)).unwrap();
+
+    assert_eq!(info.size, "2.0 GB");
+    // Speed: Unknown
+    assert_eq!(info.clock, 0);
+    assert_eq!(info.memory_count, 1);
+    assert_eq!(info.total_slots, 1);
+    // Type: RAM
+    assert_eq!(info.memory_type, "Unknown");
+    assert_eq!(
+      info.modules,
+      vec![MemoryModule {
+        locator: "DIMM 0".to_string(),
+        is_empty: false,
+        size_mb: Some(2048),
+        manufacturer: Some("QEMU".to_string()),
+        ..Default::default()
+      }]
+    );
+  }
+
+  #[test]
+  fn test_fixture_v3_entry_point() {
+    assert_eq!(
+      parse_entry_point(Q35_ENTRY_POINT),
+      Some(SmbiosEntryPoint {
+        major: 3,
+        minor: 0,
+        table_length: Q35_DMI.len() as u32,
+      })
+    );
+  }
+
+  #[test]
+  fn test_fixture_v3_structures() {
+    let structures = read_fixture(Q35_ENTRY_POINT, Q35_DMI);
+
+    let headers: Vec<(u8, u16, usize)> = structures
+      .iter()
+      .map(|s| (s.kind, s.handle, s.data.len()))
+      .collect();
+    assert_eq!(
+      headers,
+      vec![
+        (0, 0x0000, 24),
+        (1, 0x0100, 27),
+        (2, 0x0200, 15),
+        (3, 0x0300, 22),
+        (4, 0x0400, 48),
+        (16, 0x1000, 23),
+        (17, 0x1100, 40),
+        (19, 0x1300, 31),
+        (19, 0x1301, 31),
+        (32, 0x2000, 11),
+        (127, 0x7f00, 4),
+      ]
+    );
+  }
+
+  #[test]
+  fn test_fixture_v3_board_info() {
+    let info = parse_board_info(&read_fixture(Q35_ENTRY_POINT, Q35_DMI));
+
+    assert_eq!(
+      info,
+      Some(BoardInfo {
+        board_vendor: Some("QEMU".to_string()),
+        board_name: Some("Standard PC (Q35 + ICH9, 2009)".to_string()),
+        board_version: Some("pc-q35-8.2".to_string()),
+        bios_vendor: Some("SeaBIOS".to_string()),
+        bios_version: Some("rel-1.16.3-0-ga6ed6b701f0a-prebuilt.qemu.org".to_string()),
+        bios_date: Some("04/01/2014".to_string()),
+        system_vendor: Some("QEMU".to_string()),
+        product_name: Some("Standard PC (Q35 + ICH9, 2009)".to_string()),
+        chassis_type: Some("Other".to_string()),
+      })
+    );
+  }
+
+  #[test]
+  fn test_fixture_v3_memory_info() {
+    let info = parse_memory_info(&read_fixture(Q35_ENTRY_POINT, Q35_DMI)).unwrap();
+
+    assert_eq!(info.size, "8.0 GB");
+    assert_eq!(info.memory_count, 1);
+    assert_eq!(info.total_slots, 1);
+    assert_eq!(info.modules[0].locator, "DIMM 0");
+    assert_eq!(info.modules[0].size_mb, Some(8192));
+    assert_eq!(info.modules[0].bank_locator, None);
+  }
+}
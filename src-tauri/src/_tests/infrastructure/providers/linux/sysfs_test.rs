@@ -0,0 +1,40 @@
+#[cfg(target_os = "linux")]
+#[cfg(test)]
+mod tests {
+  use crate::infrastructure::providers::linux::sysfs::{list_dir, read_attribute};
+  use std::fs;
+
+  #[test]
+  fn test_read_attribute() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("scaling_governor"), "schedutil\n").unwrap();
+    fs::write(
+      dir.path().join("model"),
+      "Samsung SSD 980 PRO 1TB         \n",
+    )
+    .unwrap();
+    fs::write(dir.path().join("empty"), "\n").unwrap();
+
+    assert_eq!(
+      read_attribute(dir.path(), "scaling_governor").as_deref(),
+      Some("schedutil")
+    );
+    assert_eq!(
+      read_attribute(dir.path(), "model").as_deref(),
+      Some("Samsung SSD 980 PRO 1TB")
+    );
+    assert_eq!(read_attribute(dir.path(), "empty"), None);
+    assert_eq!(read_attribute(dir.path(), "missing"), None);
+  }
+
+  #[test]
+  fn test_list_dir() {
+    let dir = tempfile::tempdir().unwrap();
+    for name in ["sda2", "sda10", "sda1"] {
+      fs::create_dir(dir.path().join(name)).unwrap();
+    }
+
+    assert_eq!(list_dir(dir.path()), vec!["sda1", "sda10", "sda2"]);
+    assert!(list_dir(&dir.path().join("missing")).is_empty());
+  }
+}
@@ -0,0 +1,98 @@
+#[cfg(target_os = "linux")]
+#[cfg(test)]
+mod tests {
+  use crate::infrastructure::providers::linux::thermal_sys::*;
+  use std::fs;
+  use std::path::Path;
+
+  fn create_hwmon(root: &Path, dir: &str, name: &str, sensors: &[(u32, &str, &str)]) {
+    let path = root.join(dir);
+    fs::create_dir_all(&path).unwrap();
+    fs::write(path.join("name"), format!("{name}\n")).unwrap();
+    for (index, label, value) in sensors {
+      fs::write(path.join(format!("temp{index}_input")), value).unwrap();
+      fs::write(path.join(format!("temp{index}_label")), label).unwrap();
+    }
+  }
+
+  fn create_thermal_zone(root: &Path, index: u32, zone_type: &str, temp: &str) {
+    let path = root.join(format!("thermal_zone{index}"));
+    fs::create_dir_all(&path).unwrap();
+    fs::write(path.join("type"), format!("{zone_type}\n")).unwrap();
+    fs::write(path.join("temp"), temp).unwrap();
+  }
+
+  #[test]
+  fn test_coretemp_package_and_cores() {
+    let root = tempfile::tempdir().unwrap();
+    create_hwmon(root.path(), "hwmon0", "acpitz", &[(1, "", "27800")]);
+    create_hwmon(
+      root.path(),
+      "hwmon4",
+      "coretemp",
+      &[
+        (1, "Package id 0", "55000"),
+        (2, "Core 0", "52000"),
+        (3, "Core 1", "54000"),
+      ],
+    );
+
+    let sensors = get_cpu_hwmon_temperatures(root.path());
+    let labels: Vec<&str> = sensors.iter().map(|s| s.label.as_str()).collect();
+
+    assert_eq!(labels, vec!["Package id 0", "Core 0", "Core 1"]);
+    assert_eq!(sensors[0].value, 55.0);
+  }
+
+  #[test]
+  fn test_k10temp_sensors() {
+    let root = tempfile::tempdir().unwrap();
+    create_hwmon(root.path(), "hwmon1", "amdgpu", &[(1, "edge", "40000")]);
+    create_hwmon(
+      root.path(),
+      "hwmon2",
+      "k10temp",
+      &[(1, "Tctl", "61250"), (3, "Tccd1", "58500")],
+    );
+
+    let sensors = get_cpu_hwmon_temperatures(root.path());
+
+    assert_eq!(sensors.len(), 2);
+    assert_eq!(sensors[0].label, "Tctl");
+    assert_eq!(sensors[0].value, 61.25);
+    assert_eq!(sensors[1].label, "Tccd1");
+  }
+
+  #[test]
+  fn test_no_cpu_hwmon_driver() {
+    let root = tempfile::tempdir().unwrap();
+    create_hwmon(root.path(), "hwmon0", "nvme", &[(1, "Composite", "35000")]);
+
+    assert!(get_cpu_hwmon_temperatures(root.path()).is_empty());
+  }
+
+  #[test]
+  fn test_thermal_zones_filtered_and_sorted() {
+    let root = tempfile::tempdir().unwrap();
+    create_thermal_zone(root.path(), 10, "x86_pkg_temp", "66000");
+    create_thermal_zone(root.path(), 0, "acpitz", "27800");
+    create_thermal_zone(root.path(), 2, "cpu0-thermal", "48000");
+    fs::create_dir_all(root.path().join("cooling_device0")).unwrap();
+
+    let zones = get_cpu_thermal_zone_temperatures(root.path());
+    let labels: Vec<&str> = zones.iter().map(|z| z.label.as_str()).collect();
+
+    assert_eq!(labels, vec!["cpu0-thermal", "x86_pkg_temp"]);
+    assert_eq!(zones[1].value, 66.0);
+  }
+
+  #[test]
+  fn test_is_cpu_thermal_zone() {
+    assert!(is_cpu_thermal_zone("x86_pkg_temp"));
+    assert!(is_cpu_thermal_zone("cpu-thermal"));
+    assert!(is_cpu_thermal_zone("CPU-therm"));
+    assert!(is_cpu_thermal_zone("soc_thermal"));
+    assert!(!is_cpu_thermal_zone("acpitz"));
+    assert!(!is_cpu_thermal_zone("iwlwifi_1"));
+  }
+}
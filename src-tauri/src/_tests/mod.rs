@@ -10,5 +10,8 @@ pub mod infrastructure;
 #[cfg(test)]
 pub mod models;
 
+#[cfg(test)]
+pub mod services;
+
 #[cfg(test)]
 pub mod utils;
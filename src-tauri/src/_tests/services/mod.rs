@@ -1 +1,3 @@
-pub mod memory_service_test;
+#[cfg(target_os = "linux")]
+#[cfg(test)]
+pub mod monitoring_service_test;
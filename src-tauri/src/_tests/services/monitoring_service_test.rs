@@ -0,0 +1,176 @@
+#[cfg(target_os = "linux")]
+#[cfg(test)]
+mod tests {
+  mod network {
+    use crate::models::hardware::NetworkCounters;
+    use crate::services::monitoring_service::calculate_network_usage;
+
+    fn counters(rx_bytes: u64, tx_bytes: u64) -> NetworkCounters {
+      NetworkCounters {
+        rx_bytes,
+        tx_bytes,
+        rx_packets: rx_bytes / 1000,
+        tx_packets: tx_bytes / 1000,
+        ..Default::default()
+      }
+    }
+
+    #[test]
+    fn test_calculate_network_usage() {
+      let prev = NetworkCounters {
+        rx_errors: 1,
+        tx_dropped: 4,
+        ..counters(1_000_000, 500_000)
+      };
+      let curr = NetworkCounters {
+        rx_errors: 3,
+        tx_dropped: 4,
+        ..counters(3_000_000, 600_000)
+      };
+
+      let usage = calculate_network_usage("eth0", &prev, &curr, 2.0).unwrap();
+
+      assert_eq!(usage.interface, "eth0");
+      assert_eq!(usage.rx_bytes_per_sec, 1_000_000.0);
+      assert_eq!(usage.tx_bytes_per_sec, 50_000.0);
+      assert_eq!(usage.rx_packets_per_sec, 1_000.0);
+      assert_eq!(usage.tx_packets_per_sec, 50.0);
+      assert_eq!(usage.rx_errors_per_sec, 1.0);
+      assert_eq!(usage.tx_dropped_per_sec, 0.0);
+    }
+
+    #[test]
+    fn test_calculate_network_usage_sub_second_interval() {
+      let usage =
+        calculate_network_usage("eth0", &counters(0, 0), &counters(500, 0), 0.5).unwrap();
+
+      assert_eq!(usage.rx_bytes_per_sec, 1_000.0);
+    }
+
+    #[test]
+    fn test_calculate_network_usage_interface_reset() {
+      // Counters restart from zero when the driver is reloaded
+      let usage = calculate_network_usage(
+        "eth0",
+        &counters(5_000_000, 5_000_000),
+        &counters(2_000, 0),
+        1.0,
+      )
+      .unwrap();
+
+      assert_eq!(usage.rx_bytes_per_sec, 0.0);
+      assert_eq!(usage.tx_bytes_per_sec, 0.0);
+    }
+
+    #[test]
+    fn test_calculate_network_usage_wraparound() {
+      // A wrapped counter must not be reported as a huge spike
+      let prev = counters(u32::MAX as u64 - 100, u64::MAX - 10);
+      let curr = counters(50, 20);
+
+      let usage = calculate_network_usage("wlan0", &prev, &curr, 1.0).unwrap();
+
+      assert_eq!(usage.rx_bytes_per_sec, 0.0);
+      assert_eq!(usage.tx_bytes_per_sec, 0.0);
+    }
+
+    #[test]
+    fn test_calculate_network_usage_zero_elapsed() {
+      let prev = counters(0, 0);
+      let curr = counters(1_000, 1_000);
+
+      assert!(calculate_network_usage("eth0", &prev, &curr, 0.0).is_none());
+    }
+  }
+
+  mod disk {
+    use crate::models::hardware::DiskCounters;
+    use crate::services::monitoring_service::calculate_disk_usage;
+
+    fn counters(
+      reads_completed: u64,
+      writes_completed: u64,
+      request_time_ms: u64,
+      io_time_ms: u64,
+    ) -> DiskCounters {
+      DiskCounters {
+        reads_completed,
+        sectors_read: reads_completed * 8,
+        read_time_ms: request_time_ms / 2,
+        writes_completed,
+        sectors_written: writes_completed * 16,
+        write_time_ms: request_time_ms / 2,
+        io_time_ms,
+      }
+    }
+
+    #[test]
+    fn test_calculate_disk_usage() {
+      let prev = counters(1_000, 500, 2_000, 10_000);
+      let curr = counters(1_200, 700, 2_800, 10_500);
+
+      let usage = calculate_disk_usage("nvme0n1", &prev, &curr, 2.0).unwrap();
+
+      assert_eq!(usage.device, "nvme0n1");
+      // 200 reads * 8 sectors * 512 bytes over 2 s
+      assert_eq!(usage.read_bytes_per_sec, 409_600.0);
+      assert_eq!(usage.write_bytes_per_sec, 819_200.0);
+      assert_eq!(usage.read_iops, 100.0);
+      assert_eq!(usage.write_iops, 100.0);
+      // 800 ms spent on 400 requests
+      assert_eq!(usage.avg_latency_ms, 2.0);
+      // 500 ms busy out of 2000 ms
+      assert_eq!(usage.utilization, 25.0);
+    }
+
+    #[test]
+    fn test_calculate_disk_usage_utilization_is_capped() {
+      // `io_ticks` can advance slightly more than the wall clock between samples
+      let usage = calculate_disk_usage(
+        "sda",
+        &counters(0, 0, 0, 0),
+        &counters(10, 0, 50, 1_050),
+        1.0,
+      )
+      .unwrap();
+
+      assert_eq!(usage.utilization, 100.0);
+    }
+
+    #[test]
+    fn test_calculate_disk_usage_zero_io_ticks_delta() {
+      // Idle device: no request completed and nothing in flight
+      let idle = counters(1_000, 500, 2_000, 10_000);
+
+      let usage = calculate_disk_usage("sda", &idle, &idle, 1.0).unwrap();
+
+      assert_eq!(usage.read_iops, 0.0);
+      assert_eq!(usage.write_bytes_per_sec, 0.0);
+      assert_eq!(usage.avg_latency_ms, 0.0);
+      assert_eq!(usage.utilization, 0.0);
+    }
+
+    #[test]
+    fn test_calculate_disk_usage_counter_reset() {
+      // Counters restart from zero when the device is removed and re-attached
+      let prev = counters(1_000, 500, 2_000, 10_000);
+      let curr = counters(10, 0, 20, 30);
+
+      let usage = calculate_disk_usage("sdb", &prev, &curr, 1.0).unwrap();
+
+      assert_eq!(usage.read_bytes_per_sec, 0.0);
+      assert_eq!(usage.read_iops, 0.0);
+      assert_eq!(usage.write_iops, 0.0);
+      assert_eq!(usage.avg_latency_ms, 0.0);
+      assert_eq!(usage.utilization, 0.0);
+    }
+
+    #[test]
+    fn test_calculate_disk_usage_zero_elapsed() {
+      let prev = counters(0, 0, 0, 0);
+      let curr = counters(10, 10, 10, 10);
+
+      assert!(calculate_disk_usage("sda", &prev, &curr, 0.0).is_none());
+    }
+  }
+}
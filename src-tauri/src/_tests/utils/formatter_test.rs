@@ -2,7 +2,6 @@
 mod tests {
   use crate::utils::formatter::*;
 
-  #[cfg(target_os = "windows")]
   use crate::enums;
   #[cfg(target_os = "windows")]
   use nvapi::Kibibytes;
@@ -92,7 +91,6 @@ mod tests {
   }
 
   #[test]
-  #[cfg(target_os = "windows")]
   fn test_celsius_to_fahrenheit() {
     let value = 100; // 100°C
     let result = format_temperature(
@@ -104,7 +102,6 @@ mod tests {
   }
 
   #[test]
-  #[cfg(target_os = "windows")]
   fn test_fahrenheit_to_celsius() {
     let value = 212; // 212°F
     let result = format_temperature(
@@ -116,7 +113,6 @@ mod tests {
   }
 
   #[test]
-  #[cfg(target_os = "windows")]
   fn test_celsius_to_fahrenheit_negative() {
     let value = -40; // -40°C
     let result = format_temperature(
@@ -128,7 +124,6 @@ mod tests {
   }
 
   #[test]
-  #[cfg(target_os = "windows")]
   fn test_fahrenheit_to_celsius_negative() {
     let value = -40; // -40°F
     let result = format_temperature(
@@ -140,7 +135,6 @@ mod tests {
   }
 
   #[test]
-  #[cfg(target_os = "windows")]
   fn test_no_conversion() {
     let value = 25; // 25°C
     let result = format_temperature(
@@ -1,7 +1,10 @@
 use crate::commands::settings;
 use crate::enums::error::BackendError;
 use crate::models;
-use crate::models::hardware::{HardwareMonitorState, NetworkInfo, ProcessInfo, SysInfo};
+use crate::models::hardware::{
+  CpuTimeBreakdown, DiskUsage, HardwareMonitorState, NetworkInfo, NetworkUsage,
+  PressureInfo, ProcessInfo, SysInfo,
+};
 use tauri::command;
 
 ///
@@ -39,6 +42,70 @@ pub fn get_processors_usage(state: tauri::State<'_, HardwareMonitorState>) -> Ve
   cpu_service::per_cpu_usage(&state)
 }
 
+///
+/// ## Get CPU time breakdown (user / system / iowait / steal ...) of all cores and each core
+///
+#[command]
+#[specta::specta]
+pub fn get_cpu_time_breakdown(
+  state: tauri::State<'_, HardwareMonitorState>,
+) -> Result<Vec<CpuTimeBreakdown>, BackendError> {
+  use crate::services::cpu_service;
+
+  cpu_service::cpu_time_breakdown(&state)
+}
+
+///
+/// ## Get CPU time breakdown history
+///
+/// - param state: `tauri::State<AppState>` Application state
+/// - param cpu: `String` `cpu` for all cores or `cpuN` for a single core
+/// - param seconds: `u32` Number of seconds to retrieve
+///
+#[command]
+#[specta::specta]
+pub fn get_cpu_time_breakdown_history(
+  state: tauri::State<'_, HardwareMonitorState>,
+  cpu: String,
+  seconds: u32,
+) -> Result<Vec<CpuTimeBreakdown>, BackendError> {
+  use crate::services::cpu_service;
+
+  cpu_service::cpu_time_breakdown_history(&state, &cpu, seconds)
+}
+
+///
+/// ## Get current frequency, governor and throttling status of every core
+///
+#[command]
+#[specta::specta]
+pub fn get_cpu_frequencies(
+  state: tauri::State<'_, HardwareMonitorState>,
+) -> Result<Vec<models::hardware::CpuCoreFrequency>, BackendError> {
+  use crate::services::cpu_service;
+
+  cpu_service::cpu_frequencies(&state)
+}
+
+///
+/// ## Get frequency history of a core
+///
+/// - param state: `tauri::State<AppState>` Application state
+/// - param core: `u32` Core index (`N` of `cpuN`)
+/// - param seconds: `u32` Number of seconds to retrieve
+///
+#[command]
+#[specta::specta]
+pub fn get_cpu_frequency_history(
+  state: tauri::State<'_, HardwareMonitorState>,
+  core: u32,
+  seconds: u32,
+) -> Result<Vec<f32>, BackendError> {
+  use crate::services::cpu_service;
+
+  cpu_service::cpu_frequency_history(&state, core, seconds)
+}
+
 ///
 /// ## Get system information
 ///
@@ -52,6 +119,39 @@ pub async fn get_hardware_info(
   hardware_service::collect_hardware_info(state.inner()).await
 }
 
+///
+/// ## Get PCI device list (vendor / device names, driver and link status)
+///
+#[command]
+#[specta::specta]
+pub async fn get_pci_devices() -> Result<Vec<models::hardware::PciDevice>, String> {
+  use crate::services::hardware_service;
+
+  hardware_service::fetch_pci_devices().await
+}
+
+///
+/// ## Get SMART / NVMe health of every physical disk
+///
+#[command]
+#[specta::specta]
+pub async fn get_disk_health() -> Result<Vec<models::hardware::DiskHealth>, String> {
+  use crate::services::hardware_service;
+
+  hardware_service::fetch_disk_health().await
+}
+
+///
+/// ## Get physical disks with their partitions, dm / md layers and mount points
+///
+#[command]
+#[specta::specta]
+pub async fn get_block_devices() -> Result<Vec<models::hardware::BlockDevice>, String> {
+  use crate::services::hardware_service;
+
+  hardware_service::fetch_block_devices().await
+}
+
 ///
 /// ## Get detailed memory information
 ///
@@ -79,18 +179,31 @@ pub fn get_memory_usage(state: tauri::State<'_, HardwareMonitorState>) -> i32 {
   memory_service::memory_usage_percent(&state)
 }
 
+///
+/// ## Get breakdown of memory usage (cache / buffers / slab / swap ...)
+///
+#[command]
+#[specta::specta]
+pub fn get_memory_breakdown() -> Result<models::hardware::MemoryBreakdown, String> {
+  use crate::services::memory_service;
+
+  memory_service::fetch_memory_breakdown()
+}
+
 ///
 /// ## Get GPU usage (%)
 ///
-/// - param state: `tauri::State<AppState>` Application state
+/// - param state: `tauri::State<HardwareMonitorState>` Hardware monitor state
 /// - return: `i32` GPU usage (%)
 ///
 #[command]
 #[specta::specta]
-pub async fn get_gpu_usage() -> Result<i32, String> {
+pub async fn get_gpu_usage(
+  state: tauri::State<'_, HardwareMonitorState>,
+) -> Result<i32, String> {
   use crate::services::gpu_service;
 
-  gpu_service::fetch_gpu_usage().await
+  gpu_service::fetch_gpu_usage(state.inner()).await
 }
 
 ///
@@ -100,6 +213,7 @@ pub async fn get_gpu_usage() -> Result<i32, String> {
 #[specta::specta]
 pub async fn get_gpu_temperature(
   state: tauri::State<'_, settings::AppState>,
+  hardware_state: tauri::State<'_, HardwareMonitorState>,
 ) -> Result<Vec<models::hardware::NameValue>, String> {
   use crate::services::gpu_service;
 
@@ -108,18 +222,38 @@ pub async fn get_gpu_temperature(
     config.temperature_unit.clone()
   };
 
-  gpu_service::fetch_gpu_temperature(temperature_unit).await
+  gpu_service::fetch_gpu_temperature(hardware_state.inner(), temperature_unit).await
 }
 
 ///
-/// ## Get GPU fan speed
+/// ## Get CPU temperature
 ///
 #[command]
 #[specta::specta]
-pub async fn get_nvidia_gpu_cooler() -> Result<Vec<models::hardware::NameValue>, String> {
-  use crate::services::gpu_service;
+pub async fn get_cpu_temperature(
+  state: tauri::State<'_, settings::AppState>,
+) -> Result<Vec<models::hardware::NameValue>, String> {
+  use crate::services::thermal_service;
 
-  gpu_service::fetch_nvidia_gpu_cooler().await
+  let temperature_unit = {
+    let config = state.settings.lock().unwrap();
+    config.temperature_unit.clone()
+  };
+
+  thermal_service::fetch_cpu_temperature(temperature_unit).await
+}
+
+///
+/// ## Get fan speed
+///
+/// - return: `Vec<FanInfo>` Every fan (CPU / chassis / GPU) with RPM and PWM duty
+///
+#[command]
+#[specta::specta]
+pub async fn get_fans() -> Result<Vec<models::hardware::FanInfo>, String> {
+  use crate::services::fan_service;
+
+  fan_service::fetch_fans().await
 }
 
 ///
@@ -156,6 +290,23 @@ pub fn get_memory_usage_history(
   monitoring_service::memory_usage_history(&state, seconds)
 }
 
+///
+/// ## Get swap usage history
+///
+/// - param state: `tauri::State<AppState>` Application state
+/// - param seconds: `u32` Number of seconds to retrieve
+///
+#[command]
+#[specta::specta]
+pub fn get_swap_usage_history(
+  state: tauri::State<'_, HardwareMonitorState>,
+  seconds: u32,
+) -> Vec<f32> {
+  use crate::services::monitoring_service;
+
+  monitoring_service::swap_usage_history(&state, seconds)
+}
+
 ///
 /// ## Get GPU usage history
 ///
@@ -173,6 +324,76 @@ pub fn get_gpu_usage_history(
   monitoring_service::gpu_usage_history(&state, seconds)
 }
 
+///
+/// ## Get usage, memory and temperature of every GPU
+///
+/// Unlike `get_gpu_usage`, every card is reported separately.
+///
+#[command]
+#[specta::specta]
+pub fn get_gpu_usages(
+  state: tauri::State<'_, HardwareMonitorState>,
+) -> Result<Vec<models::hardware::GpuUsage>, BackendError> {
+  use crate::services::gpu_service;
+
+  gpu_service::gpu_usages(&state)
+}
+
+///
+/// ## Get clocks, memory and power of every GPU
+///
+#[command]
+#[specta::specta]
+pub fn get_gpu_metrics(
+  state: tauri::State<'_, HardwareMonitorState>,
+) -> Result<Vec<models::hardware::GpuMetrics>, BackendError> {
+  use crate::services::gpu_service;
+
+  gpu_service::gpu_metrics(&state)
+}
+
+///
+/// ## Get clocks, memory and power history of a GPU
+///
+/// - param state: `tauri::State<AppState>` Application state
+/// - param card: `String` DRM card name (e.g. `card1`)
+/// - param seconds: `u32` Number of seconds to retrieve
+///
+#[command]
+#[specta::specta]
+pub fn get_gpu_metrics_history(
+  state: tauri::State<'_, HardwareMonitorState>,
+  card: String,
+  seconds: u32,
+) -> Result<Vec<models::hardware::GpuMetrics>, BackendError> {
+  use crate::services::gpu_service;
+
+  gpu_service::gpu_metrics_history(&state, &card, seconds)
+}
+
+///
+/// ## Get CPU temperature history
+///
+/// - param state: `tauri::State<AppState>` Application state
+/// - param seconds: `u32` Number of seconds to retrieve
+///
+#[command]
+#[specta::specta]
+pub fn get_cpu_temperature_history(
+  state: tauri::State<'_, HardwareMonitorState>,
+  settings_state: tauri::State<'_, settings::AppState>,
+  seconds: u32,
+) -> Vec<f32> {
+  use crate::services::monitoring_service;
+
+  let temperature_unit = {
+    let config = settings_state.settings.lock().unwrap();
+    config.temperature_unit.clone()
+  };
+
+  monitoring_service::cpu_temperature_history(&state, seconds, temperature_unit)
+}
+
 ///
 /// ## Get network information
 ///
@@ -183,3 +404,175 @@ pub fn get_network_info() -> Result<Vec<NetworkInfo>, BackendError> {
 
   network_service::fetch_network_info()
 }
+
+///
+/// ## Get network throughput of every interface
+///
+#[command]
+#[specta::specta]
+pub fn get_network_usage(
+  state: tauri::State<'_, HardwareMonitorState>,
+) -> Result<Vec<NetworkUsage>, BackendError> {
+  use crate::services::network_service;
+
+  network_service::network_usage(&state)
+}
+
+///
+/// ## Get network throughput history
+///
+/// - param state: `tauri::State<AppState>` Application state
+/// - param interface: `String` Interface name (e.g. `eth0`)
+/// - param seconds: `u32` Number of seconds to retrieve
+///
+#[command]
+#[specta::specta]
+pub fn get_network_usage_history(
+  state: tauri::State<'_, HardwareMonitorState>,
+  interface: String,
+  seconds: u32,
+) -> Result<Vec<NetworkUsage>, BackendError> {
+  use crate::services::network_service;
+
+  network_service::network_usage_history(&state, &interface, seconds)
+}
+
+///
+/// ## Get I/O activity of every block device
+///
+#[command]
+#[specta::specta]
+pub fn get_disk_usage(
+  state: tauri::State<'_, HardwareMonitorState>,
+) -> Result<Vec<DiskUsage>, BackendError> {
+  use crate::services::disk_service;
+
+  disk_service::disk_usage(&state)
+}
+
+///
+/// ## Get I/O activity history of a block device
+///
+/// - param state: `tauri::State<AppState>` Application state
+/// - param device: `String` Block device name (e.g. `nvme0n1`)
+/// - param seconds: `u32` Number of seconds to retrieve
+///
+#[command]
+#[specta::specta]
+pub fn get_disk_usage_history(
+  state: tauri::State<'_, HardwareMonitorState>,
+  device: String,
+  seconds: u32,
+) -> Result<Vec<DiskUsage>, BackendError> {
+  use crate::services::disk_service;
+
+  disk_service::disk_usage_history(&state, &device, seconds)
+}
+
+///
+/// ## Get Pressure Stall Information and load average
+///
+#[command]
+#[specta::specta]
+pub fn get_pressure_info(
+  state: tauri::State<'_, HardwareMonitorState>,
+) -> Result<PressureInfo, BackendError> {
+  use crate::services::pressure_service;
+
+  pressure_service::pressure_info(&state)
+}
+
+///
+/// ## Get Pressure Stall Information and load average history
+///
+/// - param state: `tauri::State<AppState>` Application state
+/// - param seconds: `u32` Number of seconds to retrieve
+///
+#[command]
+#[specta::specta]
+pub fn get_pressure_history(
+  state: tauri::State<'_, HardwareMonitorState>,
+  seconds: u32,
+) -> Vec<PressureInfo> {
+  use crate::services::pressure_service;
+
+  pressure_service::pressure_history(&state, seconds)
+}
+
+///
+/// ## Get AC adapter and battery status
+///
+#[command]
+#[specta::specta]
+pub fn get_power_supply_info() -> Result<models::hardware::PowerSupplyInfo, String> {
+  use crate::services::power_service;
+
+  power_service::fetch_power_supply_info()
+}
+
+///
+/// ## Get battery level history
+///
+/// - param state: `tauri::State<AppState>` Application state
+/// - param seconds: `u32` Number of seconds to retrieve
+///
+#[command]
+#[specta::specta]
+pub fn get_battery_history(
+  state: tauri::State<'_, HardwareMonitorState>,
+  seconds: u32,
+) -> Vec<f32> {
+  use crate::services::monitoring_service;
+
+  monitoring_service::battery_history(&state, seconds)
+}
+
+///
+/// ## Get battery charge (+) / discharge (-) rate history in watts
+///
+/// - param state: `tauri::State<AppState>` Application state
+/// - param seconds: `u32` Number of seconds to retrieve
+///
+#[command]
+#[specta::specta]
+pub fn get_battery_power_history(
+  state: tauri::State<'_, HardwareMonitorState>,
+  seconds: u32,
+) -> Vec<f32> {
+  use crate::services::monitoring_service;
+
+  monitoring_service::battery_power_history(&state, seconds)
+}
+
+///
+/// ## Get power draw of every RAPL zone (package / core / uncore / dram)
+///
+/// `energy_uj` is only readable by root since Linux 5.10, so this returns
+/// `cpuPowerNotAvailable` when the app runs unprivileged (the reason is logged once).
+///
+#[command]
+#[specta::specta]
+pub fn get_cpu_power(
+  state: tauri::State<'_, HardwareMonitorState>,
+) -> Result<Vec<models::hardware::RaplPower>, BackendError> {
+  use crate::services::power_service;
+
+  power_service::cpu_power(&state)
+}
+
+///
+/// ## Get CPU package power history in watts
+///
+/// - param state: `tauri::State<AppState>` Application state
+/// - param seconds: `u32` Number of seconds to retrieve
+///
+#[command]
+#[specta::specta]
+pub fn get_cpu_power_history(
+  state: tauri::State<'_, HardwareMonitorState>,
+  seconds: u32,
+) -> Vec<f32> {
+  use crate::services::monitoring_service;
+
+  monitoring_service::cpu_power_history(&state, seconds)
+}
@@ -26,3 +26,18 @@ pub const MAX_HISTORY_QUERY_DURATION_SECONDS: u32 = 3600;
 ///
 /// Set to 60 seconds to align with the history buffer size.
 pub const HARDWARE_ARCHIVE_INTERVAL_SECONDS: u64 = 60;
+
+/// Window in seconds over which CPU thermal throttle events are counted.
+///
+/// The kernel throttle counters only increase while a core is being throttled,
+/// so comparing consecutive 1-second samples would report throttling for a single
+/// tick. Comparing against the sample taken this many seconds earlier keeps the
+/// flag up long enough for the UI to show it.
+pub const CPU_THROTTLE_WINDOW_SECONDS: usize = 10;
+
+/// Maximum time in seconds to wait for `nvidia-smi`.
+///
+/// `nvidia-smi` can hang for seconds when the driver is wedged or the GPU fell
+/// off the bus. The NVIDIA poller gives up after this long and keeps the
+/// previous statuses.
+pub const NVIDIA_SMI_TIMEOUT_SECONDS: u64 = 1;
@@ -11,6 +11,12 @@ pub enum BackendError {
   GraphicInfoNotAvailable,
   NetworkInfoNotAvailable,
   NetworkUsageNotAvailable,
+  DiskUsageNotAvailable,
+  CpuTimeNotAvailable,
+  PressureInfoNotAvailable,
+  CpuPowerNotAvailable,
+  CpuFrequencyNotAvailable,
+  GpuMetricsNotAvailable,
   UnexpectedError,
   // SystemError(String),
 }
@@ -27,6 +33,12 @@ impl Serialize for BackendError {
       BackendError::GraphicInfoNotAvailable => "graphicInfoNotAvailable",
       BackendError::NetworkInfoNotAvailable => "networkInfoNotAvailable",
       BackendError::NetworkUsageNotAvailable => "networkUsageNotAvailable",
+      BackendError::DiskUsageNotAvailable => "diskUsageNotAvailable",
+      BackendError::CpuTimeNotAvailable => "cpuTimeNotAvailable",
+      BackendError::PressureInfoNotAvailable => "pressureInfoNotAvailable",
+      BackendError::CpuPowerNotAvailable => "cpuPowerNotAvailable",
+      BackendError::CpuFrequencyNotAvailable => "cpuFrequencyNotAvailable",
+      BackendError::GpuMetricsNotAvailable => "gpuMetricsNotAvailable",
       BackendError::UnexpectedError => "unexpectedError",
       //   BackendError::SystemError(ref e) => e,
     };
@@ -75,3 +75,34 @@ impl fmt::Display for DiskKind {
     })
   }
 }
+
+///
+/// Health verdict of a physical disk (SMART / NVMe health log)
+///
+#[derive(Serialize, Deserialize, Type, Debug, Default, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+pub enum DiskHealthStatus {
+  Good,
+  /// Degrading (reallocated / pending sectors, media errors, worn out soon)
+  Warning,
+  /// SMART failed, NVMe critical warning, or rated endurance exceeded
+  Critical,
+  /// SMART data is not readable
+  #[default]
+  Unknown,
+}
+
+///
+/// Kind of a node in the block device tree
+///
+#[derive(Serialize, Deserialize, Type, Debug, PartialEq, Eq, Clone, Copy)]
+#[serde(rename_all = "camelCase")]
+pub enum BlockDeviceKind {
+  Disk,
+  Partition,
+  /// Device mapper (LVM, LUKS ...)
+  DeviceMapper,
+  /// Software RAID (md)
+  Raid,
+  Other,
+}
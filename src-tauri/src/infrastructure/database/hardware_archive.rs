@@ -14,13 +14,16 @@ pub async fn get_pool() -> Result<SqlitePool, sqlx::Error> {
 pub async fn insert(
   cpu: models::hardware_archive::HardwareData,
   ram: models::hardware_archive::HardwareData,
+  battery: models::hardware_archive::HardwareData,
+  battery_power: models::hardware_archive::HardwareData,
+  cpu_power: models::hardware_archive::PowerData,
 ) -> Result<(), sqlx::Error> {
   let pool = get_pool().await?;
 
   sqlx::query(
-    "INSERT INTO DATA_ARCHIVE (cpu_avg, cpu_max, cpu_min, ram_avg, ram_max, ram_min, timestamp)
-    VALUES ($1, $2, $3, $4, $5, $6, $7)",
-  ).bind(cpu.avg).bind(cpu.max).bind(cpu.min).bind(ram.avg).bind(ram.max).bind(ram.min).bind(chrono::Utc::now()).execute(&pool).await?;
+    "INSERT INTO DATA_ARCHIVE (cpu_avg, cpu_max, cpu_min, ram_avg, ram_max, ram_min, battery_avg, battery_max, battery_min, battery_power_avg, battery_power_max, battery_power_min, cpu_power_avg, cpu_power_max, cpu_power_min, cpu_energy_wh, timestamp)
+    VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17)",
+  ).bind(cpu.avg).bind(cpu.max).bind(cpu.min).bind(ram.avg).bind(ram.max).bind(ram.min).bind(battery.avg).bind(battery.max).bind(battery.min).bind(battery_power.avg).bind(battery_power.max).bind(battery_power.min).bind(cpu_power.avg).bind(cpu_power.max).bind(cpu_power.min).bind(cpu_power.energy_wh).bind(chrono::Utc::now()).execute(&pool).await?;
 
   Ok(())
 }
@@ -31,6 +31,39 @@ pub fn get_migrations() -> Vec<Migration> {
       sql: "CREATE TABLE PROCESS_STATS (id INTEGER PRIMARY KEY AUTOINCREMENT, pid INTEGER NOT NULL, process_name TEXT NOT NULL,  cpu_usage REAL NOT NULL,  memory_usage INTEGER NOT NULL, execution_sec INTEGER NOT NULL, timestamp DATETIME NOT NULL);",
       kind: MigrationKind::Up,
     },
+    Migration {
+      version: 5,
+      description: "add_battery_columns",
+      sql: r#"
+        ALTER TABLE DATA_ARCHIVE ADD COLUMN battery_avg INTEGER;
+        ALTER TABLE DATA_ARCHIVE ADD COLUMN battery_max INTEGER;
+        ALTER TABLE DATA_ARCHIVE ADD COLUMN battery_min INTEGER;
+        ALTER TABLE DATA_ARCHIVE ADD COLUMN battery_power_avg REAL;
+        ALTER TABLE DATA_ARCHIVE ADD COLUMN battery_power_max REAL;
+        ALTER TABLE DATA_ARCHIVE ADD COLUMN battery_power_min REAL;
+      "#,
+      kind: MigrationKind::Up,
+    },
+    Migration {
+      version: 6,
+      description: "add_cpu_power_columns",
+      sql: r#"
+        ALTER TABLE DATA_ARCHIVE ADD COLUMN cpu_power_avg REAL;
+        ALTER TABLE DATA_ARCHIVE ADD COLUMN cpu_power_max REAL;
+        ALTER TABLE DATA_ARCHIVE ADD COLUMN cpu_power_min REAL;
+        ALTER TABLE DATA_ARCHIVE ADD COLUMN cpu_energy_wh REAL;
+      "#,
+      kind: MigrationKind::Up,
+    },
+    Migration {
+      version: 7,
+      description: "add_process_gpu_columns",
+      sql: r#"
+        ALTER TABLE PROCESS_STATS ADD COLUMN gpu_usage REAL;
+        ALTER TABLE PROCESS_STATS ADD COLUMN gpu_memory_usage INTEGER;
+      "#,
+      kind: MigrationKind::Up,
+    },
     // Down Migrations
     Migration {
       version: 4,
@@ -8,13 +8,15 @@ pub async fn insert(
 
   for proc in processes {
     sqlx::query(
-      "INSERT INTO PROCESS_STATS (pid, process_name, cpu_usage, memory_usage, execution_sec, timestamp)
-       VALUES ($1, $2, $3, $4, $5, $6)"
+      "INSERT INTO PROCESS_STATS (pid, process_name, cpu_usage, memory_usage, gpu_usage, gpu_memory_usage, execution_sec, timestamp)
+       VALUES ($1, $2, $3, $4, $5, $6, $7, $8)"
     )
     .bind(proc.pid)
     .bind(&proc.process_name)
     .bind(proc.cpu_usage)
     .bind(proc.memory_usage)
+    .bind(proc.gpu_usage)
+    .bind(proc.gpu_memory_usage)
     .bind(proc.execution_sec)
     .bind(chrono::Utc::now())
     .execute(&pool)
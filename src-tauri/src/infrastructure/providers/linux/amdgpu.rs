@@ -0,0 +1,75 @@
+use crate::infrastructure::providers::{hwmon, sysfs};
+use crate::models::hardware::GpuMetrics;
+use std::path::Path;
+
+///
+/// Get clocks, memory and power of an amdgpu card
+///
+pub fn get_amd_gpu_metrics(card_id: u8) -> GpuMetrics {
+  read_amd_gpu_metrics(
+    &format!("card{card_id}"),
+    Path::new(&format!("/sys/class/drm/card{card_id}/device")),
+  )
+}
+
+///
+/// Read amdgpu attributes under `device_dir` (`/sys/class/drm/cardN/device`)
+///
+/// Unlike `amdgpu_pm_info` in debugfs, every file here is readable without root.
+///
+/// - Clocks: `pp_dpm_sclk` / `pp_dpm_mclk`
+/// - Memory: `mem_info_{vram,gtt}_{used,total}` (bytes)
+/// - Power: hwmon `power1_average` (or `power1_input` on newer GPUs) and `power1_cap` (µW)
+///
+pub fn read_amd_gpu_metrics(card: &str, device_dir: &Path) -> GpuMetrics {
+  let read_mb = |name: &str| {
+    let bytes = sysfs::read_attribute(device_dir, name)?
+      .parse::<u64>()
+      .ok()?;
+    Some((bytes as f64 / 1024.0 / 1024.0).round() as f32)
+  };
+
+  let hwmon_dir = hwmon::get_hwmon_dirs(&device_dir.join("hwmon"))
+    .into_iter()
+    .next();
+  let read_watts = |names: &[&str]| {
+    let dir = hwmon_dir.as_deref()?;
+    names.iter().find_map(|name| {
+      let microwatts = sysfs::read_attribute(dir, name)?.parse::<u64>().ok()?;
+      Some((microwatts as f32 / 100_000.0).round() / 10.0)
+    })
+  };
+
+  GpuMetrics {
+    card: card.to_string(),
+    core_clock_mhz: sysfs::read_attribute(device_dir, "pp_dpm_sclk")
+      .and_then(|content| parse_dpm_clock_mhz(&content)),
+    memory_clock_mhz: sysfs::read_attribute(device_dir, "pp_dpm_mclk")
+      .and_then(|content| parse_dpm_clock_mhz(&content)),
+    vram_used_mb: read_mb("mem_info_vram_used"),
+    vram_total_mb: read_mb("mem_info_vram_total"),
+    gtt_used_mb: read_mb("mem_info_gtt_used"),
+    gtt_total_mb: read_mb("mem_info_gtt_total"),
+    power_watts: read_watts(&["power1_average", "power1_input"]),
+    power_cap_watts: read_watts(&["power1_cap"]),
+    fan_speed_percent: None,
+  }
+}
+
+///
+/// Parse the active level (marked with `*`) of `pp_dpm_sclk` / `pp_dpm_mclk`
+///
+/// ```text
+/// 0: 500Mhz
+/// 1: 2270Mhz *
+/// 2: 2600Mhz
+/// ```
+///
+pub fn parse_dpm_clock_mhz(content: &str) -> Option<u32> {
+  content.lines().find_map(|line| {
+    let line = line.trim().strip_suffix('*')?;
+    let (_, clock) = line.split_once(':')?;
+    let clock = clock.trim().to_lowercase();
+    clock.strip_suffix("mhz")?.trim().parse::<u32>().ok()
+  })
+}
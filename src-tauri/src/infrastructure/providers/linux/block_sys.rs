@@ -0,0 +1,255 @@
+use crate::enums::hardware::BlockDeviceKind;
+use crate::infrastructure::providers::{hwmon, sysfs};
+use crate::models::hardware::{BlockDevice, MountEntry};
+use crate::utils;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+/// Size unit of `/sys/class/block/*/size`
+const SECTOR_SIZE: u64 = 512;
+
+///
+/// Enumerate disks backed by a device under `sys_block`
+///
+/// Virtual devices (`loop*`, `dm-*`, `zram*`, `md*`) have no `device` link.
+/// Optical drives (`sr*`) are skipped as well.
+///
+pub fn read_physical_disks(sys_block: &Path) -> Vec<String> {
+  let Ok(entries) = fs::read_dir(sys_block) else {
+    return Vec::new();
+  };
+
+  let mut disks: Vec<String> = entries
+    .flatten()
+    .filter(|entry| entry.path().join("device").exists())
+    .filter_map(|entry| entry.file_name().to_str().map(str::to_string))
+    .filter(|name| !name.starts_with("sr"))
+    .collect();
+
+  disks.sort();
+  disks
+}
+
+///
+/// Read the drive temperature (Celsius) exposed through hwmon
+///
+/// - NVMe: `/sys/block/nvme0n1/device/hwmon*` (the controller)
+/// - SATA with `drivetemp`: `/sys/block/sda/device/hwmon/hwmon*`
+///
+pub fn read_disk_temperature(sys_block: &Path, disk: &str) -> Option<f32> {
+  let device_dir = sys_block.join(disk).join("device");
+
+  hwmon::get_hwmon_dirs(&device_dir)
+    .into_iter()
+    .chain(hwmon::get_hwmon_dirs(&device_dir.join("hwmon")))
+    .filter(|dir| dir.join("temp1_input").exists())
+    .find_map(|dir| {
+      hwmon::read_temperatures(&dir)
+        .into_iter()
+        .next()
+        .map(|sensor| sensor.value)
+    })
+}
+
+///
+/// Get the block device tree of every physical disk
+///
+pub fn get_block_devices(mounts: &[MountEntry]) -> Vec<BlockDevice> {
+  let sys_class_block = Path::new("/sys/class/block");
+
+  // `/dev/vg0/root`, `/dev/disk/by-uuid/*` ... are symlinks to the kernel name
+  let mounts: Vec<MountEntry> = mounts
+    .iter()
+    .map(|mount| MountEntry {
+      source: fs::canonicalize(&mount.source)
+        .map(|path| path.to_string_lossy().into_owned())
+        .unwrap_or_else(|_| mount.source.clone()),
+      ..mount.clone()
+    })
+    .collect();
+
+  read_block_devices(Path::new("/sys/block"), sys_class_block, &mounts)
+}
+
+///
+/// Build the block device tree from `sys_block` / `sys_class_block`
+///
+/// Each physical disk lists its partitions, then the device mapper / md devices
+/// found through `holders/*`, recursively down to the mounted devices.
+///
+pub fn read_block_devices(
+  sys_block: &Path,
+  sys_class_block: &Path,
+  mounts: &[MountEntry],
+) -> Vec<BlockDevice> {
+  // Resolve every mount source once instead of at each node of the tree
+  let mut mounts_by_device: HashMap<String, Vec<&MountEntry>> = HashMap::new();
+  for mount in mounts {
+    if let Some(name) = resolve_device_name(sys_class_block, &mount.source) {
+      mounts_by_device.entry(name).or_default().push(mount);
+    }
+  }
+
+  read_physical_disks(sys_block)
+    .iter()
+    .map(|disk| {
+      read_block_device(
+        sys_class_block,
+        disk,
+        BlockDeviceKind::Disk,
+        &mounts_by_device,
+      )
+    })
+    .collect()
+}
+
+fn read_block_device(
+  sys_class_block: &Path,
+  name: &str,
+  kind: BlockDeviceKind,
+  mounts: &HashMap<String, Vec<&MountEntry>>,
+) -> BlockDevice {
+  let dir = sys_class_block.join(name);
+  let size = utils::formatter::format_size_with_unit(
+    sysfs::read_attribute(&dir, "size")
+      .and_then(|sectors| sectors.parse::<u64>().ok())
+      .unwrap_or(0)
+      * SECTOR_SIZE,
+    2,
+    None,
+  );
+  let label = match kind {
+    BlockDeviceKind::DeviceMapper => sysfs::read_attribute(&dir, "dm/name"),
+    BlockDeviceKind::Raid => sysfs::read_attribute(&dir, "md/level"),
+    _ => None,
+  };
+
+  let mounted = mounts.get(name).map(Vec::as_slice).unwrap_or_default();
+
+  // Partitions are sub directories of the disk
+  let mut partitions: Vec<String> = match kind {
+    BlockDeviceKind::Disk => sysfs::list_dir(&dir)
+      .into_iter()
+      .filter(|child| dir.join(child).join("partition").exists())
+      .collect(),
+    _ => Vec::new(),
+  };
+  partitions.sort_by_key(|partition| partition_number(&dir, partition));
+
+  let children = partitions
+    .iter()
+    .map(|partition| {
+      read_block_device(
+        sys_class_block,
+        partition,
+        BlockDeviceKind::Partition,
+        mounts,
+      )
+    })
+    .chain(sysfs::list_dir(&dir.join("holders")).iter().map(|holder| {
+      read_block_device(
+        sys_class_block,
+        holder,
+        holder_kind(sys_class_block, holder),
+        mounts,
+      )
+    }))
+    .collect();
+
+  BlockDevice {
+    name: name.to_string(),
+    kind,
+    label,
+    model: match kind {
+      BlockDeviceKind::Disk => sysfs::read_attribute(&dir, "device/model"),
+      _ => None,
+    },
+    size: size.value,
+    size_unit: size.unit,
+    rotational: sysfs::read_attribute(&dir, "queue/rotational").map(|value| value == "1"),
+    scheduler: sysfs::read_attribute(&dir, "queue/scheduler")
+      .and_then(|content| parse_scheduler(&content)),
+    mount_points: mounted
+      .iter()
+      .map(|mount| mount.mount_point.clone())
+      .collect(),
+    file_system: mounted.first().map(|mount| mount.file_system.clone()),
+    children,
+  }
+}
+
+///
+/// Every mount point of a block device and of the devices stacked on it
+///
+/// An md array or LVM volume spanning several disks is listed under each of them.
+///
+pub fn mount_points_of(device: &BlockDevice) -> Vec<String> {
+  let mut mount_points: Vec<String> = device
+    .mount_points
+    .iter()
+    .cloned()
+    .chain(device.children.iter().flat_map(mount_points_of))
+    .collect();
+
+  mount_points.sort();
+  mount_points.dedup();
+  mount_points
+}
+
+fn holder_kind(sys_class_block: &Path, name: &str) -> BlockDeviceKind {
+  let dir = sys_class_block.join(name);
+
+  if dir.join("dm").exists() {
+    BlockDeviceKind::DeviceMapper
+  } else if dir.join("md").exists() {
+    BlockDeviceKind::Raid
+  } else {
+    BlockDeviceKind::Other
+  }
+}
+
+fn partition_number(disk_dir: &Path, partition: &str) -> u32 {
+  sysfs::read_attribute(&disk_dir.join(partition), "partition")
+    .and_then(|number| number.parse().ok())
+    .unwrap_or(u32::MAX)
+}
+
+///
+/// Resolve a mount source (`/dev/nvme0n1p1`, `/dev/mapper/cryptroot`) to its kernel name
+///
+/// Returns `None` for sources that are not block devices (`tmpfs`, `proc` ...).
+///
+pub fn resolve_device_name(sys_class_block: &Path, source: &str) -> Option<String> {
+  let name = source.strip_prefix("/dev/")?;
+
+  if let Some(mapper_name) = name.strip_prefix("mapper/") {
+    return sysfs::list_dir(sys_class_block).into_iter().find(|device| {
+      sysfs::read_attribute(&sys_class_block.join(device), "dm/name").as_deref()
+        == Some(mapper_name)
+    });
+  }
+
+  sys_class_block
+    .join(name)
+    .exists()
+    .then(|| name.to_string())
+}
+
+///
+/// Parse the active scheduler of `queue/scheduler` (e.g. `none [mq-deadline] kyber`)
+///
+/// A single entry without brackets (`none`) is the active one.
+///
+pub fn parse_scheduler(content: &str) -> Option<String> {
+  let schedulers: Vec<&str> = content.split_whitespace().collect();
+
+  schedulers
+    .iter()
+    .find_map(|scheduler| scheduler.strip_prefix('[')?.strip_suffix(']'))
+    .or(match schedulers.as_slice() {
+      [scheduler] => Some(*scheduler),
+      _ => None,
+    })
+    .map(str::to_string)
+}
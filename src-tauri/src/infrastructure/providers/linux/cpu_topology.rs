@@ -0,0 +1,244 @@
+use crate::infrastructure::providers::sysfs;
+use crate::models::hardware::{CpuCache, CpuCoreSet, CpuTopology, NumaNode};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// ISA extensions worth surfacing (x86 `flags` / ARM `Features` of `/proc/cpuinfo`)
+const NOTABLE_ISA_FLAGS: [&str; 26] = [
+  "sse4_2",
+  "avx",
+  "avx2",
+  "fma",
+  "f16c",
+  "bmi2",
+  "aes",
+  "sha_ni",
+  "vaes",
+  "vpclmulqdq",
+  "avx_vnni",
+  "avx512f",
+  "avx512dq",
+  "avx512cd",
+  "avx512bw",
+  "avx512vl",
+  "avx512_vnni",
+  "avx512_bf16",
+  "avx512_fp16",
+  "amx_tile",
+  "amx_bf16",
+  "amx_int8",
+  "asimd",
+  "sve",
+  "sve2",
+  "sha2",
+];
+
+///
+/// Get CPU topology from `/sys` and `/proc/cpuinfo`
+///
+pub fn get_cpu_topology() -> Option<CpuTopology> {
+  let cpuinfo = fs::read_to_string("/proc/cpuinfo").unwrap_or_default();
+  read_cpu_topology(Path::new("/sys"), &cpuinfo)
+}
+
+///
+/// Read CPU topology under `sys_root` (`/sys`)
+///
+/// - Sockets / cores / threads: `devices/system/cpu/cpuN/topology`
+/// - Hybrid core sets: `devices/cpu_core/cpus` and `devices/cpu_atom/cpus` (Intel)
+/// - Caches: `devices/system/cpu/cpuN/cache/indexM`
+/// - NUMA nodes: `devices/system/node/nodeN/cpulist`
+///
+/// Returns `None` when no CPU topology is exposed.
+///
+pub fn read_cpu_topology(sys_root: &Path, cpuinfo: &str) -> Option<CpuTopology> {
+  let cpu_root = sys_root.join("devices/system/cpu");
+  let cpus = get_indexed_dirs(&cpu_root, "cpu");
+
+  let mut sockets = BTreeSet::new();
+  let mut cores = BTreeSet::new();
+  let mut logical_cores = 0;
+
+  for (_, dir) in &cpus {
+    let topology = dir.join("topology");
+    // `-1` on platforms that do not report sockets (some ARM SoCs)
+    let Some(package_id) = sysfs::read_attribute(&topology, "physical_package_id")
+      .and_then(|id| id.parse::<i32>().ok())
+    else {
+      continue;
+    };
+    let core_id = read_number(&topology, "core_id").unwrap_or(0);
+
+    sockets.insert(package_id);
+    cores.insert((package_id, core_id));
+    logical_cores += 1;
+  }
+
+  if logical_cores == 0 {
+    return None;
+  }
+
+  let physical_cores = cores.len() as u32;
+  let smt_enabled = sysfs::read_attribute(&cpu_root.join("smt"), "active")
+    .map(|active| active == "1")
+    .unwrap_or(logical_cores > physical_cores);
+
+  Some(CpuTopology {
+    sockets: sockets.len() as u32,
+    physical_cores,
+    logical_cores,
+    smt_enabled,
+    core_sets: read_core_sets(&sys_root.join("devices")),
+    caches: read_caches(&cpus),
+    numa_nodes: read_numa_nodes(&sys_root.join("devices/system/node")),
+    isa_flags: parse_isa_flags(cpuinfo),
+  })
+}
+
+///
+/// Read hybrid core sets (Performance / Efficiency)
+///
+/// Empty on non-hybrid CPUs
+///
+fn read_core_sets(devices_root: &Path) -> Vec<CpuCoreSet> {
+  [("cpu_core", "Performance"), ("cpu_atom", "Efficiency")]
+    .into_iter()
+    .filter_map(|(pmu, kind)| {
+      let cpu_list = sysfs::read_attribute(&devices_root.join(pmu), "cpus")?;
+      Some(CpuCoreSet {
+        kind: kind.to_string(),
+        logical_cores: parse_cpu_list(&cpu_list).len() as u32,
+        cpu_list,
+      })
+    })
+    .collect()
+}
+
+///
+/// Collect distinct caches of all CPUs
+///
+/// Caches shared by several CPUs (same `shared_cpu_list`) are counted once.
+///
+fn read_caches(cpus: &[(u32, PathBuf)]) -> Vec<CpuCache> {
+  // (level, type, size, shared cpu count) -> distinct shared_cpu_list
+  let mut caches: BTreeMap<(u32, String, u32, u32), BTreeSet<String>> = BTreeMap::new();
+
+  for (_, dir) in cpus {
+    for (_, index) in get_indexed_dirs(&dir.join("cache"), "index") {
+      let (Some(level), Some(cache_type), Some(size_kb), Some(shared)) = (
+        read_number(&index, "level"),
+        sysfs::read_attribute(&index, "type"),
+        sysfs::read_attribute(&index, "size").and_then(|size| parse_cache_size_kb(&size)),
+        sysfs::read_attribute(&index, "shared_cpu_list"),
+      ) else {
+        continue;
+      };
+
+      let shared_cpu_count = parse_cpu_list(&shared).len() as u32;
+      caches
+        .entry((level, cache_type, size_kb, shared_cpu_count))
+        .or_default()
+        .insert(shared);
+    }
+  }
+
+  caches
+    .into_iter()
+    .map(
+      |((level, cache_type, size_kb, shared_cpu_count), instances)| CpuCache {
+        level,
+        cache_type,
+        size_kb,
+        instances: instances.len() as u32,
+        shared_cpu_count,
+      },
+    )
+    .collect()
+}
+
+fn read_numa_nodes(node_root: &Path) -> Vec<NumaNode> {
+  get_indexed_dirs(node_root, "node")
+    .into_iter()
+    .filter_map(|(id, dir)| {
+      let cpu_list = sysfs::read_attribute(&dir, "cpulist")?;
+      Some(NumaNode {
+        id,
+        logical_cores: parse_cpu_list(&cpu_list).len() as u32,
+        cpu_list,
+      })
+    })
+    .collect()
+}
+
+///
+/// Extract notable ISA extensions from the first `flags` / `Features` line of `/proc/cpuinfo`
+///
+pub fn parse_isa_flags(cpuinfo: &str) -> Vec<String> {
+  let Some(flags) = cpuinfo.lines().find_map(|line| {
+    let (key, value) = line.split_once(':')?;
+    matches!(key.trim(), "flags" | "Features").then_some(value)
+  }) else {
+    return Vec::new();
+  };
+
+  let flags: BTreeSet<&str> = flags.split_whitespace().collect();
+  NOTABLE_ISA_FLAGS
+    .iter()
+    .filter(|flag| flags.contains(*flag))
+    .map(|flag| flag.to_string())
+    .collect()
+}
+
+///
+/// Parse kernel CPU list format (e.g. `0-3,8,10-11`)
+///
+pub fn parse_cpu_list(list: &str) -> Vec<u32> {
+  list
+    .trim()
+    .split(',')
+    .filter_map(|range| {
+      let (start, end) = range.split_once('-').unwrap_or((range, range));
+      Some(start.parse::<u32>().ok()?..=end.parse::<u32>().ok()?)
+    })
+    .flatten()
+    .collect()
+}
+
+///
+/// Parse cache `size` attribute (e.g. `48K`, `2048K`, `36M`) into KiB
+///
+pub fn parse_cache_size_kb(size: &str) -> Option<u32> {
+  let size = size.trim();
+  if let Some(mb) = size.strip_suffix('M') {
+    return mb.parse::<u32>().ok().map(|mb| mb * 1024);
+  }
+  size.strip_suffix('K').unwrap_or(size).parse::<u32>().ok()
+}
+
+/// Enumerate `{prefix}N` directories sorted by `N`
+fn get_indexed_dirs(parent: &Path, prefix: &str) -> Vec<(u32, PathBuf)> {
+  let mut dirs: Vec<(u32, PathBuf)> = fs::read_dir(parent)
+    .map(|entries| {
+      entries
+        .flatten()
+        .filter_map(|entry| {
+          let index = entry
+            .file_name()
+            .to_str()?
+            .strip_prefix(prefix)?
+            .parse::<u32>()
+            .ok()?;
+          Some((index, entry.path()))
+        })
+        .collect()
+    })
+    .unwrap_or_default();
+
+  dirs.sort_by_key(|(index, _)| *index);
+  dirs
+}
+
+fn read_number(dir: &Path, name: &str) -> Option<u32> {
+  sysfs::read_attribute(dir, name)?.parse().ok()
+}
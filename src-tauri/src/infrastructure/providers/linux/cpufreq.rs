@@ -0,0 +1,80 @@
+use crate::infrastructure::providers::sysfs;
+use crate::models::hardware::CpuCoreFrequency;
+use std::fs;
+use std::path::Path;
+
+///
+/// Get current frequency, limits, governor and throttle counters of every online core
+///
+pub fn get_cpu_frequencies() -> Vec<CpuCoreFrequency> {
+  read_cpu_frequencies(Path::new("/sys/devices/system/cpu"))
+}
+
+///
+/// Read `cpuN/cpufreq` and `cpuN/thermal_throttle` under `root` (`/sys/devices/system/cpu`)
+///
+/// Cores without `scaling_cur_freq` (offline or no cpufreq driver) are skipped.
+/// `throttle_events` / `is_throttled` are always empty here since they require a previous
+/// sample.
+///
+pub fn read_cpu_frequencies(root: &Path) -> Vec<CpuCoreFrequency> {
+  let mut cores: Vec<CpuCoreFrequency> = fs::read_dir(root)
+    .map(|entries| {
+      entries
+        .flatten()
+        .filter_map(|entry| {
+          let core = entry
+            .file_name()
+            .to_str()?
+            .strip_prefix("cpu")?
+            .parse::<u32>()
+            .ok()?;
+          let cpufreq = entry.path().join("cpufreq");
+          let throttle = entry.path().join("thermal_throttle");
+
+          Some(CpuCoreFrequency {
+            core,
+            current_mhz: read_khz_as_mhz(&cpufreq, "scaling_cur_freq")?,
+            min_mhz: read_khz_as_mhz(&cpufreq, "scaling_min_freq"),
+            max_mhz: read_khz_as_mhz(&cpufreq, "scaling_max_freq"),
+            governor: sysfs::read_attribute(&cpufreq, "scaling_governor"),
+            core_throttle_count: read_count(&throttle, "core_throttle_count"),
+            package_throttle_count: read_count(&throttle, "package_throttle_count"),
+            throttle_events: 0,
+            is_throttled: false,
+          })
+        })
+        .collect()
+    })
+    .unwrap_or_default();
+
+  cores.sort_by_key(|core| core.core);
+  cores
+}
+
+///
+/// Number of throttle events (core + package) between two samples of the same core
+///
+/// Counters that went backwards or are missing count as no event.
+///
+pub fn throttle_events(prev: &CpuCoreFrequency, curr: &CpuCoreFrequency) -> u32 {
+  let delta = |prev: Option<u32>, curr: Option<u32>| match (prev, curr) {
+    (Some(p), Some(c)) => c.saturating_sub(p),
+    _ => 0,
+  };
+
+  delta(prev.core_throttle_count, curr.core_throttle_count).saturating_add(delta(
+    prev.package_throttle_count,
+    curr.package_throttle_count,
+  ))
+}
+
+fn read_khz_as_mhz(dir: &Path, name: &str) -> Option<f32> {
+  let khz = sysfs::read_attribute(dir, name)?.parse::<u32>().ok()?;
+  Some((khz as f32 / 1000.0).round())
+}
+
+fn read_count(dir: &Path, name: &str) -> Option<u32> {
+  let count = sysfs::read_attribute(dir, name)?.parse::<u64>().ok()?;
+  Some(count.min(u32::MAX as u64) as u32)
+}
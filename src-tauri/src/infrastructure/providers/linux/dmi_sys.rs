@@ -0,0 +1,122 @@
+use crate::infrastructure::providers::sysfs;
+use crate::models::hardware::BoardInfo;
+use std::path::Path;
+
+/// Filler strings for unset DMI / SMBIOS fields (lowercase)
+///
+/// Covers both what board vendors leave in the tables and what dmidecode prints for
+/// fields that are not populated.
+const PLACEHOLDER_VALUES: [&str; 9] = [
+  "to be filled by o.e.m.",
+  "default string",
+  "not specified",
+  "not applicable",
+  "not provided",
+  "system product name",
+  "system manufacturer",
+  "no dimm",
+  "n/a",
+];
+
+/// Generic words that only mean "unset" in some fields (lowercase)
+///
+/// A chassis of type `Other` or a board named `None` is a real value, so these are
+/// checked per field with [`is_generic_value`] instead of globally.
+const GENERIC_VALUES: [&str; 4] = ["unknown", "undefined", "other", "none"];
+
+///
+/// Get motherboard, BIOS and chassis information from `/sys/class/dmi/id`
+///
+pub fn get_board_info() -> Option<BoardInfo> {
+  read_board_info(Path::new("/sys/class/dmi/id"))
+}
+
+///
+/// Read DMI attributes under `dmi_dir` (`/sys/class/dmi/id`)
+///
+/// Serial numbers / UUIDs are root-only and are not read.
+/// Returns `None` when DMI is not available (e.g. most ARM boards).
+///
+pub fn read_board_info(dmi_dir: &Path) -> Option<BoardInfo> {
+  let read = |name: &str| {
+    sysfs::read_attribute(dmi_dir, name).filter(|value| !is_placeholder(value))
+  };
+
+  let info = BoardInfo {
+    board_vendor: read("board_vendor"),
+    board_name: read("board_name"),
+    board_version: read("board_version"),
+    bios_vendor: read("bios_vendor"),
+    bios_version: read("bios_version"),
+    bios_date: read("bios_date"),
+    system_vendor: read("sys_vendor"),
+    product_name: read("product_name"),
+    chassis_type: read("chassis_type")
+      .and_then(|code| code.parse::<u8>().ok())
+      .map(|code| chassis_type_name(code).to_string()),
+  };
+
+  if info == BoardInfo::default() {
+    return None;
+  }
+
+  Some(info)
+}
+
+///
+/// Convert SMBIOS chassis type code (System Enclosure, Type 3) to its name
+///
+pub fn chassis_type_name(code: u8) -> &'static str {
+  // Bit 7 is the chassis lock flag
+  match code & 0x7f {
+    1 => "Other",
+    3 => "Desktop",
+    4 => "Low Profile Desktop",
+    5 => "Pizza Box",
+    6 => "Mini Tower",
+    7 => "Tower",
+    8 => "Portable",
+    9 => "Laptop",
+    10 => "Notebook",
+    11 => "Hand Held",
+    12 => "Docking Station",
+    13 => "All in One",
+    14 => "Sub Notebook",
+    15 => "Space-saving",
+    16 => "Lunch Box",
+    17 => "Main Server Chassis",
+    18 => "Expansion Chassis",
+    19 => "SubChassis",
+    20 => "Bus Expansion Chassis",
+    21 => "Peripheral Chassis",
+    22 => "RAID Chassis",
+    23 => "Rack Mount Chassis",
+    24 => "Sealed-case PC",
+    25 => "Multi-system Chassis",
+    26 => "Compact PCI",
+    27 => "Advanced TCA",
+    28 => "Blade",
+    29 => "Blade Enclosure",
+    30 => "Tablet",
+    31 => "Convertible",
+    32 => "Detachable",
+    33 => "IoT Gateway",
+    34 => "Embedded PC",
+    35 => "Mini PC",
+    36 => "Stick PC",
+    _ => "Unknown",
+  }
+}
+
+/// Whether a DMI string is a placeholder rather than a real value (case-insensitive)
+pub fn is_placeholder(value: &str) -> bool {
+  PLACEHOLDER_VALUES.contains(&value.trim().to_lowercase().as_str())
+}
+
+/// Whether a DMI string is a generic word such as `Unknown` or `None` (case-insensitive)
+///
+/// Only use this for fields where such a word cannot be a real value (e.g. the
+/// manufacturer or part number of a memory module).
+pub fn is_generic_value(value: &str) -> bool {
+  GENERIC_VALUES.contains(&value.trim().to_lowercase().as_str())
+}
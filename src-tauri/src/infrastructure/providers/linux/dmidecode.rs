@@ -1,3 +1,4 @@
+use crate::infrastructure::providers::linux::dmi_sys;
 use crate::models;
 use crate::utils;
 use std;
@@ -83,5 +84,108 @@ pub fn parse_dmidecode_memory_info(raw: &str) -> models::hardware::MemoryInfo {
     memory_count: count,
     total_slots,
     memory_type,
+    modules: parse_dmidecode_memory_modules(raw),
   }
 }
+
+///
+/// Parse every `Memory Device` block into a per-slot list
+///
+/// Slots without a module are kept and marked with `is_empty`.
+///
+pub fn parse_dmidecode_memory_modules(raw: &str) -> Vec<models::hardware::MemoryModule> {
+  let mut blocks: Vec<Vec<(&str, &str)>> = Vec::new();
+  let mut in_device = false;
+
+  for line in raw.lines() {
+    let trimmed = line.trim();
+
+    if trimmed == "Memory Device" {
+      blocks.push(Vec::new());
+      in_device = true;
+      continue;
+    }
+
+    // A block ends at a blank line or the next `Handle` header
+    if trimmed.is_empty() || !line.starts_with(char::is_whitespace) {
+      in_device = false;
+      continue;
+    }
+
+    if in_device
+      && let Some((key, value)) = trimmed.split_once(':')
+      && let Some(block) = blocks.last_mut()
+    {
+      block.push((key.trim(), value.trim()));
+    }
+  }
+
+  blocks
+    .into_iter()
+    .map(|fields| parse_memory_device(&fields))
+    .collect()
+}
+
+fn parse_memory_device(fields: &[(&str, &str)]) -> models::hardware::MemoryModule {
+  let get = |key: &str| {
+    fields
+      .iter()
+      .find(|(k, _)| *k == key)
+      .map(|(_, value)| *value)
+      .filter(|value| !value.is_empty() && !dmi_sys::is_placeholder(value))
+      .map(str::to_string)
+  };
+  // dmidecode prints `Unknown` / `Other` / `None` for unset module fields
+  let get_known = |key: &str| get(key).filter(|value| !dmi_sys::is_generic_value(value));
+  // `3200 MT/s` / `1600 MHz` (older dmidecode)
+  let get_number = |key: &str| {
+    get(key).and_then(|value| value.split_whitespace().next()?.parse::<u32>().ok())
+  };
+
+  let size_mb = get("Size").and_then(|size| parse_size_mb(&size));
+  let is_empty = size_mb.is_none();
+
+  if is_empty {
+    return models::hardware::MemoryModule {
+      locator: get("Locator").unwrap_or_default(),
+      bank_locator: get("Bank Locator"),
+      is_empty,
+      ..Default::default()
+    };
+  }
+
+  models::hardware::MemoryModule {
+    locator: get("Locator").unwrap_or_default(),
+    bank_locator: get("Bank Locator"),
+    is_empty,
+    size_mb,
+    memory_type: get_known("Type").filter(|typ| typ != "RAM"),
+    rated_speed_mts: get_number("Speed"),
+    configured_speed_mts: get_number("Configured Memory Speed")
+      .or_else(|| get_number("Configured Clock Speed")),
+    manufacturer: get_known("Manufacturer"),
+    part_number: get_known("Part Number"),
+    serial_number: get_known("Serial Number"),
+    rank: get_number("Rank"),
+  }
+}
+
+///
+/// Parse `Size` of a Memory Device (e.g. `16 GB`, `8192 MB`) into MB
+///
+/// Returns `None` for empty slots (`No Module Installed`)
+///
+fn parse_size_mb(size: &str) -> Option<u32> {
+  let mut parts = size.split_whitespace();
+  let value = parts.next()?.parse::<u32>().ok()?;
+
+  let mb = match parts.next()? {
+    "TB" => value * 1024 * 1024,
+    "GB" => value * 1024,
+    "MB" => value,
+    "kB" | "KB" => value / 1024,
+    _ => return None,
+  };
+
+  (mb > 0).then_some(mb)
+}
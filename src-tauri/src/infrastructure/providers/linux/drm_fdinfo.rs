@@ -0,0 +1,303 @@
+use crate::models::hardware::{DrmClient, DrmEngineCounter};
+use std::collections::{HashMap, HashSet};
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+///
+/// Get every DRM client visible in `/proc`
+///
+/// Only processes owned by the current user are readable unless running as root.
+///
+pub fn get_drm_clients() -> Vec<DrmClient> {
+  read_drm_clients(Path::new("/proc"))
+}
+
+///
+/// Read `<pid>/fdinfo/*` of every process under `proc_root`
+///
+/// Only fds whose `<pid>/fd/N` link points into `/dev/dri/` are read, so the
+/// thousands of sockets and files of a desktop session cost one `readlink` each.
+/// A client shared by several fds (dup / fork) is reported once.
+///
+pub fn read_drm_clients(proc_root: &Path) -> Vec<DrmClient> {
+  let mut clients: HashMap<(String, u64), DrmClient> = HashMap::new();
+
+  let Ok(processes) = fs::read_dir(proc_root) else {
+    return Vec::new();
+  };
+
+  for process in processes.flatten() {
+    let Some(pid) = process
+      .file_name()
+      .to_str()
+      .and_then(|name| name.parse::<u32>().ok())
+    else {
+      continue;
+    };
+    let Ok(fds) = fs::read_dir(process.path().join("fd")) else {
+      continue;
+    };
+
+    for fd in fds.flatten() {
+      let is_drm =
+        fs::read_link(fd.path()).is_ok_and(|target| target.starts_with("/dev/dri/"));
+      if !is_drm {
+        continue;
+      }
+
+      let Some(mut client) =
+        fs::read_to_string(process.path().join("fdinfo").join(fd.file_name()))
+          .ok()
+          .and_then(|content| parse_fdinfo(&content))
+      else {
+        continue;
+      };
+      client.pid = pid;
+      clients
+        .entry((client.pdev.clone(), client.client_id))
+        .or_insert(client);
+    }
+  }
+
+  let mut clients: Vec<DrmClient> = clients.into_values().collect();
+  clients.sort_by(|a, b| (&a.pdev, a.client_id).cmp(&(&b.pdev, b.client_id)));
+  clients
+}
+
+///
+/// Parse a DRM fdinfo file
+///
+/// Returns `None` for fds that are not DRM clients.
+///
+/// ```text
+/// drm-driver: i915
+/// drm-client-id: 7
+/// drm-pdev: 0000:00:02.0
+/// drm-engine-render: 25662044495 ns
+/// drm-engine-capacity-video: 2
+/// drm-resident-local0: 65536 KiB
+/// ```
+///
+/// Memory comes from `drm-resident-*`, or the legacy `drm-memory-*` on older kernels.
+///
+pub fn parse_fdinfo(content: &str) -> Option<DrmClient> {
+  let mut driver = None;
+  let mut pdev = None;
+  let mut client_id = None;
+  let mut engines: HashMap<String, DrmEngineCounter> = HashMap::new();
+  let mut memory: HashMap<String, u64> = HashMap::new();
+
+  for line in content.lines() {
+    let Some((key, value)) = line.split_once(':') else {
+      continue;
+    };
+    let value = value.trim();
+    let number = || value.split_whitespace().next()?.parse::<u64>().ok();
+
+    match key.trim() {
+      "drm-driver" => driver = Some(value.to_string()),
+      "drm-pdev" => pdev = Some(value.to_string()),
+      "drm-client-id" => client_id = number(),
+      key if key.starts_with("drm-resident-") || key.starts_with("drm-memory-") => {
+        let Some(bytes) = parse_memory_bytes(value) else {
+          continue;
+        };
+
+        if let Some(region) = key.strip_prefix("drm-resident-") {
+          memory.insert(region.to_string(), bytes);
+        } else if let Some(region) = key.strip_prefix("drm-memory-") {
+          memory.entry(region.to_string()).or_insert(bytes);
+        }
+      }
+      key => {
+        let Some(number) = number() else {
+          continue;
+        };
+
+        if let Some(engine) = key.strip_prefix("drm-engine-capacity-") {
+          engines.entry(engine.to_string()).or_default().capacity = number as u32;
+        } else if let Some(engine) = key
+          .strip_prefix("drm-engine-")
+          .or_else(|| key.strip_prefix("drm-cycles-"))
+        {
+          engines.entry(engine.to_string()).or_default().busy = number;
+        } else if let Some(engine) = key.strip_prefix("drm-total-cycles-") {
+          engines.entry(engine.to_string()).or_default().total = Some(number);
+        }
+      }
+    }
+  }
+
+  for counter in engines.values_mut() {
+    counter.capacity = counter.capacity.max(1);
+  }
+
+  Some(DrmClient {
+    pid: 0,
+    driver: driver?,
+    pdev: pdev.unwrap_or_default(),
+    client_id: client_id?,
+    engines,
+    memory,
+  })
+}
+
+///
+/// Parse a fdinfo memory value (`65536 KiB`, `64 MiB`, or plain bytes)
+///
+fn parse_memory_bytes(value: &str) -> Option<u64> {
+  let mut parts = value.split_whitespace();
+  let amount = parts.next()?.parse::<u64>().ok()?;
+  let multiplier = match parts.next() {
+    None => 1,
+    Some("KiB") => 1024,
+    Some("MiB") => 1024 * 1024,
+    Some("GiB") => 1024 * 1024 * 1024,
+    Some(_) => return None,
+  };
+
+  Some(amount * multiplier)
+}
+
+///
+/// Busyness (%) of each engine between two samples of the same clients
+///
+/// Clients that are not in both samples are ignored. Engines report
+/// nanoseconds against wall time, or (xe) busy cycles against total cycles.
+///
+pub fn engine_busy_percents(
+  prev: &[&DrmClient],
+  curr: &[&DrmClient],
+  elapsed: Duration,
+) -> HashMap<String, f32> {
+  // engine -> (busy delta, total delta, capacity)
+  let mut deltas: HashMap<&str, (u64, Option<u64>, u32)> = HashMap::new();
+
+  for client in curr {
+    let Some(prev) = prev
+      .iter()
+      .find(|prev| prev.pdev == client.pdev && prev.client_id == client.client_id)
+    else {
+      continue;
+    };
+
+    for (engine, counter) in &client.engines {
+      let Some(prev_counter) = prev.engines.get(engine) else {
+        continue;
+      };
+      let entry = deltas.entry(engine).or_insert((0, None, counter.capacity));
+      entry.0 += counter.busy.saturating_sub(prev_counter.busy);
+      if let (Some(total), Some(prev_total)) = (counter.total, prev_counter.total) {
+        // Total cycles are GPU wide, so take (not sum) the largest delta
+        let delta = total.saturating_sub(prev_total);
+        entry.1 = Some(entry.1.map_or(delta, |current: u64| current.max(delta)));
+      }
+    }
+  }
+
+  let elapsed_ns = elapsed.as_nanos() as f64;
+
+  deltas
+    .into_iter()
+    .filter_map(|(engine, (busy, total, capacity))| {
+      let period = match total {
+        Some(total) => total as f64,
+        None => elapsed_ns,
+      } * capacity as f64;
+      if period <= 0.0 {
+        return None;
+      }
+      let percent = (busy as f64 / period * 100.0).min(100.0) as f32;
+      Some((engine.to_string(), percent))
+    })
+    .collect()
+}
+
+///
+/// Overall busyness (%) of a GPU: the busiest engine, like `intel_gpu_top`'s headline
+///
+/// Returns `0.0` when no client of the GPU is visible.
+///
+pub fn gpu_busy_percent(
+  prev: &[DrmClient],
+  curr: &[DrmClient],
+  pdev: &str,
+  elapsed: Duration,
+) -> f32 {
+  let prev: Vec<&DrmClient> = prev.iter().filter(|c| c.pdev == pdev).collect();
+  let curr: Vec<&DrmClient> = curr.iter().filter(|c| c.pdev == pdev).collect();
+
+  engine_busy_percents(&prev, &curr, elapsed)
+    .into_values()
+    .fold(0.0, f32::max)
+}
+
+///
+/// GPU usage (%) of each process between two samples
+///
+/// Per GPU the busiest engine of the process counts, and a process using several
+/// GPUs reports the busiest one. Processes without a previous sample report `0.0`.
+///
+pub fn process_gpu_percents(
+  prev: &[DrmClient],
+  curr: &[DrmClient],
+  elapsed: Duration,
+) -> HashMap<u32, f32> {
+  let mut percents: HashMap<u32, f32> = HashMap::new();
+
+  for client in curr {
+    if percents.contains_key(&client.pid) {
+      continue;
+    }
+
+    let pdevs: HashSet<&str> = curr
+      .iter()
+      .filter(|c| c.pid == client.pid)
+      .map(|c| c.pdev.as_str())
+      .collect();
+
+    let percent = pdevs
+      .into_iter()
+      .map(|pdev| {
+        let prev: Vec<&DrmClient> = prev.iter().filter(|c| c.pdev == pdev).collect();
+        let curr: Vec<&DrmClient> = curr
+          .iter()
+          .filter(|c| c.pid == client.pid && c.pdev == pdev)
+          .collect();
+
+        engine_busy_percents(&prev, &curr, elapsed)
+          .into_values()
+          .fold(0.0, f32::max)
+      })
+      .fold(0.0, f32::max);
+
+    percents.insert(client.pid, percent);
+  }
+
+  percents
+}
+
+///
+/// Dedicated GPU memory (MB) resident for each process
+///
+/// Only device-local regions count: `vram*` (amdgpu / xe / nouveau) and `local*` (i915).
+///
+pub fn process_gpu_memory_mb(clients: &[DrmClient]) -> HashMap<u32, f32> {
+  let mut memory: HashMap<u32, u64> = HashMap::new();
+
+  for client in clients {
+    let bytes: u64 = client
+      .memory
+      .iter()
+      .filter(|(region, _)| region.starts_with("vram") || region.starts_with("local"))
+      .map(|(_, bytes)| bytes)
+      .sum();
+    *memory.entry(client.pid).or_default() += bytes;
+  }
+
+  memory
+    .into_iter()
+    .map(|(pid, bytes)| (pid, bytes as f32 / 1024.0 / 1024.0))
+    .collect()
+}
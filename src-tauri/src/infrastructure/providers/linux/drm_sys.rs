@@ -1,5 +1,7 @@
+use crate::models::hardware_archive::DrmClientSnapshots;
+use std::collections::HashMap;
 use std::fs;
-use std::process::Command;
+use std::path::Path;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum GpuVendor {
@@ -22,7 +24,7 @@ pub async fn get_card_ids() -> Result<Vec<CardInfo>, String> {
 
   let mut join_set = JoinSet::new();
 
-  for card_id in 0..=9 {
+  for card_id in get_all_card_ids().into_iter().map(u32::from) {
     let vendor_path = format!("/sys/class/drm/card{card_id}/device/vendor");
     if std::path::Path::new(&vendor_path).exists() {
       join_set.spawn(async move {
@@ -63,35 +65,71 @@ pub async fn get_amd_gpu_usage(card_id: u32) -> Result<f64, String> {
   Ok((percent / 100.0).into())
 }
 
-pub async fn get_intel_gpu_usage() -> Result<f64, String> {
-  let output = Command::new("intel_gpu_top")
-    .args(["-J", "-s", "1000"]) // JSON output, get only 1 second
-    .output()
-    .map_err(|e| format!("Failed to run intel_gpu_top: {e}"))?;
-
-  if !output.status.success() {
-    return Err("intel_gpu_top failed".to_string());
-  }
+///
+/// Busyness (%) of Intel cards (i915 / xe) from the DRM fdinfo snapshots of the
+/// last two ticks
+///
+/// Empty until two snapshots were taken.
+///
+pub fn get_intel_gpu_usages(
+  snapshots: &DrmClientSnapshots,
+  card_ids: &[u8],
+) -> HashMap<u8, f32> {
+  use crate::infrastructure::providers::drm_fdinfo;
+
+  let Some((prev, curr, elapsed)) = snapshots.delta() else {
+    return HashMap::new();
+  };
+
+  card_ids
+    .iter()
+    .filter(|card_id| detect_gpu_vendor(**card_id) == GpuVendor::Intel)
+    .filter_map(|card_id| {
+      let pdev = get_card_pdev(*card_id as u32)?;
+      Some((
+        *card_id,
+        drm_fdinfo::gpu_busy_percent(prev, curr, &pdev, elapsed),
+      ))
+    })
+    .collect()
+}
 
-  let stdout = String::from_utf8_lossy(&output.stdout);
-  for line in stdout.lines() {
-    if line.contains("\"render busy\"")
-      && let Some(value_str) = line.split(':').nth(1)
-      && let Ok(value) = value_str.trim().trim_end_matches(',').parse::<f32>()
-    {
-      return Ok((value / 100.0).into());
-    }
-  }
+///
+/// PCI address of a DRM card (e.g. `0000:00:02.0`), as used by `drm-pdev` in fdinfo
+///
+pub fn get_card_pdev(card_id: u32) -> Option<String> {
+  let device = fs::canonicalize(format!("/sys/class/drm/card{card_id}/device")).ok()?;
+  Some(device.file_name()?.to_str()?.to_string())
+}
 
-  Err("Could not parse intel_gpu_top output".to_string())
+///
+/// Read the GT frequency (MHz) of an Intel card directory (`/sys/class/drm/cardN`)
+///
+/// - i915: `gt_{cur,max}_freq_mhz`
+/// - xe: `device/tile0/gt0/freq0/{cur,max}_freq`
+///
+pub fn read_intel_gt_frequency_mhz(card_dir: &Path, kind: &str) -> Option<u32> {
+  [
+    card_dir.join(format!("gt_{kind}_freq_mhz")),
+    card_dir.join(format!("device/tile0/gt0/freq0/{kind}_freq")),
+  ]
+  .iter()
+  .find_map(|path| fs::read_to_string(path).ok()?.trim().parse::<u32>().ok())
+  .filter(|mhz| *mhz > 0)
 }
 
 /// Enumerate all `card*` in `/sys/class/drm/` and return card IDs
 pub fn get_all_card_ids() -> Vec<u8> {
-  use regex::Regex;
-  use std::path::Path;
+  read_card_ids(Path::new("/sys/class/drm"))
+}
 
-  let path = Path::new("/sys/class/drm");
+///
+/// Enumerate `cardN` entries of a DRM class directory (sorted by N)
+///
+/// Connectors (`card0-DP-1`) and render nodes (`renderD128`) are skipped.
+///
+pub fn read_card_ids(path: &Path) -> Vec<u8> {
+  use regex::Regex;
 
   let re = Regex::new(r"^card(\d+)$").unwrap();
 
@@ -112,6 +150,22 @@ pub fn get_all_card_ids() -> Vec<u8> {
   ids
 }
 
+/// Read `gpu_busy_percent` of the given card without blocking
+///
+/// Only drivers that expose the file (e.g. amdgpu) return `Some`
+pub fn read_gpu_busy_percent(card_id: u8) -> Option<f32> {
+  let path = format!("/sys/class/drm/card{card_id}/device/gpu_busy_percent");
+  parse_gpu_busy_percent(&fs::read_to_string(path).ok()?)
+}
+
+pub fn parse_gpu_busy_percent(content: &str) -> Option<f32> {
+  content
+    .trim()
+    .parse::<f32>()
+    .ok()
+    .filter(|percent| (0.0..=100.0).contains(percent))
+}
+
 pub fn read_vram_total_bytes(card_id: u8) -> Option<u64> {
   let path = format!("/sys/class/drm/card{card_id}/device/mem_info_vram_total");
   fs::read_to_string(path).ok()?.trim().parse::<u64>().ok()
@@ -0,0 +1,177 @@
+use super::sysfs;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TemperatureSensor {
+  pub label: String,
+  /// Temperature in Celsius
+  pub value: f32,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct FanSensor {
+  pub label: String,
+  /// Current speed in RPM (`fanN_input`)
+  pub rpm: Option<u32>,
+  /// PWM duty cycle in percent (`pwmN` scaled by `pwmN_max`)
+  pub pwm_percent: Option<f32>,
+}
+
+/// hwmon drivers that belong to a GPU
+const GPU_HWMON_DRIVERS: [&str; 4] = ["amdgpu", "radeon", "nouveau", "xe"];
+
+///
+/// Enumerate `hwmon*` directories under the given parent
+///
+/// e.g. `/sys/class/drm/card0/device/hwmon` -> `[.../hwmon/hwmon3]`
+///
+pub fn get_hwmon_dirs(parent: &Path) -> Vec<PathBuf> {
+  let mut dirs: Vec<PathBuf> = fs::read_dir(parent)
+    .map(|entries| {
+      entries
+        .flatten()
+        .filter(|entry| entry.file_name().to_string_lossy().starts_with("hwmon"))
+        .map(|entry| entry.path())
+        .collect()
+    })
+    .unwrap_or_default();
+
+  dirs.sort();
+  dirs
+}
+
+///
+/// Enumerate hwmon directories of a DRM card (`/sys/class/drm/cardN/device/hwmon/hwmon*`)
+///
+pub fn get_card_hwmon_dirs(card_id: u8) -> Vec<PathBuf> {
+  get_hwmon_dirs(Path::new(&format!(
+    "/sys/class/drm/card{card_id}/device/hwmon"
+  )))
+}
+
+///
+/// Read all `temp*_input` sensors of a hwmon directory
+///
+/// Labels come from `temp*_label` (e.g. `edge` / `junction` / `mem` on amdgpu)
+/// and fall back to the sensor name (e.g. `temp1`) when not provided.
+///
+pub fn read_temperatures(hwmon_dir: &Path) -> Vec<TemperatureSensor> {
+  get_sensor_indexes(hwmon_dir, "temp")
+    .into_iter()
+    .filter_map(|index| {
+      let value = sysfs::read_attribute(hwmon_dir, &format!("temp{index}_input"))
+        .and_then(|raw| parse_millidegrees(&raw))?;
+      let label = read_label(hwmon_dir, &format!("temp{index}"));
+
+      Some(TemperatureSensor { label, value })
+    })
+    .collect()
+}
+
+///
+/// Read `{sensor}_label`, falling back to the sensor name itself
+///
+pub fn read_label(hwmon_dir: &Path, sensor: &str) -> String {
+  sysfs::read_attribute(hwmon_dir, &format!("{sensor}_label"))
+    .unwrap_or_else(|| sensor.to_string())
+}
+
+///
+/// Read all fans of a hwmon directory
+///
+/// A fan is listed when either `fanN_input` (tachometer) or `pwmN` (duty) exists,
+/// so PWM-only headers and tach-only fans are both reported.
+///
+pub fn read_fans(hwmon_dir: &Path) -> Vec<FanSensor> {
+  let mut indexes = get_sensor_indexes(hwmon_dir, "fan");
+  indexes.extend(get_pwm_indexes(hwmon_dir));
+  indexes.sort();
+  indexes.dedup();
+
+  indexes
+    .into_iter()
+    .map(|index| FanSensor {
+      label: read_label(hwmon_dir, &format!("fan{index}")),
+      rpm: sysfs::read_attribute(hwmon_dir, &format!("fan{index}_input"))
+        .and_then(|raw| raw.parse::<u32>().ok()),
+      pwm_percent: sysfs::read_attribute(hwmon_dir, &format!("pwm{index}")).and_then(
+        |raw| {
+          let max = sysfs::read_attribute(hwmon_dir, &format!("pwm{index}_max"))
+            .and_then(|max| max.parse::<u32>().ok());
+          parse_pwm_percent(&raw, max)
+        },
+      ),
+    })
+    .collect()
+}
+
+/// Whether the hwmon chip name (`name` attribute) belongs to a GPU driver
+pub fn is_gpu_driver(name: &str) -> bool {
+  GPU_HWMON_DRIVERS.contains(&name.trim())
+}
+
+///
+/// Convert raw `pwmN` value to percent
+///
+/// `max` defaults to 255 which is the range defined by the hwmon sysfs ABI
+///
+pub fn parse_pwm_percent(raw: &str, max: Option<u32>) -> Option<f32> {
+  let max = max.filter(|max| *max > 0).unwrap_or(255) as f32;
+  let value = raw.trim().parse::<u32>().ok()? as f32;
+
+  Some(((value / max * 100.0).min(100.0) * 10.0).round() / 10.0)
+}
+
+/// Collect `N` of every `pwmN` file in a hwmon directory
+fn get_pwm_indexes(hwmon_dir: &Path) -> Vec<u32> {
+  fs::read_dir(hwmon_dir)
+    .map(|entries| {
+      entries
+        .flatten()
+        .filter_map(|entry| {
+          entry
+            .file_name()
+            .to_str()?
+            .strip_prefix("pwm")?
+            .parse::<u32>()
+            .ok()
+        })
+        .collect()
+    })
+    .unwrap_or_default()
+}
+
+///
+/// Collect `N` of every `{prefix}N_input` file in a hwmon directory
+///
+pub fn get_sensor_indexes(hwmon_dir: &Path, prefix: &str) -> Vec<u32> {
+  let mut indexes: Vec<u32> = fs::read_dir(hwmon_dir)
+    .map(|entries| {
+      entries
+        .flatten()
+        .filter_map(|entry| {
+          entry
+            .file_name()
+            .to_str()?
+            .strip_prefix(prefix)?
+            .strip_suffix("_input")?
+            .parse::<u32>()
+            .ok()
+        })
+        .collect()
+    })
+    .unwrap_or_default();
+
+  indexes.sort();
+  indexes
+}
+
+/// Convert hwmon millidegree Celsius value to Celsius
+pub fn parse_millidegrees(raw: &str) -> Option<f32> {
+  raw
+    .trim()
+    .parse::<i64>()
+    .ok()
+    .map(|millidegrees| millidegrees as f32 / 1000.0)
+}
@@ -1,6 +1,20 @@
+pub mod amdgpu;
+pub mod block_sys;
+pub mod cpu_topology;
+pub mod cpufreq;
+pub mod dmi_sys;
 pub mod dmidecode;
+pub mod drm_fdinfo;
 pub mod drm_sys;
+pub mod hwmon;
 pub mod kernel;
-pub mod lspci;
 pub mod net_sys;
+pub mod nvidia_smi;
+pub mod pci_sys;
+pub mod power_supply;
+pub mod powercap;
 pub mod procfs;
+pub mod smartctl;
+pub mod smbios;
+pub mod sysfs;
+pub mod thermal_sys;
@@ -1,8 +1,9 @@
 use std::collections::HashMap;
 use std::net::IpAddr;
+use std::path::Path;
 use std::process::Command;
 
-use crate::models::hardware::NetworkInfo;
+use crate::models::hardware::{NetworkCounters, NetworkInfo};
 
 ///
 /// ## Get network information
@@ -128,3 +129,47 @@ fn get_default_gateway(cmd: &str, ip_pos: usize) -> Result<Vec<String>, String>
       .collect(),
   )
 }
+
+///
+/// ## Get cumulative counters of every interface
+///
+pub fn get_network_counters() -> Result<HashMap<String, NetworkCounters>, String> {
+  let mut counters = HashMap::new();
+
+  for entry in std::fs::read_dir("/sys/class/net").map_err(|e| e.to_string())? {
+    let entry = entry.map_err(|e| e.to_string())?;
+    let iface = entry.file_name().into_string().unwrap_or_default();
+
+    if let Some(stats) = read_network_counters(&entry.path().join("statistics")) {
+      counters.insert(iface, stats);
+    }
+  }
+
+  Ok(counters)
+}
+
+///
+/// ## Read counters from a `statistics` directory
+///
+/// Returns `None` when the byte counters are missing. Other counters default to 0.
+///
+pub fn read_network_counters(statistics_dir: &Path) -> Option<NetworkCounters> {
+  let read = |name: &str| -> Option<u64> {
+    std::fs::read_to_string(statistics_dir.join(name))
+      .ok()?
+      .trim()
+      .parse::<u64>()
+      .ok()
+  };
+
+  Some(NetworkCounters {
+    rx_bytes: read("rx_bytes")?,
+    tx_bytes: read("tx_bytes")?,
+    rx_packets: read("rx_packets").unwrap_or(0),
+    tx_packets: read("tx_packets").unwrap_or(0),
+    rx_errors: read("rx_errors").unwrap_or(0),
+    tx_errors: read("tx_errors").unwrap_or(0),
+    rx_dropped: read("rx_dropped").unwrap_or(0),
+    tx_dropped: read("tx_dropped").unwrap_or(0),
+  })
+}
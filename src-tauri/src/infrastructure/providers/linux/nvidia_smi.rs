@@ -0,0 +1,167 @@
+use crate::models::hardware::{GpuMetrics, NvidiaGpuStatus};
+use std::collections::HashMap;
+
+/// Fields passed to `nvidia-smi --query-gpu`, in column order
+const QUERY_FIELDS: [&str; 11] = [
+  "pci.bus_id",
+  "name",
+  "utilization.gpu",
+  "temperature.gpu",
+  "memory.used",
+  "memory.total",
+  "clocks.gr",
+  "clocks.mem",
+  "power.draw",
+  "power.limit",
+  "fan.speed",
+];
+
+///
+/// Source of NVIDIA GPU status on Linux
+///
+/// The proprietary driver exposes neither `gpu_busy_percent` nor hwmon, so the
+/// values have to come from the driver itself (`nvidia-smi` or NVML).
+///
+pub trait NvidiaBackend: Send + Sync {
+  fn query_gpus(&self) -> Result<Vec<NvidiaGpuStatus>, String>;
+}
+
+///
+/// Backend running `nvidia-smi --query-gpu=... --format=csv`
+///
+pub struct NvidiaSmiBackend;
+
+impl NvidiaBackend for NvidiaSmiBackend {
+  fn query_gpus(&self) -> Result<Vec<NvidiaGpuStatus>, String> {
+    let output = std::process::Command::new("nvidia-smi")
+      .arg(format!("--query-gpu={}", QUERY_FIELDS.join(",")))
+      .arg("--format=csv,noheader,nounits")
+      .output()
+      .map_err(|e| format!("Failed to execute nvidia-smi: {e}"))?;
+
+    if !output.status.success() {
+      return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+
+    Ok(parse_query_gpu_csv(&String::from_utf8_lossy(
+      &output.stdout,
+    )))
+  }
+}
+
+///
+/// Backend used on this system
+///
+pub fn get_backend() -> &'static dyn NvidiaBackend {
+  &NvidiaSmiBackend
+}
+
+///
+/// Query every NVIDIA GPU and key the result by DRM card id
+///
+/// GPUs are matched to `cardN` through their PCI address. Returns an empty map
+/// when there is no NVIDIA card or the backend is unavailable.
+///
+pub fn get_nvidia_card_statuses(
+  backend: &dyn NvidiaBackend,
+  card_ids: &[u8],
+) -> HashMap<u8, NvidiaGpuStatus> {
+  use crate::infrastructure::providers::drm_sys;
+
+  let nvidia_cards: Vec<(u8, String)> = card_ids
+    .iter()
+    .filter(|card_id| drm_sys::detect_gpu_vendor(**card_id) == drm_sys::GpuVendor::Nvidia)
+    .filter_map(|card_id| Some((*card_id, drm_sys::get_card_pdev(*card_id as u32)?)))
+    .collect();
+  if nvidia_cards.is_empty() {
+    return HashMap::new();
+  }
+
+  let Ok(statuses) = backend.query_gpus() else {
+    return HashMap::new();
+  };
+
+  nvidia_cards
+    .into_iter()
+    .filter_map(|(card_id, pdev)| {
+      let status = statuses.iter().find(|status| status.pci_address == pdev)?;
+      Some((card_id, status.clone()))
+    })
+    .collect()
+}
+
+///
+/// Parse the output of `nvidia-smi --query-gpu=<QUERY_FIELDS> --format=csv`
+///
+/// Accepts the header line and units of plain `csv` as well as
+/// `csv,noheader,nounits`. Unsupported values (`[N/A]`, `[Not Supported]`) are `None`.
+///
+/// ```text
+/// 00000000:01:00.0, NVIDIA GeForce RTX 4070, 37, 52, 1423, 12282, 2475, 10501, 48.12, 200.00, 30
+/// ```
+///
+pub fn parse_query_gpu_csv(content: &str) -> Vec<NvidiaGpuStatus> {
+  content
+    .lines()
+    .filter(|line| !line.trim().is_empty() && !line.starts_with("pci.bus_id"))
+    .filter_map(parse_query_gpu_line)
+    .collect()
+}
+
+fn parse_query_gpu_line(line: &str) -> Option<NvidiaGpuStatus> {
+  let columns: Vec<&str> = line.split(',').map(str::trim).collect();
+  if columns.len() != QUERY_FIELDS.len() {
+    return None;
+  }
+
+  let number = |index: usize| -> Option<f32> {
+    columns[index]
+      .split_whitespace()
+      .next()?
+      .parse::<f32>()
+      .ok()
+  };
+  let clock = |index: usize| number(index).map(|mhz| mhz.round() as u32);
+
+  Some(NvidiaGpuStatus {
+    pci_address: normalize_pci_bus_id(columns[0])?,
+    name: columns[1].to_string(),
+    usage: number(2),
+    temperature: number(3),
+    memory_used_mb: number(4),
+    memory_total_mb: number(5),
+    core_clock_mhz: clock(6),
+    memory_clock_mhz: clock(7),
+    power_watts: number(8),
+    power_limit_watts: number(9),
+    fan_speed_percent: number(10),
+  })
+}
+
+///
+/// Convert an NVIDIA bus id (`00000000:01:00.0`) to the sysfs form (`0000:01:00.0`)
+///
+pub fn normalize_pci_bus_id(bus_id: &str) -> Option<String> {
+  let (domain, rest) = bus_id.trim().split_once(':')?;
+  let domain = u32::from_str_radix(domain, 16).ok()?;
+
+  Some(format!("{domain:04x}:{}", rest.to_lowercase()))
+}
+
+///
+/// Clocks / memory / power part of an NVIDIA status as `GpuMetrics`
+///
+pub fn to_gpu_metrics(card: &str, status: &NvidiaGpuStatus) -> GpuMetrics {
+  GpuMetrics {
+    card: card.to_string(),
+    core_clock_mhz: status.core_clock_mhz,
+    memory_clock_mhz: status.memory_clock_mhz,
+    vram_used_mb: status.memory_used_mb,
+    vram_total_mb: status.memory_total_mb,
+    gtt_used_mb: None,
+    gtt_total_mb: None,
+    power_watts: status.power_watts,
+    power_cap_watts: status.power_limit_watts,
+    fan_speed_percent: status.fan_speed_percent,
+  }
+}
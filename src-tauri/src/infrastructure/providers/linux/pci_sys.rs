@@ -0,0 +1,388 @@
+use crate::infrastructure::providers::sysfs;
+use crate::models::hardware::{PciDevice, PcieLinkStatus};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::OnceLock;
+
+/// Locations of `pci.ids` used by hwdata / pciutils across distributions
+const PCI_IDS_PATHS: [&str; 3] = [
+  "/usr/share/hwdata/pci.ids",
+  "/usr/share/misc/pci.ids",
+  "/usr/share/pci.ids",
+];
+
+///
+/// Names of the PCI ID database (`pci.ids`)
+///
+#[derive(Debug, Default)]
+pub struct PciIds {
+  vendors: HashMap<u16, String>,
+  devices: HashMap<(u16, u16), String>,
+  /// (vendor, device, subvendor, subdevice)
+  subsystems: HashMap<(u16, u16, u16, u16), String>,
+  classes: HashMap<u8, String>,
+  subclasses: HashMap<(u8, u8), String>,
+}
+
+impl PciIds {
+  ///
+  /// Load the first `pci.ids` found on the system
+  ///
+  /// Returns an empty database when none is installed.
+  ///
+  pub fn load() -> Self {
+    PCI_IDS_PATHS
+      .iter()
+      .find_map(|path| fs::read_to_string(path).ok())
+      .map(|content| Self::parse(&content))
+      .unwrap_or_default()
+  }
+
+  ///
+  /// Parse `pci.ids`
+  ///
+  /// - `vvvv  vendor` / `\tdddd  device` / `\t\tssss ssss  subsystem`
+  /// - `C cc  class` / `\tss  subclass` (prog-if lines are ignored)
+  ///
+  pub fn parse(content: &str) -> Self {
+    let mut ids = Self::default();
+    let mut vendor: Option<u16> = None;
+    let mut device: Option<u16> = None;
+    let mut class: Option<u8> = None;
+
+    for line in content.lines() {
+      if line.is_empty() || line.starts_with('#') {
+        continue;
+      }
+
+      let depth = line.chars().take_while(|c| *c == '\t').count();
+      let Some((id, name)) = line.trim_start_matches('\t').split_once("  ") else {
+        continue;
+      };
+      let name = name.trim().to_string();
+
+      match depth {
+        0 if id.starts_with("C ") => {
+          vendor = None;
+          class = u8::from_str_radix(&id[2..], 16).ok();
+          if let Some(class) = class {
+            ids.classes.insert(class, name);
+          }
+        }
+        0 => {
+          class = None;
+          vendor = u16::from_str_radix(id, 16).ok();
+          if let Some(vendor) = vendor {
+            ids.vendors.insert(vendor, name);
+          }
+        }
+        1 => {
+          if let Some(class) = class {
+            if let Ok(subclass) = u8::from_str_radix(id, 16) {
+              ids.subclasses.insert((class, subclass), name);
+            }
+          } else if let Some(vendor) = vendor {
+            device = u16::from_str_radix(id, 16).ok();
+            if let Some(device) = device {
+              ids.devices.insert((vendor, device), name);
+            }
+          }
+        }
+        2 => {
+          if let (Some(vendor), Some(device), Some((subvendor, subdevice))) =
+            (vendor, device, id.split_once(' '))
+            && let (Ok(subvendor), Ok(subdevice)) = (
+              u16::from_str_radix(subvendor, 16),
+              u16::from_str_radix(subdevice, 16),
+            )
+          {
+            ids
+              .subsystems
+              .insert((vendor, device, subvendor, subdevice), name);
+          }
+        }
+        _ => {}
+      }
+    }
+
+    ids
+  }
+
+  /// Fill names of `device` from its IDs
+  fn resolve(&self, device: &mut PciDevice) {
+    let hex16 = |id: &str| u16::from_str_radix(id, 16).ok();
+    let hex8 = |id: &str| u8::from_str_radix(id, 16).ok();
+
+    let vendor = hex16(&device.vendor_id);
+    let id = hex16(&device.device_id);
+    let subsystem = device
+      .subsystem_vendor_id
+      .as_deref()
+      .and_then(hex16)
+      .zip(device.subsystem_device_id.as_deref().and_then(hex16));
+
+    device.vendor_name = vendor.and_then(|v| self.vendors.get(&v).cloned());
+    device.device_name = vendor
+      .zip(id)
+      .and_then(|(v, d)| self.devices.get(&(v, d)).cloned());
+    device.subsystem_name = vendor
+      .zip(id)
+      .zip(subsystem)
+      .and_then(|((v, d), (sv, sd))| self.subsystems.get(&(v, d, sv, sd)).cloned());
+
+    let class = device.class_code.get(0..2).and_then(hex8);
+    let subclass = device.class_code.get(2..4).and_then(hex8);
+    device.class_name = class.and_then(|class| {
+      subclass
+        .and_then(|subclass| self.subclasses.get(&(class, subclass)))
+        .or_else(|| self.classes.get(&class))
+        .cloned()
+    });
+  }
+}
+
+///
+/// System `pci.ids`, parsed once on first use
+///
+fn system_pci_ids() -> &'static PciIds {
+  static PCI_IDS: OnceLock<PciIds> = OnceLock::new();
+  PCI_IDS.get_or_init(PciIds::load)
+}
+
+///
+/// Get every PCI device with names resolved from the system `pci.ids`
+///
+pub fn get_pci_devices() -> Vec<PciDevice> {
+  read_pci_devices(Path::new("/sys/bus/pci/devices"), system_pci_ids())
+}
+
+///
+/// Read every device under `root` (`/sys/bus/pci/devices`), sorted by address
+///
+pub fn read_pci_devices(root: &Path, ids: &PciIds) -> Vec<PciDevice> {
+  let mut devices: Vec<PciDevice> = fs::read_dir(root)
+    .map(|entries| {
+      entries
+        .flatten()
+        .filter_map(|entry| read_pci_device(&entry.path(), ids))
+        .collect()
+    })
+    .unwrap_or_default();
+
+  devices.sort_by(|a, b| a.address.cmp(&b.address));
+  devices
+}
+
+///
+/// Read a single device directory (e.g. `/sys/bus/pci/devices/0000:01:00.0`)
+///
+/// Link attributes only exist on PCI Express devices.
+///
+pub fn read_pci_device(dir: &Path, ids: &PciIds) -> Option<PciDevice> {
+  let address = dir.file_name()?.to_str()?.to_string();
+
+  let mut device = PciDevice {
+    address,
+    vendor_id: read_id(dir, "vendor")?,
+    device_id: read_id(dir, "device")?,
+    subsystem_vendor_id: read_id(dir, "subsystem_vendor"),
+    subsystem_device_id: read_id(dir, "subsystem_device"),
+    class_code: read_id(dir, "class")?,
+    driver: fs::read_link(dir.join("driver"))
+      .ok()
+      .and_then(|link| Some(link.file_name()?.to_str()?.to_string())),
+    current_link_speed: sysfs::read_attribute(dir, "current_link_speed")
+      .and_then(|speed| parse_link_speed(&speed)),
+    max_link_speed: sysfs::read_attribute(dir, "max_link_speed")
+      .and_then(|speed| parse_link_speed(&speed)),
+    current_link_width: read_link_width(dir, "current_link_width"),
+    max_link_width: read_link_width(dir, "max_link_width"),
+    ..Default::default()
+  };
+
+  ids.resolve(&mut device);
+  Some(device)
+}
+
+///
+/// Find the PCI device backing a DRM card (`/sys/class/drm/cardN/device`)
+///
+pub fn get_drm_card_pci_device(card_id: u8) -> Option<PciDevice> {
+  let dir = fs::canonicalize(format!("/sys/class/drm/card{card_id}/device")).ok()?;
+  read_pci_device(&dir, system_pci_ids())
+}
+
+///
+/// PCIe link of the GPU behind a DRM card
+///
+pub fn get_drm_card_pcie_link(card_id: u8) -> Option<PcieLinkStatus> {
+  let dir = fs::canonicalize(format!("/sys/class/drm/card{card_id}/device")).ok()?;
+  read_pcie_link_status(&dir)
+}
+
+///
+/// PCIe link of the NVMe controller backing a block device (e.g. `/dev/nvme0n1p2`)
+///
+/// Returns `None` for non-NVMe devices.
+///
+pub fn get_block_device_pcie_link(device: &str) -> Option<PcieLinkStatus> {
+  let name = device.strip_prefix("/dev/").unwrap_or(device);
+  if !name.starts_with("nvme") {
+    return None;
+  }
+
+  // .../0000:01:00.0/nvme/nvme0/nvme0n1/nvme0n1p2
+  let block = fs::canonicalize(Path::new("/sys/class/block").join(name)).ok()?;
+  let pci_dir = block
+    .ancestors()
+    .find(|dir| dir.join("current_link_speed").exists())?;
+  read_pcie_link_status(pci_dir)
+}
+
+///
+/// Read the physical PCIe link of a device directory
+///
+/// GPUs such as AMD Navi contain their own PCIe switch, whose internal link
+/// always reports full speed. The link of the switch's upstream port (the
+/// topmost bridge of the same vendor below the root port) is used instead.
+///
+pub fn read_pcie_link_status(dir: &Path) -> Option<PcieLinkStatus> {
+  let dir = find_physical_link_dir(dir);
+  let read_speed = |dir: &Path, name: &str| {
+    sysfs::read_attribute(dir, name).and_then(|speed| parse_link_speed(&speed))
+  };
+  // Port on the other end of the link; the host bridge (`pci0000:00`) has none
+  let slot = dir
+    .parent()
+    .filter(|parent| read_id(parent, "vendor").is_some());
+
+  pcie_link_status(
+    read_speed(&dir, "current_link_speed"),
+    read_speed(&dir, "max_link_speed"),
+    read_link_width(&dir, "current_link_width"),
+    read_link_width(&dir, "max_link_width"),
+    slot.and_then(|slot| read_speed(slot, "max_link_speed")),
+    slot.and_then(|slot| read_link_width(slot, "max_link_width")),
+  )
+}
+
+///
+/// Compare the negotiated link against what the device and its slot support
+///
+/// - `is_slot_limited`: the slot's maximum link is below the device's (fixed)
+/// - `is_width_degraded` / `is_speed_degraded`: the current link is below the
+///   device's maximum (may change at runtime, e.g. GPU power saving)
+///
+/// Returns `None` when the device has no PCIe link (e.g. integrated GPUs).
+///
+pub fn pcie_link_status(
+  current_speed: Option<f32>,
+  max_speed: Option<f32>,
+  current_width: Option<u32>,
+  max_width: Option<u32>,
+  slot_max_speed: Option<f32>,
+  slot_max_width: Option<u32>,
+) -> Option<PcieLinkStatus> {
+  if current_speed.is_none() && current_width.is_none() {
+    return None;
+  }
+
+  let is_lower = |current: Option<f32>, max: Option<f32>| matches!((current, max), (Some(current), Some(max)) if current < max);
+  let width = |width: Option<u32>| width.map(|width| width as f32);
+
+  Some(PcieLinkStatus {
+    current_generation: current_speed.and_then(pcie_generation),
+    max_generation: max_speed.and_then(pcie_generation),
+    current_width,
+    max_width,
+    slot_max_generation: slot_max_speed.and_then(pcie_generation),
+    slot_max_width,
+    is_slot_limited: is_lower(slot_max_speed, max_speed)
+      || is_lower(width(slot_max_width), width(max_width)),
+    is_width_degraded: is_lower(width(current_width), width(max_width)),
+    is_speed_degraded: is_lower(current_speed, max_speed),
+  })
+}
+
+///
+/// Convert a link speed in GT/s to its PCIe generation
+///
+pub fn pcie_generation(speed_gts: f32) -> Option<u32> {
+  match speed_gts {
+    s if s >= 64.0 => Some(6),
+    s if s >= 32.0 => Some(5),
+    s if s >= 16.0 => Some(4),
+    s if s >= 8.0 => Some(3),
+    s if s >= 5.0 => Some(2),
+    s if s >= 2.5 => Some(1),
+    _ => None,
+  }
+}
+
+/// Climb from `dir` to the upstream port of an in-device PCIe switch, if any
+fn find_physical_link_dir(dir: &Path) -> PathBuf {
+  let vendor = read_id(dir, "vendor");
+  let mut link_dir = dir.to_path_buf();
+
+  while let Some(parent) = link_dir.parent() {
+    let is_device_bridge = vendor.is_some()
+      && read_id(parent, "vendor") == vendor
+      && read_id(parent, "class").is_some_and(|class| class.starts_with("0604"));
+    // Root ports sit directly under the host bridge (`pci0000:00`)
+    let is_root_port = parent
+      .parent()
+      .is_none_or(|grandparent| read_id(grandparent, "vendor").is_none());
+
+    if !is_device_bridge || is_root_port {
+      break;
+    }
+    link_dir = parent.to_path_buf();
+  }
+
+  link_dir
+}
+
+///
+/// Human readable GPU name from `pci.ids`
+///
+/// Prefers the marketing name in brackets (e.g. `Navi 21 [Radeon RX 6800 XT]`
+/// -> `Radeon RX 6800 XT`).
+///
+pub fn gpu_display_name(device: &PciDevice) -> Option<String> {
+  let name = device.device_name.as_deref()?;
+
+  let marketing = name
+    .rsplit_once('[')
+    .and_then(|(_, rest)| rest.strip_suffix(']'))
+    .filter(|marketing| !marketing.is_empty());
+
+  Some(marketing.unwrap_or(name).to_string())
+}
+
+///
+/// Parse a link speed attribute (e.g. `16.0 GT/s PCIe`, `8 GT/s`) into GT/s
+///
+/// Returns `None` for `Unknown`.
+///
+pub fn parse_link_speed(speed: &str) -> Option<f32> {
+  speed
+    .split_whitespace()
+    .next()?
+    .parse::<f32>()
+    .ok()
+    .filter(|speed| *speed > 0.0)
+}
+
+/// Read a hex ID attribute (`0x10de`) as `10de`
+fn read_id(dir: &Path, name: &str) -> Option<String> {
+  let value = sysfs::read_attribute(dir, name)?;
+  Some(value.trim_start_matches("0x").to_lowercase())
+}
+
+fn read_link_width(dir: &Path, name: &str) -> Option<u32> {
+  sysfs::read_attribute(dir, name)?
+    .parse::<u32>()
+    .ok()
+    .filter(|width| *width > 0)
+}
@@ -0,0 +1,139 @@
+use crate::infrastructure::providers::sysfs;
+use crate::models::hardware::{BatteryInfo, PowerSupplyInfo};
+use crate::utils::rounding::round1;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+///
+/// Get AC adapter and battery status from `/sys/class/power_supply`
+///
+pub fn get_power_supply_info() -> PowerSupplyInfo {
+  read_power_supply_info(Path::new("/sys/class/power_supply"))
+}
+
+///
+/// Read every power supply under `root` (`/sys/class/power_supply`)
+///
+/// Batteries of peripherals (`scope` = `Device`, e.g. wireless mice) are skipped.
+///
+pub fn read_power_supply_info(root: &Path) -> PowerSupplyInfo {
+  let mut ac_online = None;
+  let mut batteries = Vec::new();
+
+  for dir in get_supply_dirs(root) {
+    if sysfs::read_attribute(&dir, "scope").as_deref() == Some("Device") {
+      continue;
+    }
+
+    match sysfs::read_attribute(&dir, "type").as_deref() {
+      Some("Mains") | Some("USB") => {
+        if let Some(online) = read_number(&dir, "online") {
+          ac_online = Some(ac_online.unwrap_or(false) || online == 1.0);
+        }
+      }
+      Some("Battery") => {
+        let name = dir
+          .file_name()
+          .map(|name| name.to_string_lossy().into_owned())
+          .unwrap_or_default();
+        batteries.push(read_battery(&dir, &name));
+      }
+      _ => {}
+    }
+  }
+
+  PowerSupplyInfo {
+    ac_online,
+    batteries,
+  }
+}
+
+///
+/// Read a battery directory (e.g. `/sys/class/power_supply/BAT0`)
+///
+/// Drivers report either energy (`energy_*`, µWh) or charge (`charge_*`, µAh).
+/// Charge values are converted to Wh with the design voltage.
+///
+pub fn read_battery(dir: &Path, name: &str) -> BatteryInfo {
+  let status =
+    sysfs::read_attribute(dir, "status").unwrap_or_else(|| "Unknown".to_string());
+
+  // µV -> V
+  let voltage = read_number(dir, "voltage_now").map(|v| v / 1_000_000.0);
+  let design_voltage = read_number(dir, "voltage_min_design")
+    .map(|v| v / 1_000_000.0)
+    .or(voltage);
+
+  // Energy in Wh, falling back to charge (Ah) * design voltage
+  let energy = |key: &str| {
+    read_number(dir, &format!("energy_{key}"))
+      .map(|uwh| uwh / 1_000_000.0)
+      .or_else(|| {
+        let ah = read_number(dir, &format!("charge_{key}"))? / 1_000_000.0;
+        Some(ah * design_voltage?)
+      })
+  };
+  let energy_now = energy("now");
+  let energy_full = energy("full");
+  let energy_full_design = energy("full_design");
+
+  // W, falling back to current (A) * voltage (V)
+  let power = read_number(dir, "power_now")
+    .map(|uw| uw / 1_000_000.0)
+    .or_else(|| Some(read_number(dir, "current_now")? / 1_000_000.0 * voltage?))
+    .map(f32::abs);
+
+  let percentage = read_number(dir, "capacity").or_else(|| {
+    let full = energy_full.filter(|full| *full > 0.0)?;
+    Some((energy_now? / full * 100.0).min(100.0))
+  });
+
+  let health = match (energy_full, energy_full_design) {
+    (Some(full), Some(design)) if design > 0.0 => Some(round1(full / design * 100.0)),
+    _ => None,
+  };
+
+  let is_discharging = status == "Discharging";
+  let power_watts = power.map(|watts| {
+    // Positive while charging, negative while discharging
+    round1(if is_discharging { -watts } else { watts })
+  });
+
+  let time_to_empty_minutes = if is_discharging {
+    read_number(dir, "time_to_empty_now")
+      .map(|secs| secs / 60.0)
+      .or_else(|| {
+        let watts = power.filter(|watts| *watts > 0.0)?;
+        Some(energy_now? / watts * 60.0)
+      })
+      .map(f32::round)
+  } else {
+    None
+  };
+
+  BatteryInfo {
+    name: name.to_string(),
+    status,
+    percentage: percentage.map(round1),
+    power_watts,
+    voltage: voltage.map(|v| (v * 100.0).round() / 100.0),
+    cycle_count: read_number(dir, "cycle_count").map(|count| count as u32),
+    energy_full_wh: energy_full.map(round1),
+    energy_full_design_wh: energy_full_design.map(round1),
+    health,
+    time_to_empty_minutes,
+  }
+}
+
+fn get_supply_dirs(root: &Path) -> Vec<PathBuf> {
+  let mut dirs: Vec<PathBuf> = fs::read_dir(root)
+    .map(|entries| entries.flatten().map(|entry| entry.path()).collect())
+    .unwrap_or_default();
+
+  dirs.sort();
+  dirs
+}
+
+fn read_number(dir: &Path, name: &str) -> Option<f32> {
+  sysfs::read_attribute(dir, name)?.parse::<f32>().ok()
+}
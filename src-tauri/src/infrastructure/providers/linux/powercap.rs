@@ -0,0 +1,95 @@
+use crate::models::hardware::RaplCounters;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+///
+/// Get RAPL energy counters of every zone from `/sys/class/powercap`
+///
+/// `energy_uj` is only readable by root since Linux 5.10, so an error describing
+/// the missing permission is returned when running unprivileged.
+///
+pub fn get_rapl_counters() -> Result<HashMap<String, RaplCounters>, String> {
+  read_rapl_counters(Path::new("/sys/class/powercap"))
+}
+
+///
+/// Read RAPL zones (`intel-rapl:N`) and sub-zones (`intel-rapl:N:M`) under `root`
+///
+/// Keys are the zone ids (e.g. `intel-rapl:0:1`). AMD CPUs expose the same interface.
+/// A missing `root` yields no zones; an `energy_uj` that exists but cannot be read
+/// for lack of permission is an error.
+///
+pub fn read_rapl_counters(root: &Path) -> Result<HashMap<String, RaplCounters>, String> {
+  let Ok(entries) = fs::read_dir(root) else {
+    return Ok(HashMap::new());
+  };
+
+  let mut counters = HashMap::new();
+  for entry in entries.flatten() {
+    let Some(zone) = entry.file_name().to_str().map(str::to_string) else {
+      continue;
+    };
+    if !zone.starts_with("intel-rapl:") {
+      continue;
+    }
+
+    let path = entry.path();
+    let read = |name: &str| fs::read_to_string(path.join(name)).ok();
+
+    let energy_uj = match fs::read_to_string(path.join("energy_uj")) {
+      Ok(value) => value,
+      Err(e) if e.kind() == std::io::ErrorKind::PermissionDenied => {
+        return Err(format!(
+          "{} is only readable by root since Linux 5.10",
+          path.join("energy_uj").display()
+        ));
+      }
+      Err(_) => continue,
+    };
+
+    let (Some(name), Some(energy_uj), Some(max_energy_range_uj)) = (
+      read("name"),
+      energy_uj.trim().parse().ok(),
+      read("max_energy_range_uj").and_then(|value| value.trim().parse().ok()),
+    ) else {
+      continue;
+    };
+
+    counters.insert(
+      zone,
+      RaplCounters {
+        name: name.trim().to_string(),
+        energy_uj,
+        max_energy_range_uj,
+      },
+    );
+  }
+
+  Ok(counters)
+}
+
+///
+/// Energy consumed between two `energy_uj` readings
+///
+/// The counter counts up to `max_energy_range_uj` and then wraps to 0.
+/// Returns `None` when `prev` is outside that range (corrupt or bogus range),
+/// since the delta is unknown.
+///
+pub fn energy_delta_uj(prev: u64, curr: u64, max_energy_range_uj: u64) -> Option<u64> {
+  if curr >= prev {
+    Some(curr - prev)
+  } else if prev <= max_energy_range_uj {
+    Some(max_energy_range_uj - prev + curr + 1)
+  } else {
+    None
+  }
+}
+
+/// Whether the zone is a CPU package (`package-N`)
+///
+/// Sub-zones (core / uncore / dram) and `psys` overlap with packages and must not
+/// be summed with them.
+pub fn is_package_zone(name: &str) -> bool {
+  name.starts_with("package-")
+}
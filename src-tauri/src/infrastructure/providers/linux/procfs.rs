@@ -1,3 +1,9 @@
+use crate::models::hardware::{
+  CpuTimes, DiskCounters, LoadAverage, MemoryBreakdown, MountEntry, PressureResource,
+  PressureStall,
+};
+use std::collections::HashMap;
+
 pub fn get_mem_total_kb() -> std::io::Result<u64> {
   use std::fs;
   use std::io;
@@ -19,3 +25,297 @@ pub fn get_mem_total_kb() -> std::io::Result<u64> {
     "MemTotal entry not found",
   ))
 }
+
+///
+/// Get memory breakdown from `/proc/meminfo`
+///
+pub fn get_memory_breakdown() -> std::io::Result<MemoryBreakdown> {
+  let content = std::fs::read_to_string("/proc/meminfo")?;
+  parse_memory_breakdown(&content).ok_or_else(|| {
+    std::io::Error::new(std::io::ErrorKind::NotFound, "MemTotal entry not found")
+  })
+}
+
+///
+/// Parse `/proc/meminfo` into `MemoryBreakdown`
+///
+/// Returns `None` when `MemTotal` is missing. Other entries default to 0
+/// since availability depends on the kernel version and config.
+///
+pub fn parse_memory_breakdown(content: &str) -> Option<MemoryBreakdown> {
+  let entries = parse_meminfo(content);
+  let kb = |key: &str| entries.get(key).copied().unwrap_or(0);
+  let mib = |key: &str| crate::utils::rounding::round1(kb(key) as f32 / 1024.0);
+
+  entries.get("MemTotal")?;
+
+  Some(MemoryBreakdown {
+    total: mib("MemTotal"),
+    free: mib("MemFree"),
+    available: mib("MemAvailable"),
+    buffers: mib("Buffers"),
+    cached: mib("Cached"),
+    swap_cached: mib("SwapCached"),
+    dirty: mib("Dirty"),
+    writeback: mib("Writeback"),
+    slab: mib("Slab"),
+    slab_reclaimable: mib("SReclaimable"),
+    shmem: mib("Shmem"),
+    swap_total: mib("SwapTotal"),
+    swap_free: mib("SwapFree"),
+    committed: mib("Committed_AS"),
+    commit_limit: mib("CommitLimit"),
+    hugepages_total: kb("HugePages_Total") as u32,
+    hugepages_free: kb("HugePages_Free") as u32,
+    hugepage_size: mib("Hugepagesize"),
+  })
+}
+
+///
+/// Parse `/proc/meminfo` into `key -> value` (kB, or a count for `HugePages_*`)
+///
+pub fn parse_meminfo(content: &str) -> HashMap<String, u64> {
+  content
+    .lines()
+    .filter_map(|line| {
+      let (key, value) = line.split_once(':')?;
+      let value = value.split_whitespace().next()?.parse::<u64>().ok()?;
+      Some((key.trim().to_string(), value))
+    })
+    .collect()
+}
+
+/// Sector size used by `/proc/diskstats`, regardless of the device's physical sector size
+pub const DISKSTATS_SECTOR_SIZE: u64 = 512;
+
+///
+/// Get cumulative I/O counters of every block device from `/proc/diskstats`
+///
+pub fn get_disk_counters() -> std::io::Result<HashMap<String, DiskCounters>> {
+  let content = std::fs::read_to_string("/proc/diskstats")?;
+  Ok(parse_diskstats(&content))
+}
+
+///
+/// Parse `/proc/diskstats`
+///
+/// Virtual devices without a backing store (`loop*`, `ram*`, `zram*`) are skipped.
+///
+pub fn parse_diskstats(content: &str) -> HashMap<String, DiskCounters> {
+  content
+    .lines()
+    .filter_map(|line| {
+      let fields: Vec<&str> = line.split_whitespace().collect();
+      // major minor name + at least 11 stat fields
+      if fields.len() < 14 {
+        return None;
+      }
+
+      let name = fields[2];
+      if ["loop", "ram", "zram"]
+        .iter()
+        .any(|prefix| name.starts_with(prefix))
+      {
+        return None;
+      }
+
+      let field = |i: usize| fields[i].parse::<u64>().ok();
+
+      Some((
+        name.to_string(),
+        DiskCounters {
+          reads_completed: field(3)?,
+          sectors_read: field(5)?,
+          read_time_ms: field(6)?,
+          writes_completed: field(7)?,
+          sectors_written: field(9)?,
+          write_time_ms: field(10)?,
+          io_time_ms: field(12)?,
+        },
+      ))
+    })
+    .collect()
+}
+
+///
+/// Get cumulative CPU time counters from `/proc/stat`
+///
+/// Keys are `cpu` (all cores) and `cpuN` (each core)
+///
+pub fn get_cpu_times() -> std::io::Result<HashMap<String, CpuTimes>> {
+  let content = std::fs::read_to_string("/proc/stat")?;
+  Ok(parse_cpu_times(&content))
+}
+
+///
+/// Parse the `cpu*` lines of `/proc/stat`
+///
+/// Columns added by newer kernels (`iowait`, `irq`, `softirq`, `steal`) default to 0.
+///
+pub fn parse_cpu_times(content: &str) -> HashMap<String, CpuTimes> {
+  content
+    .lines()
+    .filter(|line| line.starts_with("cpu"))
+    .filter_map(|line| {
+      let mut fields = line.split_whitespace();
+      let name = fields.next()?;
+      let values = fields
+        .map(|field| field.parse::<u64>().ok())
+        .collect::<Option<Vec<u64>>>()?;
+      // user nice system idle are always present
+      if values.len() < 4 {
+        return None;
+      }
+
+      let value = |i: usize| values.get(i).copied().unwrap_or(0);
+
+      Some((
+        name.to_string(),
+        CpuTimes {
+          user: value(0),
+          nice: value(1),
+          system: value(2),
+          idle: value(3),
+          iowait: value(4),
+          irq: value(5),
+          softirq: value(6),
+          steal: value(7),
+        },
+      ))
+    })
+    .collect()
+}
+
+///
+/// Get Pressure Stall Information of `resource` (`cpu` / `memory` / `io`)
+///
+/// Returns `None` when PSI is not available on this kernel
+///
+pub fn get_pressure(resource: &str) -> Option<PressureResource> {
+  let content = std::fs::read_to_string(format!("/proc/pressure/{resource}")).ok()?;
+  parse_pressure(&content)
+}
+
+///
+/// Parse `/proc/pressure/*`
+///
+/// e.g. `some avg10=0.12 avg60=0.05 avg300=0.01 total=123456`
+///
+pub fn parse_pressure(content: &str) -> Option<PressureResource> {
+  let mut some = None;
+  let mut full = None;
+
+  for line in content.lines() {
+    let mut fields = line.split_whitespace();
+    match fields.next() {
+      Some("some") => some = parse_pressure_stall(fields),
+      Some("full") => full = parse_pressure_stall(fields),
+      _ => {}
+    }
+  }
+
+  Some(PressureResource { some: some?, full })
+}
+
+fn parse_pressure_stall<'a>(
+  fields: impl Iterator<Item = &'a str>,
+) -> Option<PressureStall> {
+  let mut stall = PressureStall::default();
+
+  for field in fields {
+    let (key, value) = field.split_once('=')?;
+    match key {
+      "avg10" => stall.avg10 = value.parse().ok()?,
+      "avg60" => stall.avg60 = value.parse().ok()?,
+      "avg300" => stall.avg300 = value.parse().ok()?,
+      // microseconds
+      "total" => stall.total_ms = value.parse::<u64>().ok()? as f64 / 1000.0,
+      _ => {}
+    }
+  }
+
+  Some(stall)
+}
+
+///
+/// Get load average from `/proc/loadavg`
+///
+pub fn get_load_average() -> std::io::Result<LoadAverage> {
+  let content = std::fs::read_to_string("/proc/loadavg")?;
+  parse_loadavg(&content).ok_or_else(|| {
+    std::io::Error::new(std::io::ErrorKind::InvalidData, "Invalid /proc/loadavg")
+  })
+}
+
+///
+/// Parse `/proc/loadavg`
+///
+/// e.g. `0.52 0.58 0.59 2/1024 12345`
+///
+pub fn parse_loadavg(content: &str) -> Option<LoadAverage> {
+  let mut fields = content.split_whitespace();
+  let one = fields.next()?.parse().ok()?;
+  let five = fields.next()?.parse().ok()?;
+  let fifteen = fields.next()?.parse().ok()?;
+  let (running, total) = fields.next()?.split_once('/')?;
+
+  Some(LoadAverage {
+    one,
+    five,
+    fifteen,
+    running: running.parse().ok()?,
+    total: total.parse().ok()?,
+  })
+}
+
+///
+/// Get mounted file systems from `/proc/mounts`
+///
+pub fn get_mounts() -> std::io::Result<Vec<MountEntry>> {
+  let content = std::fs::read_to_string("/proc/mounts")?;
+  Ok(parse_mounts(&content))
+}
+
+///
+/// Parse `/proc/mounts`
+///
+/// e.g. `/dev/mapper/vg0-root / ext4 rw,relatime 0 0`
+///
+/// Spaces and tabs in paths are escaped as octal (`\040`).
+///
+pub fn parse_mounts(content: &str) -> Vec<MountEntry> {
+  content
+    .lines()
+    .filter_map(|line| {
+      let mut fields = line.split_whitespace();
+      Some(MountEntry {
+        source: unescape_mount_field(fields.next()?),
+        mount_point: unescape_mount_field(fields.next()?),
+        file_system: fields.next()?.to_string(),
+      })
+    })
+    .collect()
+}
+
+fn unescape_mount_field(field: &str) -> String {
+  let mut result = String::with_capacity(field.len());
+  let mut rest = field;
+
+  while let Some(index) = rest.find('\\') {
+    result.push_str(&rest[..index]);
+    let escaped = rest.get(index + 1..index + 4);
+    match escaped.and_then(|octal| u8::from_str_radix(octal, 8).ok()) {
+      Some(byte) => {
+        result.push(byte as char);
+        rest = &rest[index + 4..];
+      }
+      None => {
+        result.push('\\');
+        rest = &rest[index + 1..];
+      }
+    }
+  }
+  result.push_str(rest);
+
+  result
+}
@@ -0,0 +1,165 @@
+use crate::enums::hardware::DiskHealthStatus;
+use crate::models::hardware::DiskHealth;
+use serde_json::Value;
+
+/// `Percentage Used` from which the drive is reported as wearing out
+const PERCENTAGE_USED_WARNING: u32 = 90;
+
+/// ATA SMART attribute ids
+const ATTRIBUTE_REALLOCATED_SECTORS: u64 = 5;
+const ATTRIBUTE_TEMPERATURE: u64 = 194;
+const ATTRIBUTE_PENDING_SECTORS: u64 = 197;
+const ATTRIBUTE_UNCORRECTABLE_SECTORS: u64 = 198;
+
+///
+/// Run `smartctl --json -a -n standby` on a disk
+///
+/// SMART commands need root (or `CAP_SYS_RAWIO`), so this fails when running
+/// unprivileged. Disks in standby are not spun up and fail as well.
+///
+pub fn get_smartctl_json(disk: &str) -> Result<String, String> {
+  let output = std::process::Command::new("smartctl")
+    .arg("--json")
+    .arg("-a")
+    .args(["-n", "standby"])
+    .arg(format!("/dev/{disk}"))
+    .output()
+    .map_err(|e| format!("Failed to execute smartctl: {e}"))?;
+
+  // Exit status is a bit mask: bits 0-1 mean the device could not be queried,
+  // the others report disk conditions while the JSON is still complete
+  match output.status.code() {
+    Some(code) if code & 0b11 == 0 => {
+      Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    }
+    _ => Err(format!(
+      "smartctl could not read /dev/{disk}: {}",
+      String::from_utf8_lossy(&output.stderr).trim()
+    )),
+  }
+}
+
+///
+/// Parse `smartctl --json -a` output of an ATA or NVMe disk
+///
+/// `mount_points` and `status` are left for the caller.
+///
+pub fn parse_smartctl_json(disk: &str, content: &str) -> Result<DiskHealth, String> {
+  let json: Value = serde_json::from_str(content)
+    .map_err(|e| format!("Failed to parse smartctl output: {e}"))?;
+
+  let string = |key: &str| {
+    json
+      .get(key)
+      .and_then(Value::as_str)
+      .map(str::trim)
+      .filter(|value| !value.is_empty())
+      .map(str::to_string)
+  };
+  let nvme_log = json.get("nvme_smart_health_information_log");
+  let nvme = |key: &str| nvme_log.and_then(|log| log.get(key)).and_then(as_u32);
+
+  Ok(DiskHealth {
+    device: disk.to_string(),
+    model: string("model_name"),
+    serial: string("serial_number"),
+    firmware: string("firmware_version"),
+    mount_points: Vec::new(),
+    smart_passed: json
+      .pointer("/smart_status/passed")
+      .and_then(Value::as_bool),
+    power_on_hours: json
+      .pointer("/power_on_time/hours")
+      .and_then(as_u32)
+      .or_else(|| nvme("power_on_hours")),
+    temperature: json
+      .pointer("/temperature/current")
+      .and_then(Value::as_f64)
+      .map(|celsius| celsius as f32)
+      .or_else(|| ata_temperature(&json)),
+    reallocated_sectors: ata_attribute_raw(&json, ATTRIBUTE_REALLOCATED_SECTORS),
+    pending_sectors: ata_attribute_raw(&json, ATTRIBUTE_PENDING_SECTORS),
+    uncorrectable_sectors: ata_attribute_raw(&json, ATTRIBUTE_UNCORRECTABLE_SECTORS),
+    percentage_used: nvme("percentage_used"),
+    media_errors: nvme("media_errors"),
+    critical_warning: nvme("critical_warning"),
+    status: DiskHealthStatus::Unknown,
+  })
+}
+
+///
+/// Raw value of an ATA SMART attribute (`ata_smart_attributes.table[]`)
+///
+/// Vendors pack extra fields into the upper bytes of the 48-bit `raw.value`, so the
+/// leading number of `raw.string` (decoded by smartctl's drive database) is used.
+///
+fn ata_attribute_raw(json: &Value, id: u64) -> Option<u32> {
+  ata_attribute(json, id)?
+    .pointer("/raw/string")?
+    .as_str()?
+    .split_whitespace()
+    .next()?
+    .parse()
+    .ok()
+}
+
+///
+/// Temperature from ATA attribute 194 when smartctl reports no `temperature`
+///
+/// Only the low byte of the raw value is the current temperature, the others
+/// hold min / max on many drives.
+///
+fn ata_temperature(json: &Value) -> Option<f32> {
+  let raw = ata_attribute(json, ATTRIBUTE_TEMPERATURE)?
+    .pointer("/raw/value")?
+    .as_u64()?;
+
+  Some((raw & 0xff) as f32).filter(|celsius| *celsius > 0.0)
+}
+
+fn ata_attribute(json: &Value, id: u64) -> Option<&Value> {
+  json
+    .pointer("/ata_smart_attributes/table")?
+    .as_array()?
+    .iter()
+    .find(|attribute| attribute.get("id").and_then(Value::as_u64) == Some(id))
+}
+
+fn as_u32(value: &Value) -> Option<u32> {
+  value
+    .as_u64()
+    .map(|value| value.min(u32::MAX as u64) as u32)
+}
+
+///
+/// Health verdict of a disk
+///
+/// - Critical: SMART failed, any NVMe critical warning, or rated endurance used up
+/// - Warning: reallocated / pending / uncorrectable sectors, media errors, or endurance
+///   almost used up
+/// - Good: SMART passed with none of the above
+///
+pub fn health_status(health: &DiskHealth) -> DiskHealthStatus {
+  let percentage_used = health.percentage_used.unwrap_or(0);
+
+  if health.smart_passed == Some(false)
+    || health.critical_warning.unwrap_or(0) > 0
+    || percentage_used >= 100
+  {
+    return DiskHealthStatus::Critical;
+  }
+
+  if health.reallocated_sectors.unwrap_or(0) > 0
+    || health.pending_sectors.unwrap_or(0) > 0
+    || health.uncorrectable_sectors.unwrap_or(0) > 0
+    || health.media_errors.unwrap_or(0) > 0
+    || percentage_used >= PERCENTAGE_USED_WARNING
+  {
+    return DiskHealthStatus::Warning;
+  }
+
+  match health.smart_passed {
+    Some(true) => DiskHealthStatus::Good,
+    _ => DiskHealthStatus::Unknown,
+  }
+}
@@ -0,0 +1,378 @@
+use crate::infrastructure::providers::linux::dmi_sys;
+use crate::models::hardware::{BoardInfo, MemoryInfo, MemoryModule};
+use crate::utils;
+use std::fs;
+use std::path::Path;
+
+/// SMBIOS structure types
+const TYPE_BIOS: u8 = 0;
+const TYPE_SYSTEM: u8 = 1;
+const TYPE_BASEBOARD: u8 = 2;
+const TYPE_CHASSIS: u8 = 3;
+const TYPE_MEMORY_ARRAY: u8 = 16;
+const TYPE_MEMORY_DEVICE: u8 = 17;
+const TYPE_END_OF_TABLE: u8 = 127;
+
+/// Physical Memory Array `Use` value for system memory
+const MEMORY_ARRAY_USE_SYSTEM: u8 = 0x03;
+
+///
+/// SMBIOS entry point (`_SM_` 32-bit or `_SM3_` 64-bit)
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct SmbiosEntryPoint {
+  pub major: u8,
+  pub minor: u8,
+  /// Structure table length (maximum size for `_SM3_`)
+  pub table_length: u32,
+}
+
+///
+/// A single structure of the SMBIOS table
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct SmbiosStructure {
+  pub kind: u8,
+  pub handle: u16,
+  /// Formatted area including the 4-byte header
+  pub data: Vec<u8>,
+  /// Strings section (referenced 1-based from the formatted area)
+  pub strings: Vec<String>,
+}
+
+impl SmbiosStructure {
+  fn byte(&self, offset: usize) -> Option<u8> {
+    self.data.get(offset).copied()
+  }
+
+  fn word(&self, offset: usize) -> Option<u16> {
+    let bytes = self.data.get(offset..offset + 2)?;
+    Some(u16::from_le_bytes([bytes[0], bytes[1]]))
+  }
+
+  fn dword(&self, offset: usize) -> Option<u32> {
+    let bytes = self.data.get(offset..offset + 4)?;
+    Some(u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]]))
+  }
+
+  /// String referenced by the byte at `offset`, without placeholders
+  fn string(&self, offset: usize) -> Option<String> {
+    let index = self.byte(offset)? as usize;
+    let value = self.strings.get(index.checked_sub(1)?)?.trim();
+
+    if value.is_empty() || dmi_sys::is_placeholder(value) {
+      return None;
+    }
+    Some(value.to_string())
+  }
+
+  /// [`Self::string`] that also drops generic words (`Unknown`, `None`, ...)
+  fn known_string(&self, offset: usize) -> Option<String> {
+    self
+      .string(offset)
+      .filter(|value| !dmi_sys::is_generic_value(value))
+  }
+}
+
+///
+/// Read SMBIOS structures from `/sys/firmware/dmi/tables`
+///
+/// Both files are root-only, so this fails when running unprivileged.
+///
+pub fn get_smbios_structures() -> Result<Vec<SmbiosStructure>, String> {
+  read_smbios_structures(Path::new("/sys/firmware/dmi/tables"))
+}
+
+///
+/// Read `smbios_entry_point` and `DMI` under `tables_dir`
+///
+pub fn read_smbios_structures(tables_dir: &Path) -> Result<Vec<SmbiosStructure>, String> {
+  let entry_point = fs::read(tables_dir.join("smbios_entry_point"))
+    .map_err(|e| format!("Failed to read smbios_entry_point: {e}"))?;
+  let entry_point = parse_entry_point(&entry_point)
+    .ok_or_else(|| "Invalid SMBIOS entry point".to_string())?;
+
+  let table = fs::read(tables_dir.join("DMI"))
+    .map_err(|e| format!("Failed to read DMI table: {e}"))?;
+  let length = (entry_point.table_length as usize).min(table.len());
+
+  Ok(parse_structures(&table[..length]))
+}
+
+///
+/// Parse an SMBIOS 2.x (`_SM_`) or 3.x (`_SM3_`) entry point
+///
+pub fn parse_entry_point(bytes: &[u8]) -> Option<SmbiosEntryPoint> {
+  if bytes.starts_with(b"_SM3_") {
+    let table_length = bytes.get(0x0c..0x10)?;
+    return Some(SmbiosEntryPoint {
+      major: *bytes.get(0x07)?,
+      minor: *bytes.get(0x08)?,
+      table_length: u32::from_le_bytes(table_length.try_into().ok()?),
+    });
+  }
+
+  if bytes.starts_with(b"_SM_") {
+    let table_length = bytes.get(0x16..0x18)?;
+    return Some(SmbiosEntryPoint {
+      major: *bytes.get(0x06)?,
+      minor: *bytes.get(0x07)?,
+      table_length: u16::from_le_bytes(table_length.try_into().ok()?) as u32,
+    });
+  }
+
+  None
+}
+
+///
+/// Split a raw structure table into structures
+///
+/// Parsing stops at the End-of-Table structure (type 127) or at the first
+/// malformed structure.
+///
+pub fn parse_structures(table: &[u8]) -> Vec<SmbiosStructure> {
+  let mut structures = Vec::new();
+  let mut offset = 0;
+
+  while offset + 4 <= table.len() {
+    let kind = table[offset];
+    let length = table[offset + 1] as usize;
+    let handle = u16::from_le_bytes([table[offset + 2], table[offset + 3]]);
+
+    if length < 4 || offset + length > table.len() {
+      break;
+    }
+    let data = table[offset..offset + length].to_vec();
+
+    // Strings section ends with a double NUL
+    let strings_start = offset + length;
+    let Some(strings_len) = table[strings_start..]
+      .windows(2)
+      .position(|window| window == [0, 0])
+    else {
+      break;
+    };
+    let strings = table[strings_start..strings_start + strings_len]
+      .split(|byte| *byte == 0)
+      .filter(|string| !string.is_empty())
+      .map(|string| String::from_utf8_lossy(string).into_owned())
+      .collect();
+
+    structures.push(SmbiosStructure {
+      kind,
+      handle,
+      data,
+      strings,
+    });
+
+    if kind == TYPE_END_OF_TABLE {
+      break;
+    }
+    offset = strings_start + strings_len + 2;
+  }
+
+  structures
+}
+
+///
+/// Build memory information from Physical Memory Array (16) and Memory Device (17)
+///
+/// Devices of arrays not used as system memory (e.g. flash) are skipped.
+/// Returns `None` when the table has no memory devices.
+///
+pub fn parse_memory_info(structures: &[SmbiosStructure]) -> Option<MemoryInfo> {
+  let system_arrays: Vec<u16> = structures
+    .iter()
+    .filter(|s| s.kind == TYPE_MEMORY_ARRAY)
+    .filter(|s| s.byte(0x05) == Some(MEMORY_ARRAY_USE_SYSTEM))
+    .map(|s| s.handle)
+    .collect();
+
+  let modules: Vec<MemoryModule> = structures
+    .iter()
+    .filter(|s| s.kind == TYPE_MEMORY_DEVICE)
+    .filter(|s| {
+      system_arrays.is_empty()
+        || s
+          .word(0x04)
+          .is_some_and(|array| system_arrays.contains(&array))
+    })
+    .map(parse_memory_device)
+    .collect();
+
+  if modules.is_empty() {
+    return None;
+  }
+
+  let installed: Vec<&MemoryModule> =
+    modules.iter().filter(|module| !module.is_empty).collect();
+  let total_bytes: u64 = installed
+    .iter()
+    .filter_map(|module| module.size_mb)
+    .map(|mb| mb as u64 * 1024 * 1024)
+    .sum();
+  let size_with_unit = utils::formatter::format_size_with_unit(total_bytes, 1, None);
+
+  Some(MemoryInfo {
+    is_detailed: true,
+    size: format!(
+      "{value:.1} {unit}",
+      value = size_with_unit.value,
+      unit = size_with_unit.unit
+    ),
+    // DDR memory: MT/s => MHz
+    clock: installed
+      .iter()
+      .find_map(|module| module.configured_speed_mts)
+      .unwrap_or(0)
+      / 2,
+    clock_unit: "MHz".into(),
+    memory_count: installed.len() as u32,
+    total_slots: modules.len() as u32,
+    memory_type: installed
+      .iter()
+      .find_map(|module| module.memory_type.clone())
+      .unwrap_or_else(|| "Unknown".to_string()),
+    modules,
+  })
+}
+
+///
+/// Decode a Memory Device (type 17) structure
+///
+/// Fields added in later SMBIOS versions are read only when the structure is
+/// long enough to contain them.
+///
+fn parse_memory_device(device: &SmbiosStructure) -> MemoryModule {
+  let locator = device.string(0x10).unwrap_or_default();
+  let bank_locator = device.string(0x11);
+
+  let Some(size_mb) = parse_device_size_mb(device) else {
+    return MemoryModule {
+      locator,
+      bank_locator,
+      is_empty: true,
+      ..Default::default()
+    };
+  };
+
+  // 0xFFFF: the value is in the 3.3+ extended field
+  let speed = |offset: usize, extended: usize| match device.word(offset)? {
+    0 => None,
+    0xffff => device.dword(extended).filter(|speed| *speed > 0),
+    speed => Some(speed as u32),
+  };
+
+  MemoryModule {
+    locator,
+    bank_locator,
+    is_empty: false,
+    size_mb: Some(size_mb),
+    memory_type: device
+      .byte(0x12)
+      .and_then(memory_type_name)
+      .map(str::to_string),
+    rated_speed_mts: speed(0x15, 0x54),
+    configured_speed_mts: speed(0x20, 0x58),
+    manufacturer: device.known_string(0x17),
+    part_number: device.known_string(0x1a),
+    serial_number: device.known_string(0x18),
+    rank: device
+      .byte(0x1b)
+      .map(|attributes| (attributes & 0x0f) as u32)
+      .filter(|rank| *rank > 0),
+  }
+}
+
+///
+/// Decode the `Size` field of a Memory Device into MB
+///
+/// - `0`: no module installed, `0xFFFF`: unknown
+/// - `0x7FFF`: size is in `Extended Size` (32 GB or more)
+/// - Bit 15 set: the value is in KB
+///
+fn parse_device_size_mb(device: &SmbiosStructure) -> Option<u32> {
+  let size = match device.word(0x0c)? {
+    0 | 0xffff => return None,
+    0x7fff => device.dword(0x1c)? & 0x7fff_ffff,
+    size if size & 0x8000 != 0 => (size & 0x7fff) as u32 / 1024,
+    size => size as u32,
+  };
+
+  (size > 0).then_some(size)
+}
+
+///
+/// Convert Memory Device `Memory Type` to its name
+///
+/// Returns `None` for `Other` / `Unknown` and the generic `RAM`.
+///
+pub fn memory_type_name(code: u8) -> Option<&'static str> {
+  let name = match code {
+    0x03 => "DRAM",
+    0x04 => "EDRAM",
+    0x05 => "VRAM",
+    0x06 => "SRAM",
+    0x08 => "ROM",
+    0x09 => "Flash",
+    0x0a => "EEPROM",
+    0x0b => "FEPROM",
+    0x0c => "EPROM",
+    0x0d => "CDRAM",
+    0x0e => "3DRAM",
+    0x0f => "SDRAM",
+    0x10 => "SGRAM",
+    0x11 => "RDRAM",
+    0x12 => "DDR",
+    0x13 => "DDR2",
+    0x14 => "DDR2 FB-DIMM",
+    0x18 => "DDR3",
+    0x19 => "FBD2",
+    0x1a => "DDR4",
+    0x1b => "LPDDR",
+    0x1c => "LPDDR2",
+    0x1d => "LPDDR3",
+    0x1e => "LPDDR4",
+    0x1f => "Logical non-volatile device",
+    0x20 => "HBM",
+    0x21 => "HBM2",
+    0x22 => "DDR5",
+    0x23 => "LPDDR5",
+    0x24 => "HBM3",
+    _ => return None,
+  };
+  Some(name)
+}
+
+///
+/// Build board information from BIOS (0), System (1), Baseboard (2) and Chassis (3)
+///
+/// Returns `None` when none of these structures carry a value.
+///
+pub fn parse_board_info(structures: &[SmbiosStructure]) -> Option<BoardInfo> {
+  let find = |kind: u8| structures.iter().find(|s| s.kind == kind);
+  let bios = find(TYPE_BIOS);
+  let system = find(TYPE_SYSTEM);
+  let board = find(TYPE_BASEBOARD);
+  let chassis = find(TYPE_CHASSIS);
+
+  let info = BoardInfo {
+    board_vendor: board.and_then(|s| s.string(0x04)),
+    board_name: board.and_then(|s| s.string(0x05)),
+    board_version: board.and_then(|s| s.string(0x06)),
+    bios_vendor: bios.and_then(|s| s.string(0x04)),
+    bios_version: bios.and_then(|s| s.string(0x05)),
+    bios_date: bios.and_then(|s| s.string(0x08)),
+    system_vendor: system.and_then(|s| s.string(0x04)),
+    product_name: system.and_then(|s| s.string(0x05)),
+    chassis_type: chassis
+      .and_then(|s| s.byte(0x05))
+      .map(|code| dmi_sys::chassis_type_name(code).to_string()),
+  };
+
+  if info == BoardInfo::default() {
+    return None;
+  }
+
+  Some(info)
+}
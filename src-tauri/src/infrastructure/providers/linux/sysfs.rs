@@ -0,0 +1,33 @@
+use std::fs;
+use std::path::Path;
+
+///
+/// Read a sysfs attribute `dir/name`, trimmed
+///
+/// Returns `None` when the file cannot be read or is empty.
+///
+pub fn read_attribute(dir: &Path, name: &str) -> Option<String> {
+  fs::read_to_string(dir.join(name))
+    .ok()
+    .map(|value| value.trim().to_string())
+    .filter(|value| !value.is_empty())
+}
+
+///
+/// Names of the entries of `dir`, sorted
+///
+/// Returns an empty list when the directory cannot be read.
+///
+pub fn list_dir(dir: &Path) -> Vec<String> {
+  let mut names: Vec<String> = fs::read_dir(dir)
+    .map(|entries| {
+      entries
+        .flatten()
+        .filter_map(|entry| entry.file_name().to_str().map(str::to_string))
+        .collect()
+    })
+    .unwrap_or_default();
+
+  names.sort();
+  names
+}
@@ -0,0 +1,89 @@
+use super::hwmon::{self, TemperatureSensor};
+use super::sysfs;
+use std::fs;
+use std::path::Path;
+
+/// hwmon drivers that report CPU package / core temperatures
+const CPU_HWMON_DRIVERS: [&str; 4] = ["coretemp", "k10temp", "zenpower", "cpu_thermal"];
+
+///
+/// Get CPU temperatures in Celsius
+///
+/// Prefers the CPU hwmon drivers (coretemp / k10temp package and per-core sensors)
+/// and falls back to CPU thermal zones when none of them is loaded.
+///
+pub fn get_cpu_temperatures() -> Vec<TemperatureSensor> {
+  let sensors = get_cpu_hwmon_temperatures(Path::new("/sys/class/hwmon"));
+  if !sensors.is_empty() {
+    return sensors;
+  }
+
+  get_cpu_thermal_zone_temperatures(Path::new("/sys/class/thermal"))
+}
+
+///
+/// Read temperatures of CPU hwmon drivers under `hwmon_root` (`/sys/class/hwmon`)
+///
+pub fn get_cpu_hwmon_temperatures(hwmon_root: &Path) -> Vec<TemperatureSensor> {
+  hwmon::get_hwmon_dirs(hwmon_root)
+    .into_iter()
+    .filter(|dir| {
+      sysfs::read_attribute(dir, "name")
+        .is_some_and(|name| CPU_HWMON_DRIVERS.contains(&name.as_str()))
+    })
+    .flat_map(|dir| hwmon::read_temperatures(&dir))
+    .collect()
+}
+
+///
+/// Read temperatures of CPU thermal zones under `thermal_root` (`/sys/class/thermal`)
+///
+pub fn get_cpu_thermal_zone_temperatures(thermal_root: &Path) -> Vec<TemperatureSensor> {
+  let mut zones: Vec<(u32, TemperatureSensor)> = fs::read_dir(thermal_root)
+    .map(|entries| {
+      entries
+        .flatten()
+        .filter_map(|entry| {
+          let index = entry
+            .file_name()
+            .to_str()?
+            .strip_prefix("thermal_zone")?
+            .parse::<u32>()
+            .ok()?;
+          let path = entry.path();
+
+          let zone_type = fs::read_to_string(path.join("type")).ok()?;
+          let zone_type = zone_type.trim();
+          if !is_cpu_thermal_zone(zone_type) {
+            return None;
+          }
+
+          let value =
+            hwmon::parse_millidegrees(&fs::read_to_string(path.join("temp")).ok()?)?;
+
+          Some((
+            index,
+            TemperatureSensor {
+              label: zone_type.to_string(),
+              value,
+            },
+          ))
+        })
+        .collect()
+    })
+    .unwrap_or_default();
+
+  zones.sort_by_key(|(index, _)| *index);
+  zones.into_iter().map(|(_, zone)| zone).collect()
+}
+
+///
+/// Whether the thermal zone type describes a CPU sensor
+///
+/// e.g. `x86_pkg_temp` (Intel), `cpu-thermal` / `cpu0-thermal` (ARM SoCs)
+///
+pub fn is_cpu_thermal_zone(zone_type: &str) -> bool {
+  zone_type == "x86_pkg_temp"
+    || zone_type == "soc_thermal"
+    || zone_type.to_lowercase().contains("cpu")
+}
@@ -26,6 +26,7 @@ pub fn get_cpu_info(
     clock: cpus[0].frequency() as u32,
     clock_unit: "MHz".to_string(),
     cpu_name: cpus[0].name().to_string(),
+    topology: None,
   };
 
   Ok(cpu_info)
@@ -55,6 +56,12 @@ pub fn get_storage_info() -> Result<Vec<models::hardware::StorageInfo>, String>
       free_unit: free.unit,
       storage_type: enums::hardware::DiskKind::from(disk.kind()),
       file_system: disk.file_system().to_string_lossy().into_owned(),
+      #[cfg(target_os = "linux")]
+      pcie_link: crate::infrastructure::providers::pci_sys::get_block_device_pcie_link(
+        &disk.name().to_string_lossy(),
+      ),
+      #[cfg(not(target_os = "linux"))]
+      pcie_link: None,
     };
 
     storage_info.push(storage);
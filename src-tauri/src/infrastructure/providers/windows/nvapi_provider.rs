@@ -207,6 +207,8 @@ pub async fn get_nvidia_gpu_info() -> Result<Vec<models::hardware::GraphicInfo>,
           precision: 1,
         }
         .to_string(),
+        pcie_link: None,
+        metrics: None,
       };
 
       gpu_info_list.push(gpu_info);
@@ -62,6 +62,7 @@ pub async fn query_memory_info() -> Result<MemoryInfo, String> {
       physical_memory[0].smbios_memory_type,
     ),
     is_detailed: true,
+    modules: Vec::new(),
   };
 
   Ok(memory_info)
@@ -39,22 +39,72 @@ pub fn run() {
   let system = Arc::new(Mutex::new(System::new_all()));
   let cpu_history = Arc::new(Mutex::new(VecDeque::with_capacity(60)));
   let memory_history = Arc::new(Mutex::new(VecDeque::with_capacity(60)));
+  let swap_history = Arc::new(Mutex::new(VecDeque::with_capacity(60)));
+  let battery_history = Arc::new(Mutex::new(VecDeque::with_capacity(60)));
+  let battery_power_history = Arc::new(Mutex::new(VecDeque::with_capacity(60)));
   let gpu_history = Arc::new(Mutex::new(VecDeque::with_capacity(60)));
+  let cpu_temperature_history = Arc::new(Mutex::new(VecDeque::with_capacity(60)));
   let process_cpu_histories = Arc::new(Mutex::new(HashMap::new()));
   let process_memory_histories = Arc::new(Mutex::new(HashMap::new()));
-  let nv_gpu_usage_histories = Arc::new(Mutex::new(HashMap::new()));
-  let nv_gpu_temperature_histories = Arc::new(Mutex::new(HashMap::new()));
-  let nv_gpu_dedicated_memory_histories = Arc::new(Mutex::new(HashMap::new()));
+  let gpu_usage_histories = Arc::new(Mutex::new(HashMap::new()));
+  let gpu_temperature_histories = Arc::new(Mutex::new(HashMap::new()));
+  let gpu_dedicated_memory_histories = Arc::new(Mutex::new(HashMap::new()));
+  let network_usage_histories = Arc::new(Mutex::new(HashMap::new()));
+  #[cfg(target_os = "linux")]
+  let network_counters = Arc::new(Mutex::new(HashMap::new()));
+  let disk_usage_histories = Arc::new(Mutex::new(HashMap::new()));
+  #[cfg(target_os = "linux")]
+  let disk_counters = Arc::new(Mutex::new(HashMap::new()));
+  let cpu_time_histories = Arc::new(Mutex::new(HashMap::new()));
+  #[cfg(target_os = "linux")]
+  let cpu_times = Arc::new(Mutex::new(HashMap::new()));
+  let pressure_history = Arc::new(Mutex::new(VecDeque::with_capacity(60)));
+  let cpu_power_history = Arc::new(Mutex::new(VecDeque::with_capacity(60)));
+  let rapl_power_histories = Arc::new(Mutex::new(HashMap::new()));
+  #[cfg(target_os = "linux")]
+  let rapl_counters = Arc::new(Mutex::new(HashMap::new()));
+  #[cfg(target_os = "linux")]
+  let cpu_energy_joules = Arc::new(Mutex::new(0.0));
+  let cpu_frequencies = Arc::new(Mutex::new(Vec::new()));
+  let cpu_frequency_histories = Arc::new(Mutex::new(HashMap::new()));
+  #[cfg(target_os = "linux")]
+  let cpu_throttle_windows = Arc::new(Mutex::new(HashMap::new()));
+  let gpu_metrics_histories = Arc::new(Mutex::new(HashMap::new()));
+  #[cfg(target_os = "linux")]
+  let drm_clients = Arc::new(Mutex::new(
+    models::hardware_archive::DrmClientSnapshots::default(),
+  ));
+  let nvidia_gpu_statuses = Arc::new(Mutex::new(HashMap::new()));
+  let process_gpu_histories = Arc::new(Mutex::new(HashMap::new()));
+  let process_gpu_memory_histories = Arc::new(Mutex::new(HashMap::new()));
 
   let state = models::hardware::HardwareMonitorState {
     system: Arc::clone(&system),
     cpu_history: Arc::clone(&cpu_history),
     memory_history: Arc::clone(&memory_history),
+    swap_history: Arc::clone(&swap_history),
+    battery_history: Arc::clone(&battery_history),
+    battery_power_history: Arc::clone(&battery_power_history),
     gpu_history: Arc::clone(&gpu_history),
+    cpu_temperature_history: Arc::clone(&cpu_temperature_history),
+    network_usage_histories: Arc::clone(&network_usage_histories),
+    disk_usage_histories: Arc::clone(&disk_usage_histories),
+    cpu_time_histories: Arc::clone(&cpu_time_histories),
+    pressure_history: Arc::clone(&pressure_history),
+    cpu_power_history: Arc::clone(&cpu_power_history),
+    rapl_power_histories: Arc::clone(&rapl_power_histories),
+    cpu_frequencies: Arc::clone(&cpu_frequencies),
+    cpu_frequency_histories: Arc::clone(&cpu_frequency_histories),
+    gpu_metrics_histories: Arc::clone(&gpu_metrics_histories),
+    process_gpu_histories: Arc::clone(&process_gpu_histories),
+    process_gpu_memory_histories: Arc::clone(&process_gpu_memory_histories),
     process_cpu_histories: Arc::clone(&process_cpu_histories),
     process_memory_histories: Arc::clone(&process_memory_histories),
-    nv_gpu_usage_histories: Arc::clone(&nv_gpu_usage_histories),
-    nv_gpu_temperature_histories: Arc::clone(&nv_gpu_temperature_histories),
+    gpu_usage_histories: Arc::clone(&gpu_usage_histories),
+    gpu_temperature_histories: Arc::clone(&gpu_temperature_histories),
+    nvidia_gpu_statuses: Arc::clone(&nvidia_gpu_statuses),
+    #[cfg(target_os = "linux")]
+    drm_clients: Arc::clone(&drm_clients),
   };
 
   let settings = app_state.settings.lock().unwrap().clone();
@@ -67,16 +117,41 @@ pub fn run() {
     hardware::get_process_list,
     hardware::get_cpu_usage,
     hardware::get_hardware_info,
+    hardware::get_pci_devices,
+    hardware::get_disk_health,
+    hardware::get_block_devices,
     hardware::get_memory_info_detail,
     hardware::get_memory_usage,
+    hardware::get_memory_breakdown,
     hardware::get_gpu_usage,
     hardware::get_processors_usage,
+    hardware::get_cpu_time_breakdown,
+    hardware::get_cpu_time_breakdown_history,
+    hardware::get_cpu_frequencies,
+    hardware::get_cpu_frequency_history,
     hardware::get_gpu_temperature,
-    hardware::get_nvidia_gpu_cooler,
+    hardware::get_cpu_temperature,
+    hardware::get_fans,
     hardware::get_cpu_usage_history,
     hardware::get_memory_usage_history,
+    hardware::get_swap_usage_history,
     hardware::get_gpu_usage_history,
+    hardware::get_gpu_usages,
+    hardware::get_gpu_metrics,
+    hardware::get_gpu_metrics_history,
+    hardware::get_cpu_temperature_history,
     hardware::get_network_info,
+    hardware::get_network_usage,
+    hardware::get_network_usage_history,
+    hardware::get_disk_usage,
+    hardware::get_disk_usage_history,
+    hardware::get_pressure_info,
+    hardware::get_pressure_history,
+    hardware::get_power_supply_info,
+    hardware::get_battery_history,
+    hardware::get_battery_power_history,
+    hardware::get_cpu_power,
+    hardware::get_cpu_power_history,
     settings::commands::get_settings,
     settings::commands::set_language,
     settings::commands::set_theme,
@@ -140,13 +215,58 @@ pub fn run() {
           system: Arc::clone(&system),
           cpu_history: Arc::clone(&cpu_history),
           memory_history: Arc::clone(&memory_history),
+          swap_history: Arc::clone(&swap_history),
+          #[cfg(target_os = "linux")]
+          battery_history: Arc::clone(&battery_history),
+          #[cfg(target_os = "linux")]
+          battery_power_history: Arc::clone(&battery_power_history),
+          #[cfg(target_os = "linux")]
+          gpu_history: Arc::clone(&gpu_history),
+          #[cfg(target_os = "linux")]
+          cpu_temperature_history: Arc::clone(&cpu_temperature_history),
+          #[cfg(target_os = "linux")]
+          network_usage_histories: Arc::clone(&network_usage_histories),
+          #[cfg(target_os = "linux")]
+          network_counters: Arc::clone(&network_counters),
+          #[cfg(target_os = "linux")]
+          disk_usage_histories: Arc::clone(&disk_usage_histories),
+          #[cfg(target_os = "linux")]
+          disk_counters: Arc::clone(&disk_counters),
+          #[cfg(target_os = "linux")]
+          cpu_time_histories: Arc::clone(&cpu_time_histories),
+          #[cfg(target_os = "linux")]
+          cpu_times: Arc::clone(&cpu_times),
+          #[cfg(target_os = "linux")]
+          pressure_history: Arc::clone(&pressure_history),
+          #[cfg(target_os = "linux")]
+          cpu_power_history: Arc::clone(&cpu_power_history),
+          #[cfg(target_os = "linux")]
+          rapl_power_histories: Arc::clone(&rapl_power_histories),
+          #[cfg(target_os = "linux")]
+          rapl_counters: Arc::clone(&rapl_counters),
+          #[cfg(target_os = "linux")]
+          cpu_energy_joules: Arc::clone(&cpu_energy_joules),
+          #[cfg(target_os = "linux")]
+          cpu_frequencies: Arc::clone(&cpu_frequencies),
+          #[cfg(target_os = "linux")]
+          cpu_frequency_histories: Arc::clone(&cpu_frequency_histories),
+          #[cfg(target_os = "linux")]
+          cpu_throttle_windows: Arc::clone(&cpu_throttle_windows),
+          #[cfg(target_os = "linux")]
+          gpu_metrics_histories: Arc::clone(&gpu_metrics_histories),
+          #[cfg(target_os = "linux")]
+          drm_clients: Arc::clone(&drm_clients),
+          #[cfg(target_os = "linux")]
+          nvidia_gpu_statuses: Arc::clone(&nvidia_gpu_statuses),
+          #[cfg(target_os = "linux")]
+          process_gpu_histories: Arc::clone(&process_gpu_histories),
+          #[cfg(target_os = "linux")]
+          process_gpu_memory_histories: Arc::clone(&process_gpu_memory_histories),
           process_cpu_histories: Arc::clone(&process_cpu_histories),
           process_memory_histories: Arc::clone(&process_memory_histories),
-          nv_gpu_usage_histories: Arc::clone(&nv_gpu_usage_histories),
-          nv_gpu_temperature_histories: Arc::clone(&nv_gpu_temperature_histories),
-          nv_gpu_dedicated_memory_histories: Arc::clone(
-            &nv_gpu_dedicated_memory_histories,
-          ),
+          gpu_usage_histories: Arc::clone(&gpu_usage_histories),
+          gpu_temperature_histories: Arc::clone(&gpu_temperature_histories),
+          gpu_dedicated_memory_histories: Arc::clone(&gpu_dedicated_memory_histories),
         },
       );
       {
@@ -161,13 +281,58 @@ pub fn run() {
             system: Arc::clone(&system),
             cpu_history: Arc::clone(&cpu_history),
             memory_history: Arc::clone(&memory_history),
+            swap_history: Arc::clone(&swap_history),
+            #[cfg(target_os = "linux")]
+            battery_history: Arc::clone(&battery_history),
+            #[cfg(target_os = "linux")]
+            battery_power_history: Arc::clone(&battery_power_history),
+            #[cfg(target_os = "linux")]
+            gpu_history: Arc::clone(&gpu_history),
+            #[cfg(target_os = "linux")]
+            cpu_temperature_history: Arc::clone(&cpu_temperature_history),
+            #[cfg(target_os = "linux")]
+            network_usage_histories: Arc::clone(&network_usage_histories),
+            #[cfg(target_os = "linux")]
+            network_counters: Arc::clone(&network_counters),
+            #[cfg(target_os = "linux")]
+            disk_usage_histories: Arc::clone(&disk_usage_histories),
+            #[cfg(target_os = "linux")]
+            disk_counters: Arc::clone(&disk_counters),
+            #[cfg(target_os = "linux")]
+            cpu_time_histories: Arc::clone(&cpu_time_histories),
+            #[cfg(target_os = "linux")]
+            cpu_times: Arc::clone(&cpu_times),
+            #[cfg(target_os = "linux")]
+            pressure_history: Arc::clone(&pressure_history),
+            #[cfg(target_os = "linux")]
+            cpu_power_history: Arc::clone(&cpu_power_history),
+            #[cfg(target_os = "linux")]
+            rapl_power_histories: Arc::clone(&rapl_power_histories),
+            #[cfg(target_os = "linux")]
+            rapl_counters: Arc::clone(&rapl_counters),
+            #[cfg(target_os = "linux")]
+            cpu_energy_joules: Arc::clone(&cpu_energy_joules),
+            #[cfg(target_os = "linux")]
+            cpu_frequencies: Arc::clone(&cpu_frequencies),
+            #[cfg(target_os = "linux")]
+            cpu_frequency_histories: Arc::clone(&cpu_frequency_histories),
+            #[cfg(target_os = "linux")]
+            cpu_throttle_windows: Arc::clone(&cpu_throttle_windows),
+            #[cfg(target_os = "linux")]
+            gpu_metrics_histories: Arc::clone(&gpu_metrics_histories),
+            #[cfg(target_os = "linux")]
+            drm_clients: Arc::clone(&drm_clients),
+            #[cfg(target_os = "linux")]
+            nvidia_gpu_statuses: Arc::clone(&nvidia_gpu_statuses),
+            #[cfg(target_os = "linux")]
+            process_gpu_histories: Arc::clone(&process_gpu_histories),
+            #[cfg(target_os = "linux")]
+            process_gpu_memory_histories: Arc::clone(&process_gpu_memory_histories),
             process_cpu_histories: Arc::clone(&process_cpu_histories),
             process_memory_histories: Arc::clone(&process_memory_histories),
-            nv_gpu_usage_histories: Arc::clone(&nv_gpu_usage_histories),
-            nv_gpu_temperature_histories: Arc::clone(&nv_gpu_temperature_histories),
-            nv_gpu_dedicated_memory_histories: Arc::clone(
-              &nv_gpu_dedicated_memory_histories,
-            ),
+            gpu_usage_histories: Arc::clone(&gpu_usage_histories),
+            gpu_temperature_histories: Arc::clone(&gpu_temperature_histories),
+            gpu_dedicated_memory_histories: Arc::clone(&gpu_dedicated_memory_histories),
           },
         );
         {
@@ -1,21 +1,47 @@
-use crate::{enums::hardware::DiskKind, utils::formatter::SizeUnit};
+use crate::{
+  enums::hardware::{BlockDeviceKind, DiskHealthStatus, DiskKind},
+  utils::formatter::SizeUnit,
+};
 use serde::{Deserialize, Serialize};
 use specta::Type;
 use std::collections::{HashMap, VecDeque};
 use std::sync::{Arc, Mutex};
 use sysinfo;
 
+///
+/// Histories and caches read by the commands
+///
+/// Unlike `MonitorResources`, the Linux-only histories (battery, CPU power,
+/// per-process GPU ...) are kept on every platform so the commands have one
+/// signature everywhere and return empty histories where nothing is sampled.
+///
 pub struct HardwareMonitorState {
   pub system: Arc<Mutex<sysinfo::System>>,
   pub cpu_history: Arc<Mutex<VecDeque<f32>>>,
   pub memory_history: Arc<Mutex<VecDeque<f32>>>,
+  pub swap_history: Arc<Mutex<VecDeque<f32>>>,
+  pub battery_history: Arc<Mutex<VecDeque<f32>>>,
+  pub battery_power_history: Arc<Mutex<VecDeque<f32>>>,
+  pub cpu_power_history: Arc<Mutex<VecDeque<f32>>>,
+  pub cpu_frequencies: Arc<Mutex<Vec<CpuCoreFrequency>>>,
+  pub cpu_frequency_histories: Arc<Mutex<HashMap<u32, VecDeque<f32>>>>,
+  pub rapl_power_histories: Arc<Mutex<HashMap<String, VecDeque<RaplPower>>>>,
   pub gpu_history: Arc<Mutex<VecDeque<f32>>>,
+  pub gpu_metrics_histories: Arc<Mutex<HashMap<String, VecDeque<GpuMetrics>>>>,
+  pub cpu_temperature_history: Arc<Mutex<VecDeque<f32>>>,
   pub process_cpu_histories: Arc<Mutex<HashMap<sysinfo::Pid, VecDeque<f32>>>>,
   pub process_memory_histories: Arc<Mutex<HashMap<sysinfo::Pid, VecDeque<f32>>>>,
-  #[allow(dead_code)]
-  pub nv_gpu_usage_histories: Arc<Mutex<HashMap<String, VecDeque<f32>>>>,
-  #[allow(dead_code)]
-  pub nv_gpu_temperature_histories: Arc<Mutex<HashMap<String, VecDeque<i32>>>>,
+  pub process_gpu_histories: Arc<Mutex<HashMap<sysinfo::Pid, VecDeque<f32>>>>,
+  pub process_gpu_memory_histories: Arc<Mutex<HashMap<sysinfo::Pid, VecDeque<f32>>>>,
+  pub network_usage_histories: Arc<Mutex<HashMap<String, VecDeque<NetworkUsage>>>>,
+  pub disk_usage_histories: Arc<Mutex<HashMap<String, VecDeque<DiskUsage>>>>,
+  pub cpu_time_histories: Arc<Mutex<HashMap<String, VecDeque<CpuTimeBreakdown>>>>,
+  pub pressure_history: Arc<Mutex<VecDeque<PressureInfo>>>,
+  pub gpu_usage_histories: Arc<Mutex<HashMap<String, VecDeque<f32>>>>,
+  pub gpu_temperature_histories: Arc<Mutex<HashMap<String, VecDeque<i32>>>>,
+  pub nvidia_gpu_statuses: Arc<Mutex<HashMap<u8, NvidiaGpuStatus>>>,
+  #[cfg(target_os = "linux")]
+  pub drm_clients: Arc<Mutex<crate::models::hardware_archive::DrmClientSnapshots>>,
 }
 
 #[derive(Serialize, Deserialize, Type, Clone)]
@@ -28,6 +54,129 @@ pub struct MemoryInfo {
   pub total_slots: u32,
   pub memory_type: String,
   pub is_detailed: bool,
+  /// Per-slot details (empty when only the aggregate is available)
+  #[serde(default)]
+  pub modules: Vec<MemoryModule>,
+}
+
+///
+/// A memory slot (SMBIOS Memory Device, Type 17)
+///
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct MemoryModule {
+  /// Slot name (e.g. `DIMM_A1`, `ChannelA-DIMM0`)
+  pub locator: String,
+  pub bank_locator: Option<String>,
+  /// No module is installed in the slot
+  pub is_empty: bool,
+  pub size_mb: Option<u32>,
+  /// e.g. `DDR4`, `DDR5`, `LPDDR5`
+  pub memory_type: Option<String>,
+  /// Maximum speed supported by the module (MT/s)
+  pub rated_speed_mts: Option<u32>,
+  /// Speed the memory controller actually runs at (MT/s)
+  pub configured_speed_mts: Option<u32>,
+  pub manufacturer: Option<String>,
+  pub part_number: Option<String>,
+  pub serial_number: Option<String>,
+  pub rank: Option<u32>,
+}
+
+///
+/// Breakdown of system memory (`/proc/meminfo`)
+///
+/// All sizes are in MiB
+///
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct MemoryBreakdown {
+  pub total: f32,
+  pub free: f32,
+  /// Estimate of memory available for new workloads without swapping
+  pub available: f32,
+  pub buffers: f32,
+  /// Page cache (excluding swap cache)
+  pub cached: f32,
+  pub swap_cached: f32,
+  /// Waiting to be written back to disk
+  pub dirty: f32,
+  /// Actively being written back to disk
+  pub writeback: f32,
+  /// Kernel data structures cache
+  pub slab: f32,
+  /// Part of `slab` that can be reclaimed under pressure
+  pub slab_reclaimable: f32,
+  /// tmpfs and shared memory
+  pub shmem: f32,
+  pub swap_total: f32,
+  pub swap_free: f32,
+  /// Memory the kernel has promised to allocations (`Committed_AS`)
+  pub committed: f32,
+  pub commit_limit: f32,
+  pub hugepages_total: u32,
+  pub hugepages_free: u32,
+  pub hugepage_size: f32,
+}
+
+///
+/// Status of a battery (`/sys/class/power_supply/BAT*`)
+///
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct BatteryInfo {
+  /// e.g. `BAT0`
+  pub name: String,
+  /// `Charging` / `Discharging` / `Full` / `Not charging` / `Unknown`
+  pub status: String,
+  pub percentage: Option<f32>,
+  /// Positive while charging, negative while discharging (W)
+  pub power_watts: Option<f32>,
+  /// Current voltage (V)
+  pub voltage: Option<f32>,
+  pub cycle_count: Option<u32>,
+  pub energy_full_wh: Option<f32>,
+  pub energy_full_design_wh: Option<f32>,
+  /// `energy_full_wh` / `energy_full_design_wh` (%)
+  pub health: Option<f32>,
+  /// Only reported while discharging
+  pub time_to_empty_minutes: Option<f32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct PowerSupplyInfo {
+  /// `None` when the system has no AC adapter (e.g. desktop without power_supply class)
+  pub ac_online: Option<bool>,
+  pub batteries: Vec<BatteryInfo>,
+}
+
+///
+/// Negotiated PCIe link against what the device supports
+///
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct PcieLinkStatus {
+  /// PCIe generation (1 = 2.5 GT/s ... 6 = 64 GT/s)
+  pub current_generation: Option<u32>,
+  pub max_generation: Option<u32>,
+  pub current_width: Option<u32>,
+  pub max_width: Option<u32>,
+  /// Maximum link of the port the device is plugged into (root port / switch)
+  pub slot_max_generation: Option<u32>,
+  pub slot_max_width: Option<u32>,
+  /// The slot supports fewer lanes or a lower generation than the device
+  /// (e.g. x16 card in an x4 slot)
+  ///
+  /// Derived from the maximum links of both ends, so it does not change at runtime.
+  pub is_slot_limited: bool,
+  /// Currently running with fewer lanes than the device supports
+  pub is_width_degraded: bool,
+  /// Currently running below the speed the device supports
+  ///
+  /// GPUs lower the link speed while idle to save power, so this is transient
+  /// and only meaningful under load.
+  pub is_speed_degraded: bool,
 }
 
 #[derive(Serialize, Deserialize, Type)]
@@ -39,6 +188,49 @@ pub struct GraphicInfo {
   pub clock: u32,
   pub memory_size: String,
   pub memory_size_dedicated: String,
+  /// `None` for integrated GPUs and on platforms without sysfs
+  #[serde(default)]
+  pub pcie_link: Option<PcieLinkStatus>,
+  /// Live clocks / memory / power (Linux only)
+  #[serde(default)]
+  pub metrics: Option<GpuMetrics>,
+}
+
+///
+/// Clocks, memory and power of a GPU read from sysfs
+///
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct GpuMetrics {
+  /// e.g. `card1`
+  pub card: String,
+  pub core_clock_mhz: Option<u32>,
+  pub memory_clock_mhz: Option<u32>,
+  pub vram_used_mb: Option<f32>,
+  pub vram_total_mb: Option<f32>,
+  /// System memory mapped by the GPU (Graphics Translation Table)
+  pub gtt_used_mb: Option<f32>,
+  pub gtt_total_mb: Option<f32>,
+  pub power_watts: Option<f32>,
+  pub power_cap_watts: Option<f32>,
+  /// Fan duty cycle (%) reported by the driver (NVIDIA)
+  pub fan_speed_percent: Option<f32>,
+}
+
+///
+/// Latest usage, memory and temperature of a single GPU
+///
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct GpuUsage {
+  /// Same as `GraphicInfo.id` (e.g. `card1`)
+  pub id: String,
+  /// Usage (%)
+  pub usage: Option<f32>,
+  pub memory_used_mb: Option<f32>,
+  pub memory_total_mb: Option<f32>,
+  /// Celsius
+  pub temperature: Option<i32>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, Type)]
@@ -48,6 +240,25 @@ pub struct NameValue {
   pub value: i32, // Celsius temperature
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct FanInfo {
+  /// Sensor chip the fan is attached to (e.g. `nct6798`, `amdgpu`)
+  pub chip: String,
+
+  /// Fan label (e.g. `CPU Fan`, `fan1`)
+  pub label: String,
+
+  /// Current speed (RPM)
+  pub rpm: Option<u32>,
+
+  /// PWM duty cycle (%)
+  pub pwm_percent: Option<f32>,
+
+  /// Whether the fan is controlled by a GPU
+  pub is_gpu: bool,
+}
+
 #[derive(Serialize, Deserialize, Type)]
 #[serde(rename_all = "camelCase")]
 pub struct StorageInfo {
@@ -58,6 +269,77 @@ pub struct StorageInfo {
   pub free_unit: SizeUnit,
   pub storage_type: DiskKind,
   pub file_system: String,
+  /// PCIe link of the NVMe controller (Linux only)
+  pub pcie_link: Option<PcieLinkStatus>,
+}
+
+///
+/// Health of a physical disk from `smartctl --json` and hwmon
+///
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct DiskHealth {
+  /// Physical block device (e.g. `nvme0n1`, `sda`)
+  pub device: String,
+  pub model: Option<String>,
+  pub serial: Option<String>,
+  pub firmware: Option<String>,
+  /// Mount points of the partitions and dm / md devices on this disk
+  pub mount_points: Vec<String>,
+  /// Overall SMART self-assessment
+  pub smart_passed: Option<bool>,
+  pub power_on_hours: Option<u32>,
+  /// Celsius
+  pub temperature: Option<f32>,
+  /// ATA attribute 5 (Reallocated Sector Count)
+  pub reallocated_sectors: Option<u32>,
+  /// ATA attribute 197 (Current Pending Sector Count)
+  pub pending_sectors: Option<u32>,
+  /// ATA attribute 198 (Offline Uncorrectable Sector Count)
+  pub uncorrectable_sectors: Option<u32>,
+  /// NVMe `Percentage Used` of the rated endurance (may exceed 100)
+  pub percentage_used: Option<u32>,
+  /// NVMe `Media and Data Integrity Errors`
+  pub media_errors: Option<u32>,
+  /// NVMe `Critical Warning` bits
+  pub critical_warning: Option<u32>,
+  pub status: DiskHealthStatus,
+}
+
+///
+/// A node of the block device tree (disk -> partitions -> dm / md layers)
+///
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct BlockDevice {
+  /// Kernel name (e.g. `nvme0n1`, `nvme0n1p2`, `dm-0`)
+  pub name: String,
+  pub kind: BlockDeviceKind,
+  /// Device mapper name (e.g. `cryptroot`, `vg0-root`) or md RAID level
+  pub label: Option<String>,
+  /// Disk model (disks only)
+  pub model: Option<String>,
+  pub size: f32,
+  pub size_unit: SizeUnit,
+  /// `None` for partitions, which share the queue of their disk
+  pub rotational: Option<bool>,
+  /// Active I/O scheduler (e.g. `mq-deadline`, `none`)
+  pub scheduler: Option<String>,
+  pub mount_points: Vec<String>,
+  pub file_system: Option<String>,
+  /// Partitions, then the dm / md devices stacked on this device
+  pub children: Vec<BlockDevice>,
+}
+
+///
+/// An entry of `/proc/mounts`
+///
+#[derive(Debug, Clone, PartialEq)]
+pub struct MountEntry {
+  /// Mounted device as written in `/proc/mounts` (e.g. `/dev/mapper/vg0-root`)
+  pub source: String,
+  pub mount_point: String,
+  pub file_system: String,
 }
 
 #[derive(Serialize, Deserialize, Type)]
@@ -73,6 +355,262 @@ pub struct NetworkInfo {
   pub default_ipv6_gateway: Vec<String>,
 }
 
+///
+/// Cumulative interface counters (`/sys/class/net/<iface>/statistics`)
+///
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NetworkCounters {
+  pub rx_bytes: u64,
+  pub tx_bytes: u64,
+  pub rx_packets: u64,
+  pub tx_packets: u64,
+  pub rx_errors: u64,
+  pub tx_errors: u64,
+  pub rx_dropped: u64,
+  pub tx_dropped: u64,
+}
+
+///
+/// Per-second network throughput of an interface
+///
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkUsage {
+  pub interface: String,
+  pub rx_bytes_per_sec: f32,
+  pub tx_bytes_per_sec: f32,
+  pub rx_packets_per_sec: f32,
+  pub tx_packets_per_sec: f32,
+  pub rx_errors_per_sec: f32,
+  pub tx_errors_per_sec: f32,
+  pub rx_dropped_per_sec: f32,
+  pub tx_dropped_per_sec: f32,
+}
+
+///
+/// Cumulative block device counters (`/proc/diskstats`)
+///
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DiskCounters {
+  pub reads_completed: u64,
+  pub sectors_read: u64,
+  pub read_time_ms: u64,
+  pub writes_completed: u64,
+  pub sectors_written: u64,
+  pub write_time_ms: u64,
+  pub io_time_ms: u64,
+}
+
+///
+/// Per-second I/O activity of a block device
+///
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct DiskUsage {
+  /// Block device name (e.g. `nvme0n1`, `sda1`, `dm-0`)
+  pub device: String,
+  pub read_bytes_per_sec: f32,
+  pub write_bytes_per_sec: f32,
+  pub read_iops: f32,
+  pub write_iops: f32,
+  /// Average time per completed request (ms)
+  pub avg_latency_ms: f32,
+  /// Percentage of time the device had I/O in flight
+  pub utilization: f32,
+}
+
+///
+/// Frequency scaling status of a core (`/sys/devices/system/cpu/cpuN`)
+///
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct CpuCoreFrequency {
+  pub core: u32,
+  pub current_mhz: f32,
+  /// Scaling limits set by the governor / policy
+  pub min_mhz: Option<f32>,
+  pub max_mhz: Option<f32>,
+  /// e.g. `performance`, `powersave`, `schedutil`
+  pub governor: Option<String>,
+  /// Thermal throttling events since boot (Intel `thermal_throttle`)
+  pub core_throttle_count: Option<u32>,
+  pub package_throttle_count: Option<u32>,
+  /// Throttle events over the last `CPU_THROTTLE_WINDOW_SECONDS`
+  pub throttle_events: u32,
+  /// `throttle_events` is non-zero
+  pub is_throttled: bool,
+}
+
+///
+/// RAPL energy counter of a powercap zone (`/sys/class/powercap/intel-rapl:*`)
+///
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RaplCounters {
+  /// `package-0`, `core`, `uncore`, `dram`, `psys`
+  pub name: String,
+  pub energy_uj: u64,
+  pub max_energy_range_uj: u64,
+}
+
+///
+/// Busy counter of a GPU engine reported in DRM fdinfo
+///
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DrmEngineCounter {
+  /// Busy time (ns, `drm-engine-*`) or busy cycles (`drm-cycles-*`, xe)
+  pub busy: u64,
+  /// Elapsed GPU cycles (`drm-total-cycles-*`, xe only)
+  pub total: Option<u64>,
+  /// Number of engines of this class (`drm-engine-capacity-*`, 1 when omitted)
+  pub capacity: u32,
+}
+
+///
+/// A DRM client (an open GPU context) parsed from `/proc/<pid>/fdinfo/<fd>`
+///
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DrmClient {
+  pub pid: u32,
+  /// `i915`, `xe`, `amdgpu` ...
+  pub driver: String,
+  /// PCI address of the GPU (e.g. `0000:00:02.0`)
+  pub pdev: String,
+  pub client_id: u64,
+  /// Engine class (`render`, `video`, `rcs` ...) -> counter
+  pub engines: HashMap<String, DrmEngineCounter>,
+  /// Memory region (`vram`, `gtt`, `local0`, `system0` ...) -> resident bytes
+  pub memory: HashMap<String, u64>,
+}
+
+///
+/// Status of an NVIDIA GPU reported by the proprietary driver
+///
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NvidiaGpuStatus {
+  /// PCI address in sysfs form (e.g. `0000:01:00.0`)
+  pub pci_address: String,
+  pub name: String,
+  /// Usage (%)
+  pub usage: Option<f32>,
+  /// Celsius
+  pub temperature: Option<f32>,
+  pub memory_used_mb: Option<f32>,
+  pub memory_total_mb: Option<f32>,
+  pub core_clock_mhz: Option<u32>,
+  pub memory_clock_mhz: Option<u32>,
+  pub power_watts: Option<f32>,
+  pub power_limit_watts: Option<f32>,
+  /// Fan duty cycle (%)
+  pub fan_speed_percent: Option<f32>,
+}
+
+///
+/// Power draw of a RAPL zone
+///
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct RaplPower {
+  /// Zone id (e.g. `intel-rapl:0`, `intel-rapl:0:0`)
+  pub zone: String,
+  /// Domain name (e.g. `package-0`, `core`, `dram`)
+  pub name: String,
+  pub watts: f32,
+}
+
+///
+/// Cumulative CPU time counters in USER_HZ (`/proc/stat`)
+///
+/// `guest` / `guest_nice` are already accounted in `user` / `nice` and are not kept.
+///
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CpuTimes {
+  pub user: u64,
+  pub nice: u64,
+  pub system: u64,
+  pub idle: u64,
+  pub iowait: u64,
+  pub irq: u64,
+  pub softirq: u64,
+  pub steal: u64,
+}
+
+///
+/// Share of CPU time (%) spent in each state since the previous sample
+///
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct CpuTimeBreakdown {
+  /// `cpu` for the aggregate of all cores, `cpuN` for each core
+  pub cpu: String,
+  pub user: f32,
+  pub nice: f32,
+  pub system: f32,
+  pub idle: f32,
+  /// Idle while waiting for I/O to complete
+  pub iowait: f32,
+  pub irq: f32,
+  pub softirq: f32,
+  /// Time stolen by the hypervisor for other guests
+  pub steal: f32,
+}
+
+///
+/// A `some` / `full` line of `/proc/pressure/*`
+///
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct PressureStall {
+  /// Share of time (%) stalled over the last 10 / 60 / 300 seconds
+  pub avg10: f32,
+  pub avg60: f32,
+  pub avg300: f32,
+  /// Cumulative stall time (ms)
+  pub total_ms: f64,
+}
+
+///
+/// Pressure Stall Information of a resource (cpu / memory / io)
+///
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct PressureResource {
+  /// At least one task was stalled on the resource
+  pub some: PressureStall,
+  /// All non-idle tasks were stalled at the same time
+  /// (not reported for cpu before Linux 5.13)
+  pub full: Option<PressureStall>,
+}
+
+///
+/// `/proc/loadavg`
+///
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct LoadAverage {
+  pub one: f32,
+  pub five: f32,
+  pub fifteen: f32,
+  /// Currently runnable scheduling entities
+  pub running: u32,
+  /// Scheduling entities that exist on the system
+  pub total: u32,
+}
+
+///
+/// Resource contention of the system
+///
+/// PSI fields are `None` when the kernel is built without `CONFIG_PSI`
+/// or booted with `psi=0`.
+///
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct PressureInfo {
+  pub cpu: Option<PressureResource>,
+  pub memory: Option<PressureResource>,
+  pub io: Option<PressureResource>,
+  pub load_average: LoadAverage,
+}
+
 #[derive(Serialize, Deserialize, Type)]
 #[serde(rename_all = "camelCase")]
 pub struct ProcessInfo {
@@ -89,6 +627,14 @@ pub struct ProcessInfo {
   /// Memory usage
   #[serde(serialize_with = "serialize_usage")]
   pub memory_usage: f32,
+
+  /// GPU usage (busiest engine, DRM fdinfo)
+  #[serde(serialize_with = "serialize_usage")]
+  pub gpu_usage: f32,
+
+  /// Dedicated GPU memory usage (MB)
+  #[serde(serialize_with = "serialize_usage")]
+  pub gpu_memory_usage: f32,
 }
 
 #[derive(Serialize, Deserialize, Type)]
@@ -98,6 +644,56 @@ pub struct SysInfo {
   pub memory: Option<MemoryInfo>,
   pub gpus: Option<Vec<GraphicInfo>>,
   pub storage: Vec<StorageInfo>,
+  pub board: Option<BoardInfo>,
+}
+
+///
+/// Motherboard, firmware and chassis information (DMI / SMBIOS)
+///
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct BoardInfo {
+  pub board_vendor: Option<String>,
+  pub board_name: Option<String>,
+  pub board_version: Option<String>,
+  pub bios_vendor: Option<String>,
+  pub bios_version: Option<String>,
+  /// As reported by the firmware (usually `MM/DD/YYYY`)
+  pub bios_date: Option<String>,
+  pub system_vendor: Option<String>,
+  pub product_name: Option<String>,
+  /// e.g. `Desktop`, `Laptop`, `Rack Mount Chassis`
+  pub chassis_type: Option<String>,
+}
+
+///
+/// PCI device enumerated from `/sys/bus/pci/devices`
+///
+/// IDs are lowercase hex without `0x` (e.g. `10de`). Names are resolved from
+/// `pci.ids` and are `None` when the database is not installed.
+///
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct PciDevice {
+  /// e.g. `0000:01:00.0`
+  pub address: String,
+  pub vendor_id: String,
+  pub device_id: String,
+  pub subsystem_vendor_id: Option<String>,
+  pub subsystem_device_id: Option<String>,
+  /// Class / subclass / prog-if (e.g. `030000`)
+  pub class_code: String,
+  pub class_name: Option<String>,
+  pub vendor_name: Option<String>,
+  pub device_name: Option<String>,
+  pub subsystem_name: Option<String>,
+  /// Bound kernel driver (e.g. `amdgpu`, `nvme`)
+  pub driver: Option<String>,
+  /// PCIe link speed in GT/s
+  pub current_link_speed: Option<f32>,
+  pub max_link_speed: Option<f32>,
+  pub current_link_width: Option<u32>,
+  pub max_link_width: Option<u32>,
 }
 
 #[derive(Serialize, Deserialize, Type)]
@@ -109,6 +705,56 @@ pub struct CpuInfo {
   pub clock: u32,
   pub clock_unit: String,
   pub cpu_name: String,
+  /// Detailed layout (Linux only)
+  pub topology: Option<CpuTopology>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct CpuTopology {
+  pub sockets: u32,
+  pub physical_cores: u32,
+  pub logical_cores: u32,
+  pub smt_enabled: bool,
+  /// Hybrid core sets (P-cores / E-cores), empty on non-hybrid CPUs
+  pub core_sets: Vec<CpuCoreSet>,
+  pub caches: Vec<CpuCache>,
+  pub numa_nodes: Vec<NumaNode>,
+  /// Notable ISA extensions (e.g. `avx2`, `avx512f`, `sve`)
+  pub isa_flags: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct CpuCoreSet {
+  /// `Performance` / `Efficiency`
+  pub kind: String,
+  /// Kernel CPU list format (e.g. `0-15`)
+  pub cpu_list: String,
+  pub logical_cores: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct CpuCache {
+  pub level: u32,
+  /// `Data` / `Instruction` / `Unified`
+  pub cache_type: String,
+  /// Size of a single instance (KiB)
+  pub size_kb: u32,
+  /// Number of distinct instances across the system
+  pub instances: u32,
+  /// Logical CPUs sharing one instance
+  pub shared_cpu_count: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct NumaNode {
+  pub id: u32,
+  /// Kernel CPU list format (e.g. `0-7,16-23`)
+  pub cpu_list: String,
+  pub logical_cores: u32,
 }
 
 fn serialize_usage<S>(x: &f32, s: S) -> Result<S::Ok, S::Error>
@@ -5,15 +5,108 @@ use std::{
   sync::{Arc, Mutex},
 };
 
+/// DRM clients with the time they were read
+pub type DrmClientSnapshot =
+  (std::time::Instant, Vec<crate::models::hardware::DrmClient>);
+
+/// DRM fdinfo snapshots of the last two ticks
+///
+/// Read once per tick and shared by GPU busyness and per-process GPU usage.
+#[derive(Default)]
+pub struct DrmClientSnapshots {
+  pub previous: Option<DrmClientSnapshot>,
+  pub latest: Option<DrmClientSnapshot>,
+}
+
+impl DrmClientSnapshots {
+  /// Previous and latest clients with the time between them
+  pub fn delta(
+    &self,
+  ) -> Option<(
+    &[crate::models::hardware::DrmClient],
+    &[crate::models::hardware::DrmClient],
+    std::time::Duration,
+  )> {
+    let (prev_at, prev) = self.previous.as_ref()?;
+    let (curr_at, curr) = self.latest.as_ref()?;
+    Some((prev, curr, curr_at.duration_since(*prev_at)))
+  }
+}
+
 pub struct MonitorResources {
   pub system: Arc<Mutex<sysinfo::System>>,
   pub cpu_history: Arc<Mutex<VecDeque<f32>>>,
   pub memory_history: Arc<Mutex<VecDeque<f32>>>,
+  pub swap_history: Arc<Mutex<VecDeque<f32>>>,
+  #[cfg(target_os = "linux")]
+  pub battery_history: Arc<Mutex<VecDeque<f32>>>,
+  #[cfg(target_os = "linux")]
+  pub battery_power_history: Arc<Mutex<VecDeque<f32>>>,
+  #[cfg(target_os = "linux")]
+  pub cpu_power_history: Arc<Mutex<VecDeque<f32>>>,
+  #[cfg(target_os = "linux")]
+  pub cpu_frequencies: Arc<Mutex<Vec<crate::models::hardware::CpuCoreFrequency>>>,
+  #[cfg(target_os = "linux")]
+  pub cpu_frequency_histories: Arc<Mutex<HashMap<u32, VecDeque<f32>>>>,
+  /// Per-core samples of the last `CPU_THROTTLE_WINDOW_SECONDS`
+  #[cfg(target_os = "linux")]
+  pub cpu_throttle_windows:
+    Arc<Mutex<HashMap<u32, VecDeque<crate::models::hardware::CpuCoreFrequency>>>>,
+  /// Package energy (J) consumed since the last archive, reset by the archive job
+  #[cfg(target_os = "linux")]
+  pub cpu_energy_joules: Arc<Mutex<f64>>,
+  #[cfg(target_os = "linux")]
+  pub rapl_power_histories:
+    Arc<Mutex<HashMap<String, VecDeque<crate::models::hardware::RaplPower>>>>,
+  #[cfg(target_os = "linux")]
+  pub rapl_counters: Arc<
+    Mutex<HashMap<String, (std::time::Instant, crate::models::hardware::RaplCounters)>>,
+  >,
+  #[cfg(target_os = "linux")]
+  pub gpu_history: Arc<Mutex<VecDeque<f32>>>,
+  #[cfg(target_os = "linux")]
+  pub gpu_metrics_histories:
+    Arc<Mutex<HashMap<String, VecDeque<crate::models::hardware::GpuMetrics>>>>,
+  #[cfg(target_os = "linux")]
+  pub drm_clients: Arc<Mutex<DrmClientSnapshots>>,
+  /// Latest `nvidia-smi` status of every NVIDIA card, keyed by DRM card id
+  #[cfg(target_os = "linux")]
+  pub nvidia_gpu_statuses:
+    Arc<Mutex<HashMap<u8, crate::models::hardware::NvidiaGpuStatus>>>,
+  #[cfg(target_os = "linux")]
+  pub cpu_temperature_history: Arc<Mutex<VecDeque<f32>>>,
   pub process_cpu_histories: Arc<Mutex<HashMap<sysinfo::Pid, VecDeque<f32>>>>,
   pub process_memory_histories: Arc<Mutex<HashMap<sysinfo::Pid, VecDeque<f32>>>>,
-  pub nv_gpu_usage_histories: Arc<Mutex<HashMap<String, VecDeque<f32>>>>,
-  pub nv_gpu_temperature_histories: Arc<Mutex<HashMap<String, VecDeque<i32>>>>,
-  pub nv_gpu_dedicated_memory_histories: Arc<Mutex<HashMap<String, VecDeque<i32>>>>,
+  #[cfg(target_os = "linux")]
+  pub process_gpu_histories: Arc<Mutex<HashMap<sysinfo::Pid, VecDeque<f32>>>>,
+  #[cfg(target_os = "linux")]
+  pub process_gpu_memory_histories: Arc<Mutex<HashMap<sysinfo::Pid, VecDeque<f32>>>>,
+  #[cfg(target_os = "linux")]
+  pub network_usage_histories:
+    Arc<Mutex<HashMap<String, VecDeque<crate::models::hardware::NetworkUsage>>>>,
+  #[cfg(target_os = "linux")]
+  pub network_counters: Arc<
+    Mutex<
+      HashMap<String, (std::time::Instant, crate::models::hardware::NetworkCounters)>,
+    >,
+  >,
+  #[cfg(target_os = "linux")]
+  pub disk_usage_histories:
+    Arc<Mutex<HashMap<String, VecDeque<crate::models::hardware::DiskUsage>>>>,
+  #[cfg(target_os = "linux")]
+  pub disk_counters: Arc<
+    Mutex<HashMap<String, (std::time::Instant, crate::models::hardware::DiskCounters)>>,
+  >,
+  #[cfg(target_os = "linux")]
+  pub cpu_time_histories:
+    Arc<Mutex<HashMap<String, VecDeque<crate::models::hardware::CpuTimeBreakdown>>>>,
+  #[cfg(target_os = "linux")]
+  pub pressure_history: Arc<Mutex<VecDeque<crate::models::hardware::PressureInfo>>>,
+  #[cfg(target_os = "linux")]
+  pub cpu_times: Arc<Mutex<HashMap<String, crate::models::hardware::CpuTimes>>>,
+  pub gpu_usage_histories: Arc<Mutex<HashMap<String, VecDeque<f32>>>>,
+  pub gpu_temperature_histories: Arc<Mutex<HashMap<String, VecDeque<i32>>>>,
+  pub gpu_dedicated_memory_histories: Arc<Mutex<HashMap<String, VecDeque<i32>>>>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, Type)]
@@ -24,13 +117,22 @@ pub struct HardwareArchiveSettings {
   pub refresh_interval_days: u32,
 }
 
-#[derive(Serialize, Deserialize, Debug)]
+#[derive(Serialize, Deserialize, Debug, Default)]
 pub struct HardwareData {
   pub avg: Option<f32>,
   pub max: Option<f32>,
   pub min: Option<f32>,
 }
 
+#[derive(Serialize, Deserialize, Debug, Default)]
+pub struct PowerData {
+  pub avg: Option<f32>,
+  pub max: Option<f32>,
+  pub min: Option<f32>,
+  /// Energy consumed during the archive interval (Wh)
+  pub energy_wh: Option<f64>,
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 pub struct GpuData {
   pub gpu_name: String,
@@ -51,5 +153,9 @@ pub struct ProcessStatData {
   pub process_name: String,
   pub cpu_usage: f32,
   pub memory_usage: i32,
+  /// GPU usage (%), 0 for processes without a DRM client
+  pub gpu_usage: f32,
+  /// Dedicated GPU memory (MB)
+  pub gpu_memory_usage: i32,
   pub execution_sec: i32,
 }
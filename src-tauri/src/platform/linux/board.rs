@@ -0,0 +1,18 @@
+use crate::infrastructure::providers::{dmi_sys, smbios};
+use crate::models::hardware::BoardInfo;
+
+///
+/// Get motherboard, BIOS and chassis information
+///
+/// `/sys/class/dmi/id` is preferred; the raw SMBIOS tables are parsed when it is
+/// missing or empty.
+///
+pub fn get_board_info() -> Result<BoardInfo, String> {
+  if let Some(board) = dmi_sys::get_board_info() {
+    return Ok(board);
+  }
+
+  let structures = smbios::get_smbios_structures()?;
+  smbios::parse_board_info(&structures)
+    .ok_or_else(|| "No board information found in SMBIOS tables".to_string())
+}
@@ -0,0 +1,10 @@
+use crate::infrastructure::providers::cpu_topology;
+use crate::models::hardware::CpuTopology;
+
+///
+/// Get CPU topology through `/sys/devices/system/cpu` and `/proc/cpuinfo`
+///
+pub fn get_cpu_topology() -> Result<CpuTopology, String> {
+  cpu_topology::get_cpu_topology()
+    .ok_or_else(|| "No CPU topology found in /sys/devices/system/cpu".to_string())
+}
@@ -0,0 +1,35 @@
+use crate::infrastructure::providers::{hwmon, sysfs};
+use crate::models::hardware::FanInfo;
+use std::path::Path;
+
+///
+/// Get every fan exposed through `/sys/class/hwmon`
+///
+/// Covers motherboard Super I/O chips, laptop EC drivers and GPU drivers (amdgpu etc.)
+///
+pub async fn get_fans() -> Result<Vec<FanInfo>, String> {
+  let fans: Vec<FanInfo> = hwmon::get_hwmon_dirs(Path::new("/sys/class/hwmon"))
+    .into_iter()
+    .flat_map(|hwmon_dir| {
+      let chip = sysfs::read_attribute(&hwmon_dir, "name")
+        .unwrap_or_else(|| "unknown".to_string());
+      let is_gpu = hwmon::is_gpu_driver(&chip);
+
+      hwmon::read_fans(&hwmon_dir)
+        .into_iter()
+        .map(move |fan| FanInfo {
+          chip: chip.clone(),
+          label: fan.label,
+          rpm: fan.rpm,
+          pwm_percent: fan.pwm_percent,
+          is_gpu,
+        })
+    })
+    .collect();
+
+  if fans.is_empty() {
+    return Err("No fan sensor found in hwmon".to_string());
+  }
+
+  Ok(fans)
+}
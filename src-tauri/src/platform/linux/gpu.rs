@@ -1,39 +1,110 @@
+use crate::enums;
 use crate::infrastructure;
 use crate::models;
+use crate::utils;
+use std::collections::HashMap;
 
-pub async fn get_gpu_usage() -> Result<f32, String> {
-  let cards = infrastructure::providers::drm_sys::get_card_ids().await?;
+///
+/// Get the usage of the busiest card
+///
+/// On hybrid (iGPU + dGPU) and multi-GPU systems the first card is often idle,
+/// so every card is read and the highest usage wins. Intel (fdinfo delta) and
+/// NVIDIA (`nvidia-smi`) cards use the values sampled by the system monitor.
+///
+pub async fn get_gpu_usage(
+  nvidia: HashMap<u8, models::hardware::NvidiaGpuStatus>,
+  intel: HashMap<u8, f32>,
+) -> Result<f32, String> {
+  use infrastructure::providers::drm_sys;
+
+  let cards = drm_sys::get_card_ids().await?;
+  let mut busiest: Option<f64> = None;
 
   for card in cards {
     // TODO Also handle Vendor ID detection in infrastructure layer
-    match card.vendor_id.as_str() {
-      "0x1002" => {
-        if let Ok(usage) =
-          infrastructure::providers::drm_sys::get_amd_gpu_usage(card.id).await
-        {
-          return Ok((usage * 100.0) as f32);
-        }
-      }
-      "0x8086" => {
-        if let Ok(usage) = infrastructure::providers::drm_sys::get_intel_gpu_usage().await
-        {
-          return Ok((usage * 100.0) as f32);
-        }
+    let usage = match card.vendor_id.as_str() {
+      "0x1002" => infrastructure::providers::drm_sys::get_amd_gpu_usage(card.id).await,
+      "0x8086" => intel
+        .get(&(card.id as u8))
+        .map(|usage| (usage / 100.0).into())
+        .ok_or_else(|| format!("Failed to get Intel usage of card{}", card.id)),
+      "0x10de" => nvidia
+        .get(&(card.id as u8))
+        .and_then(|status| status.usage)
+        .map(|usage| (usage / 100.0).into())
+        .ok_or_else(|| format!("Failed to get NVIDIA usage of card{}", card.id)),
+      _ => continue,
+    };
+
+    if let Ok(usage) = usage {
+      busiest = Some(busiest.map_or(usage, |busiest| busiest.max(usage)));
+    }
+  }
+
+  busiest
+    .map(|usage| (usage * 100.0) as f32)
+    .ok_or_else(|| "Failed to get GPU usage on Linux (non-NVIDIA fallback)".to_string())
+}
+
+///
+/// Get temperatures of every hwmon sensor exposed by the DRM cards
+///
+/// Returns one `NameValue` per sensor (e.g. `card0 edge`, `card0 junction`, `card0 mem`).
+/// NVIDIA cards have no hwmon, so their driver temperature is reported as `cardN gpu`.
+///
+pub async fn get_gpu_temperature(
+  nvidia: HashMap<u8, models::hardware::NvidiaGpuStatus>,
+  temperature_unit: enums::settings::TemperatureUnit,
+) -> Result<Vec<models::hardware::NameValue>, String> {
+  use infrastructure::providers::{drm_sys, hwmon};
+
+  let card_ids = drm_sys::get_all_card_ids();
+  let mut temps = Vec::new();
+
+  for card_id in card_ids {
+    if let Some(temperature) = nvidia.get(&card_id).and_then(|status| status.temperature)
+    {
+      temps.push(models::hardware::NameValue {
+        name: format!("card{card_id} gpu"),
+        value: utils::formatter::format_temperature(
+          enums::settings::TemperatureUnit::Celsius,
+          temperature_unit.clone(),
+          temperature.round() as i32,
+        ),
+      });
+    }
+
+    for hwmon_dir in hwmon::get_card_hwmon_dirs(card_id) {
+      for sensor in hwmon::read_temperatures(&hwmon_dir) {
+        temps.push(models::hardware::NameValue {
+          name: format!("card{card_id} {}", sensor.label),
+          value: utils::formatter::format_temperature(
+            enums::settings::TemperatureUnit::Celsius,
+            temperature_unit.clone(),
+            sensor.value.round() as i32,
+          ),
+        });
       }
-      _ => {}
     }
   }
 
-  Err("Failed to get GPU usage on Linux (non-NVIDIA fallback)".to_string())
+  if temps.is_empty() {
+    return Err("No GPU temperature sensor found in hwmon".to_string());
+  }
+
+  Ok(temps)
 }
 
-pub async fn get_gpu_info() -> Result<Vec<models::hardware::GraphicInfo>, String> {
+pub async fn get_gpu_info(
+  nvidia: HashMap<u8, models::hardware::NvidiaGpuStatus>,
+) -> Result<Vec<models::hardware::GraphicInfo>, String> {
   use tokio::task::JoinSet;
 
   let card_ids = infrastructure::providers::drm_sys::get_all_card_ids();
   let mut join_set = JoinSet::new();
 
   for card_id in card_ids {
+    let nvidia_status = nvidia.get(&card_id).cloned();
     join_set.spawn(async move {
       match infrastructure::providers::drm_sys::detect_gpu_vendor(card_id) {
         infrastructure::providers::drm_sys::GpuVendor::Amd => {
@@ -42,6 +113,9 @@ pub async fn get_gpu_info() -> Result<Vec<models::hardware::GraphicInfo>, String
         infrastructure::providers::drm_sys::GpuVendor::Intel => {
           get_intel_graphic_info(card_id).await.ok()
         }
+        infrastructure::providers::drm_sys::GpuVendor::Nvidia => {
+          Some(get_nvidia_graphic_info(card_id, nvidia_status.as_ref()).await)
+        }
         _ => None,
       }
       .map(|info| (card_id, info)) // Attach card_id for sorting
@@ -64,13 +138,16 @@ pub async fn get_gpu_info() -> Result<Vec<models::hardware::GraphicInfo>, String
 async fn get_amd_graphic_info(
   card_id: u8,
 ) -> Result<models::hardware::GraphicInfo, String> {
-  const VENDOR_ID: &str = "1002";
-
-  let name =
-    infrastructure::providers::lspci::get_gpu_name_from_lspci_by_vendor_id(VENDOR_ID)
-      .unwrap_or_else(|| "Unknown AMD GPU".to_string());
+  let name = get_pci_gpu_name(card_id)
+    .await
+    .unwrap_or_else(|| "Unknown AMD GPU".to_string());
 
-  let clock = infrastructure::providers::kernel::read_pm_info_sclk(card_id).unwrap_or(0);
+  let metrics = infrastructure::providers::amdgpu::get_amd_gpu_metrics(card_id);
+  // debugfs `amdgpu_pm_info` is only readable by root
+  let clock = metrics
+    .core_clock_mhz
+    .or_else(|| infrastructure::providers::kernel::read_pm_info_sclk(card_id))
+    .unwrap_or(0);
   let memory_total =
     infrastructure::providers::drm_sys::read_vram_total_bytes(card_id).unwrap_or(0);
 
@@ -81,18 +158,93 @@ async fn get_amd_graphic_info(
     clock,
     memory_size: crate::utils::formatter::format_size(memory_total, 1),
     memory_size_dedicated: crate::utils::formatter::format_size(memory_total, 1),
+    pcie_link: infrastructure::providers::pci_sys::get_drm_card_pcie_link(card_id),
+    metrics: Some(metrics),
   })
 }
 
 pub async fn get_intel_graphic_info(
   card_id: u8,
 ) -> Result<models::hardware::GraphicInfo, String> {
+  use infrastructure::providers::drm_sys;
+
+  let card_dir = std::path::PathBuf::from(format!("/sys/class/drm/card{card_id}"));
+  let current_mhz = drm_sys::read_intel_gt_frequency_mhz(&card_dir, "cur");
+  let name = get_pci_gpu_name(card_id)
+    .await
+    .unwrap_or_else(|| "Intel Integrated Graphics".to_string());
+
   Ok(models::hardware::GraphicInfo {
     id: format!("card{card_id}"),
-    name: "Intel Integrated Graphics".into(),
+    name,
     vendor_name: "Intel".into(),
-    clock: 0, // Difficult to obtain. Set to 0 as unsupported
+    // Requested frequency, or the maximum when the driver does not expose it
+    clock: current_mhz
+      .or_else(|| drm_sys::read_intel_gt_frequency_mhz(&card_dir, "max"))
+      .unwrap_or(0),
     memory_size: "N/A".into(),
     memory_size_dedicated: "N/A".into(),
+    pcie_link: infrastructure::providers::pci_sys::get_drm_card_pcie_link(card_id),
+    metrics: Some(models::hardware::GpuMetrics {
+      card: format!("card{card_id}"),
+      core_clock_mhz: current_mhz,
+      ..Default::default()
+    }),
+  })
+}
+
+///
+/// NVIDIA card info from the cached `nvidia-smi` status
+///
+/// Without a status (no `nvidia-smi`, nouveau, not sampled yet) the card is
+/// still listed with its PCI name and link, but without metrics.
+///
+async fn get_nvidia_graphic_info(
+  card_id: u8,
+  status: Option<&models::hardware::NvidiaGpuStatus>,
+) -> models::hardware::GraphicInfo {
+  use infrastructure::providers::nvidia_smi;
+
+  let memory_size = status
+    .and_then(|status| status.memory_total_mb)
+    .map(|mb| {
+      crate::utils::formatter::format_size((mb as f64 * 1024.0 * 1024.0) as u64, 1)
+    })
+    .unwrap_or_else(|| "N/A".into());
+  let name = match status {
+    Some(status) => status.name.clone(),
+    None => get_pci_gpu_name(card_id)
+      .await
+      .unwrap_or_else(|| "Unknown NVIDIA GPU".to_string()),
+  };
+
+  models::hardware::GraphicInfo {
+    id: format!("card{card_id}"),
+    name,
+    vendor_name: "NVIDIA".into(),
+    clock: status.and_then(|status| status.core_clock_mhz).unwrap_or(0),
+    memory_size: memory_size.clone(),
+    memory_size_dedicated: memory_size,
+    pcie_link: infrastructure::providers::pci_sys::get_drm_card_pcie_link(card_id),
+    metrics: status
+      .map(|status| nvidia_smi::to_gpu_metrics(&format!("card{card_id}"), status)),
+  }
+}
+
+///
+/// Marketing name of the GPU behind a DRM card, from `pci.ids`
+///
+/// The first lookup parses the whole `pci.ids`, so it runs on the blocking
+/// thread pool.
+///
+async fn get_pci_gpu_name(card_id: u8) -> Option<String> {
+  use infrastructure::providers::pci_sys;
+
+  tokio::task::spawn_blocking(move || {
+    pci_sys::get_drm_card_pci_device(card_id)
+      .and_then(|device| pci_sys::gpu_display_name(&device))
   })
+  .await
+  .ok()
+  .flatten()
 }
@@ -26,6 +26,7 @@ pub fn get_memory_info() -> std::pin::Pin<
       total_slots: 0,
       memory_type: "Unknown".into(),
       is_detailed: false,
+      modules: Vec::new(),
     })
   })
 }
@@ -34,8 +35,19 @@ pub fn get_memory_info_detail() -> std::pin::Pin<
   Box<dyn std::future::Future<Output = Result<MemoryInfo, String>> + Send + 'static>,
 > {
   Box::pin(async {
-    let raw = providers::dmidecode::get_raw_dmidecode().await?;
-    let parsed = providers::dmidecode::parse_dmidecode_memory_info(&raw);
+    // Raw SMBIOS tables need no polkit prompt; `pkexec dmidecode` is the fallback
+    let parsed = match get_smbios_memory_info() {
+      Ok(info) => info,
+      Err(e) => {
+        log_warn!(
+          "Failed to read SMBIOS tables, falling back to dmidecode",
+          "get_memory_info_detail",
+          Some(e)
+        );
+        let raw = providers::dmidecode::get_raw_dmidecode().await?;
+        providers::dmidecode::parse_dmidecode_memory_info(&raw)
+      }
+    };
 
     if let Err(e) =
       linux::cache::write_cache(&parsed, &linux::cache::get_memory_cache_path())
@@ -51,7 +63,18 @@ pub fn get_memory_info_detail() -> std::pin::Pin<
   })
 }
 
+fn get_smbios_memory_info() -> Result<MemoryInfo, String> {
+  let structures = providers::smbios::get_smbios_structures()?;
+  providers::smbios::parse_memory_info(&structures)
+    .ok_or_else(|| "No memory devices in SMBIOS tables".to_string())
+}
+
 fn get_memory_info_cached_detail() -> std::io::Result<MemoryInfo> {
   let cache_path = linux::cache::get_memory_cache_path();
   linux::cache::read_cache(&cache_path)
 }
+
+pub fn get_memory_breakdown() -> Result<models::hardware::MemoryBreakdown, String> {
+  providers::procfs::get_memory_breakdown()
+    .map_err(|e| format!("Failed to read /proc/meminfo: {e}"))
+}
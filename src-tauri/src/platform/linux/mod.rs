@@ -1,14 +1,24 @@
 use crate::enums;
 use crate::enums::error::BackendError;
-use crate::models::hardware::{GraphicInfo, NetworkInfo};
-use crate::platform::traits::{GpuPlatform, MemoryPlatform, NetworkPlatform, Platform};
+use crate::models::hardware::{GraphicInfo, HardwareMonitorState, NetworkInfo};
+use crate::platform::traits::{
+  BoardPlatform, CpuPlatform, FanPlatform, GpuPlatform, MemoryPlatform, NetworkPlatform,
+  PciPlatform, Platform, PowerPlatform, StoragePlatform, ThermalPlatform,
+};
 use std::future::Future;
 use std::pin::Pin;
 
+pub mod board;
 pub mod cache;
+pub mod cpu;
+pub mod fan;
 pub mod gpu;
 pub mod memory;
 pub mod network;
+pub mod pci;
+pub mod power;
+pub mod storage;
+pub mod thermal;
 
 pub struct LinuxPlatform;
 
@@ -18,6 +28,18 @@ impl LinuxPlatform {
   }
 }
 
+impl CpuPlatform for LinuxPlatform {
+  fn get_cpu_topology(&self) -> Result<crate::models::hardware::CpuTopology, String> {
+    cpu::get_cpu_topology()
+  }
+}
+
+impl BoardPlatform for LinuxPlatform {
+  fn get_board_info(&self) -> Result<crate::models::hardware::BoardInfo, String> {
+    board::get_board_info()
+  }
+}
+
 impl MemoryPlatform for LinuxPlatform {
   fn get_memory_info(
     &self,
@@ -42,18 +64,33 @@ impl MemoryPlatform for LinuxPlatform {
   > {
     memory::get_memory_info_detail()
   }
+
+  fn get_memory_breakdown(
+    &self,
+  ) -> Result<crate::models::hardware::MemoryBreakdown, String> {
+    memory::get_memory_breakdown()
+  }
 }
 
 impl GpuPlatform for LinuxPlatform {
   fn get_gpu_usage(
     &self,
+    state: &HardwareMonitorState,
   ) -> Pin<Box<dyn Future<Output = Result<f32, String>> + Send + '_>> {
-    Box::pin(gpu::get_gpu_usage())
+    use crate::infrastructure::providers::drm_sys;
+
+    let nvidia = state.nvidia_gpu_statuses.lock().unwrap().clone();
+    let intel = drm_sys::get_intel_gpu_usages(
+      &state.drm_clients.lock().unwrap(),
+      &drm_sys::get_all_card_ids(),
+    );
+    Box::pin(gpu::get_gpu_usage(nvidia, intel))
   }
 
   fn get_gpu_temperature(
     &self,
-    _temperature_unit: enums::settings::TemperatureUnit,
+    state: &HardwareMonitorState,
+    temperature_unit: enums::settings::TemperatureUnit,
   ) -> Pin<
     Box<
       dyn Future<Output = Result<Vec<crate::models::hardware::NameValue>, String>>
@@ -61,13 +98,16 @@ impl GpuPlatform for LinuxPlatform {
         + '_,
     >,
   > {
-    Box::pin(async { Err("Not implemented".to_string()) })
+    let nvidia = state.nvidia_gpu_statuses.lock().unwrap().clone();
+    Box::pin(gpu::get_gpu_temperature(nvidia, temperature_unit))
   }
 
   fn get_gpu_info(
     &self,
+    state: &HardwareMonitorState,
   ) -> Pin<Box<dyn Future<Output = Result<Vec<GraphicInfo>, String>> + Send + '_>> {
-    Box::pin(gpu::get_gpu_info())
+    let nvidia = state.nvidia_gpu_statuses.lock().unwrap().clone();
+    Box::pin(gpu::get_gpu_info(nvidia))
   }
 }
 
@@ -77,4 +117,81 @@ impl NetworkPlatform for LinuxPlatform {
   }
 }
 
+impl ThermalPlatform for LinuxPlatform {
+  fn get_cpu_temperature(
+    &self,
+    temperature_unit: enums::settings::TemperatureUnit,
+  ) -> Pin<
+    Box<
+      dyn Future<Output = Result<Vec<crate::models::hardware::NameValue>, String>>
+        + Send
+        + '_,
+    >,
+  > {
+    Box::pin(thermal::get_cpu_temperature(temperature_unit))
+  }
+}
+
+impl FanPlatform for LinuxPlatform {
+  fn get_fans(
+    &self,
+  ) -> Pin<
+    Box<
+      dyn Future<Output = Result<Vec<crate::models::hardware::FanInfo>, String>>
+        + Send
+        + '_,
+    >,
+  > {
+    Box::pin(fan::get_fans())
+  }
+}
+
+impl PowerPlatform for LinuxPlatform {
+  fn get_power_supply_info(
+    &self,
+  ) -> Result<crate::models::hardware::PowerSupplyInfo, String> {
+    power::get_power_supply_info()
+  }
+}
+
+impl PciPlatform for LinuxPlatform {
+  fn get_pci_devices(
+    &self,
+  ) -> Pin<
+    Box<
+      dyn Future<Output = Result<Vec<crate::models::hardware::PciDevice>, String>>
+        + Send
+        + '_,
+    >,
+  > {
+    Box::pin(pci::get_pci_devices())
+  }
+}
+
+impl StoragePlatform for LinuxPlatform {
+  fn get_disk_health(
+    &self,
+  ) -> Pin<
+    Box<
+      dyn Future<Output = Result<Vec<crate::models::hardware::DiskHealth>, String>>
+        + Send
+        + '_,
+    >,
+  > {
+    Box::pin(storage::get_disk_health())
+  }
+
+  fn get_block_devices(
+    &self,
+  ) -> Pin<
+    Box<
+      dyn Future<Output = Result<Vec<crate::models::hardware::BlockDevice>, String>>
+        + Send
+        + '_,
+    >,
+  > {
+    Box::pin(storage::get_block_devices())
+  }
+}
+
 impl Platform for LinuxPlatform {}
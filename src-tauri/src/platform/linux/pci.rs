@@ -0,0 +1,20 @@
+use crate::infrastructure::providers::pci_sys;
+use crate::models::hardware::PciDevice;
+
+///
+/// Get every PCI device through `/sys/bus/pci/devices`
+///
+/// Walking sysfs (and parsing `pci.ids` on the first call) blocks, so it runs on the
+/// blocking thread pool.
+///
+pub async fn get_pci_devices() -> Result<Vec<PciDevice>, String> {
+  let devices = tokio::task::spawn_blocking(pci_sys::get_pci_devices)
+    .await
+    .map_err(|e| format!("Failed to join PCI task: {e}"))?;
+
+  if devices.is_empty() {
+    return Err("No PCI device found in /sys/bus/pci/devices".to_string());
+  }
+
+  Ok(devices)
+}
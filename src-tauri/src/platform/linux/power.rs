@@ -0,0 +1,9 @@
+use crate::infrastructure::providers::power_supply;
+use crate::models::hardware::PowerSupplyInfo;
+
+///
+/// Get AC adapter and battery status through `/sys/class/power_supply`
+///
+pub fn get_power_supply_info() -> Result<PowerSupplyInfo, String> {
+  Ok(power_supply::get_power_supply_info())
+}
@@ -0,0 +1,72 @@
+use crate::infrastructure::providers::{block_sys, procfs, smartctl};
+use crate::models::hardware::{BlockDevice, DiskHealth};
+use std::path::Path;
+
+///
+/// Get health of every physical disk
+///
+/// SMART data comes from `smartctl`, which needs root. Without it only the
+/// hwmon temperature is reported and the verdict stays `Unknown`.
+/// `smartctl` blocks for every disk, so this runs on the blocking thread pool.
+///
+pub async fn get_disk_health() -> Result<Vec<DiskHealth>, String> {
+  tokio::task::spawn_blocking(read_disk_health)
+    .await
+    .map_err(|e| format!("Failed to join disk health task: {e}"))?
+}
+
+fn read_disk_health() -> Result<Vec<DiskHealth>, String> {
+  // Mount points are informative only, so unreadable /proc/mounts is not an error
+  let mounts = procfs::get_mounts().unwrap_or_default();
+  let disks = block_sys::get_block_devices(&mounts);
+  if disks.is_empty() {
+    return Err("No physical disk found in /sys/block".to_string());
+  }
+
+  Ok(
+    disks
+      .into_iter()
+      .map(|disk| {
+        let mut health = smartctl::get_smartctl_json(&disk.name)
+          .and_then(|json| smartctl::parse_smartctl_json(&disk.name, &json))
+          .unwrap_or_else(|_| DiskHealth {
+            device: disk.name.clone(),
+            ..Default::default()
+          });
+
+        if health.temperature.is_none() {
+          health.temperature =
+            block_sys::read_disk_temperature(Path::new("/sys/block"), &disk.name);
+        }
+        health.mount_points = block_sys::mount_points_of(&disk);
+        health.status = smartctl::health_status(&health);
+
+        health
+      })
+      .collect(),
+  )
+}
+
+///
+/// Get the block device tree (disks, partitions, dm / md layers and mounts)
+///
+/// Walking sysfs and resolving every mount source blocks, so it runs on the
+/// blocking thread pool.
+///
+pub async fn get_block_devices() -> Result<Vec<BlockDevice>, String> {
+  tokio::task::spawn_blocking(read_block_devices)
+    .await
+    .map_err(|e| format!("Failed to join block device task: {e}"))?
+}
+
+fn read_block_devices() -> Result<Vec<BlockDevice>, String> {
+  let mounts =
+    procfs::get_mounts().map_err(|e| format!("Failed to read /proc/mounts: {e}"))?;
+  let devices = block_sys::get_block_devices(&mounts);
+
+  if devices.is_empty() {
+    return Err("No physical disk found in /sys/block".to_string());
+  }
+
+  Ok(devices)
+}
@@ -0,0 +1,31 @@
+use crate::enums;
+use crate::infrastructure::providers;
+use crate::models::hardware::NameValue;
+use crate::utils;
+
+///
+/// Get CPU temperatures from hwmon (coretemp / k10temp) or thermal zones
+///
+pub async fn get_cpu_temperature(
+  temperature_unit: enums::settings::TemperatureUnit,
+) -> Result<Vec<NameValue>, String> {
+  let sensors = providers::thermal_sys::get_cpu_temperatures();
+
+  if sensors.is_empty() {
+    return Err("No CPU temperature sensor found".to_string());
+  }
+
+  Ok(
+    sensors
+      .into_iter()
+      .map(|sensor| NameValue {
+        name: sensor.label,
+        value: utils::formatter::format_temperature(
+          enums::settings::TemperatureUnit::Celsius,
+          temperature_unit.clone(),
+          sensor.value.round() as i32,
+        ),
+      })
+      .collect(),
+  )
+}
@@ -54,6 +54,7 @@ pub fn get_memory_info() -> Result<MemoryInfo, String> {
     total_slots,
     memory_type,
     is_detailed: has_any_detail,
+    modules: Vec::new(),
   })
 }
 
@@ -1,7 +1,12 @@
 use crate::enums::error::BackendError;
 use crate::enums::settings::TemperatureUnit;
-use crate::models::hardware::{GraphicInfo, MemoryInfo, NetworkInfo};
-use crate::platform::traits::{GpuPlatform, MemoryPlatform, NetworkPlatform, Platform};
+use crate::models::hardware::{
+  GraphicInfo, HardwareMonitorState, MemoryInfo, NetworkInfo,
+};
+use crate::platform::traits::{
+  BoardPlatform, CpuPlatform, FanPlatform, GpuPlatform, MemoryPlatform, NetworkPlatform,
+  PciPlatform, Platform, PowerPlatform, StoragePlatform, ThermalPlatform,
+};
 use std::future::Future;
 use std::pin::Pin;
 use tauri::async_runtime;
@@ -16,6 +21,20 @@ impl MacOSPlatform {
   }
 }
 
+impl CpuPlatform for MacOSPlatform {
+  fn get_cpu_topology(&self) -> Result<crate::models::hardware::CpuTopology, String> {
+    // macOS is not supported yet (build-only stub)
+    Err("get_cpu_topology is not implemented for MacOSPlatform".to_string())
+  }
+}
+
+impl BoardPlatform for MacOSPlatform {
+  fn get_board_info(&self) -> Result<crate::models::hardware::BoardInfo, String> {
+    // macOS is not supported yet (build-only stub)
+    Err("get_board_info is not implemented for MacOSPlatform".to_string())
+  }
+}
+
 impl MemoryPlatform for MacOSPlatform {
   fn get_memory_info(
     &self,
@@ -35,11 +54,19 @@ impl MemoryPlatform for MacOSPlatform {
       Err("get_memory_info_detail is not implemented for MacOSPlatform".to_string())
     })
   }
+
+  fn get_memory_breakdown(
+    &self,
+  ) -> Result<crate::models::hardware::MemoryBreakdown, String> {
+    // macOS is not supported yet (build-only stub)
+    Err("get_memory_breakdown is not implemented for MacOSPlatform".to_string())
+  }
 }
 
 impl GpuPlatform for MacOSPlatform {
   fn get_gpu_usage(
     &self,
+    _state: &HardwareMonitorState,
   ) -> Pin<Box<dyn Future<Output = Result<f32, String>> + Send + '_>> {
     Box::pin(async {
       // macOS is not supported yet (build-only stub)
@@ -49,6 +76,7 @@ impl GpuPlatform for MacOSPlatform {
 
   fn get_gpu_temperature(
     &self,
+    _state: &HardwareMonitorState,
     _temperature_unit: TemperatureUnit,
   ) -> Pin<
     Box<
@@ -65,6 +93,7 @@ impl GpuPlatform for MacOSPlatform {
 
   fn get_gpu_info(
     &self,
+    _state: &HardwareMonitorState,
   ) -> Pin<Box<dyn Future<Output = Result<Vec<GraphicInfo>, String>> + Send + '_>> {
     Box::pin(async {
       // macOS is not supported yet (build-only stub)
@@ -79,4 +108,97 @@ impl NetworkPlatform for MacOSPlatform {
   }
 }
 
+impl ThermalPlatform for MacOSPlatform {
+  fn get_cpu_temperature(
+    &self,
+    _temperature_unit: TemperatureUnit,
+  ) -> Pin<
+    Box<
+      dyn Future<Output = Result<Vec<crate::models::hardware::NameValue>, String>>
+        + Send
+        + '_,
+    >,
+  > {
+    Box::pin(async {
+      // macOS is not supported yet (build-only stub)
+      Err("get_cpu_temperature is not implemented for MacOSPlatform".to_string())
+    })
+  }
+}
+
+impl FanPlatform for MacOSPlatform {
+  fn get_fans(
+    &self,
+  ) -> Pin<
+    Box<
+      dyn Future<Output = Result<Vec<crate::models::hardware::FanInfo>, String>>
+        + Send
+        + '_,
+    >,
+  > {
+    Box::pin(async {
+      // macOS is not supported yet (build-only stub)
+      Err("get_fans is not implemented for MacOSPlatform".to_string())
+    })
+  }
+}
+
+impl PowerPlatform for MacOSPlatform {
+  fn get_power_supply_info(
+    &self,
+  ) -> Result<crate::models::hardware::PowerSupplyInfo, String> {
+    // macOS is not supported yet (build-only stub)
+    Err("get_power_supply_info is not implemented for MacOSPlatform".to_string())
+  }
+}
+
+impl PciPlatform for MacOSPlatform {
+  fn get_pci_devices(
+    &self,
+  ) -> Pin<
+    Box<
+      dyn Future<Output = Result<Vec<crate::models::hardware::PciDevice>, String>>
+        + Send
+        + '_,
+    >,
+  > {
+    Box::pin(async {
+      // macOS is not supported yet (build-only stub)
+      Err("get_pci_devices is not implemented for MacOSPlatform".to_string())
+    })
+  }
+}
+
+impl StoragePlatform for MacOSPlatform {
+  fn get_disk_health(
+    &self,
+  ) -> Pin<
+    Box<
+      dyn Future<Output = Result<Vec<crate::models::hardware::DiskHealth>, String>>
+        + Send
+        + '_,
+    >,
+  > {
+    Box::pin(async {
+      // macOS is not supported yet (build-only stub)
+      Err("get_disk_health is not implemented for MacOSPlatform".to_string())
+    })
+  }
+
+  fn get_block_devices(
+    &self,
+  ) -> Pin<
+    Box<
+      dyn Future<Output = Result<Vec<crate::models::hardware::BlockDevice>, String>>
+        + Send
+        + '_,
+    >,
+  > {
+    Box::pin(async {
+      // macOS is not supported yet (build-only stub)
+      Err("get_block_devices is not implemented for MacOSPlatform".to_string())
+    })
+  }
+}
+
 impl Platform for MacOSPlatform {}
@@ -4,6 +4,18 @@ use crate::models;
 use std::future::Future;
 use std::pin::Pin;
 
+/// Trait that defines platform-specific CPU operations
+pub trait CpuPlatform: Send + Sync {
+  /// Get core types, SMT siblings and cache sharing of every logical CPU
+  fn get_cpu_topology(&self) -> Result<models::hardware::CpuTopology, String>;
+}
+
+/// Trait that defines platform-specific motherboard / firmware operations
+pub trait BoardPlatform: Send + Sync {
+  /// Get motherboard, BIOS and chassis information
+  fn get_board_info(&self) -> Result<models::hardware::BoardInfo, String>;
+}
+
 /// Trait that defines platform-specific memory operations
 pub trait MemoryPlatform: Send + Sync {
   /// Get basic memory information
@@ -19,6 +31,9 @@ pub trait MemoryPlatform: Send + Sync {
   ) -> Pin<
     Box<dyn Future<Output = Result<models::hardware::MemoryInfo, String>> + Send + '_>,
   >;
+
+  /// Get breakdown of memory usage (cache / buffers / slab / swap ...)
+  fn get_memory_breakdown(&self) -> Result<models::hardware::MemoryBreakdown, String>;
 }
 
 /// Trait that defines platform-specific GPU operations
@@ -26,11 +41,13 @@ pub trait GpuPlatform: Send + Sync {
   /// Get GPU usage
   fn get_gpu_usage(
     &self,
+    state: &models::hardware::HardwareMonitorState,
   ) -> Pin<Box<dyn Future<Output = Result<f32, String>> + Send + '_>>;
 
   /// Get GPU temperature
   fn get_gpu_temperature(
     &self,
+    state: &models::hardware::HardwareMonitorState,
     temperature_unit: enums::settings::TemperatureUnit,
   ) -> Pin<
     Box<
@@ -41,6 +58,7 @@ pub trait GpuPlatform: Send + Sync {
   /// Get GPU information
   fn get_gpu_info(
     &self,
+    state: &models::hardware::HardwareMonitorState,
   ) -> Pin<
     Box<
       dyn Future<Output = Result<Vec<models::hardware::GraphicInfo>, String>> + Send + '_,
@@ -57,5 +75,79 @@ pub trait NetworkPlatform: Send + Sync {
   ) -> Result<Vec<crate::models::hardware::NetworkInfo>, BackendError>;
 }
 
+/// Trait that defines platform-specific thermal operations
+pub trait ThermalPlatform: Send + Sync {
+  /// Get CPU temperatures (package / per-core sensors)
+  fn get_cpu_temperature(
+    &self,
+    temperature_unit: enums::settings::TemperatureUnit,
+  ) -> Pin<
+    Box<
+      dyn Future<Output = Result<Vec<models::hardware::NameValue>, String>> + Send + '_,
+    >,
+  >;
+}
+
+/// Trait that defines platform-specific fan operations
+pub trait FanPlatform: Send + Sync {
+  /// Get every fan exposed by the system (CPU / chassis / GPU)
+  fn get_fans(
+    &self,
+  ) -> Pin<
+    Box<dyn Future<Output = Result<Vec<models::hardware::FanInfo>, String>> + Send + '_>,
+  >;
+}
+
+/// Trait that defines platform-specific power supply operations
+pub trait PowerPlatform: Send + Sync {
+  /// Get AC adapter and battery status
+  fn get_power_supply_info(&self) -> Result<models::hardware::PowerSupplyInfo, String>;
+}
+
+/// Trait that defines platform-specific PCI operations
+pub trait PciPlatform: Send + Sync {
+  /// Get every PCI device with vendor / device names
+  fn get_pci_devices(
+    &self,
+  ) -> Pin<
+    Box<
+      dyn Future<Output = Result<Vec<models::hardware::PciDevice>, String>> + Send + '_,
+    >,
+  >;
+}
+
+/// Trait that defines platform-specific storage operations
+pub trait StoragePlatform: Send + Sync {
+  /// Get SMART / NVMe health of every physical disk
+  fn get_disk_health(
+    &self,
+  ) -> Pin<
+    Box<
+      dyn Future<Output = Result<Vec<models::hardware::DiskHealth>, String>> + Send + '_,
+    >,
+  >;
+
+  /// Get the block device tree of every physical disk
+  fn get_block_devices(
+    &self,
+  ) -> Pin<
+    Box<
+      dyn Future<Output = Result<Vec<models::hardware::BlockDevice>, String>> + Send + '_,
+    >,
+  >;
+}
+
 /// Trait that integrates all platform functionality
-pub trait Platform: MemoryPlatform + GpuPlatform + NetworkPlatform {}
+pub trait Platform:
+  CpuPlatform
+  + BoardPlatform
+  + MemoryPlatform
+  + GpuPlatform
+  + NetworkPlatform
+  + ThermalPlatform
+  + FanPlatform
+  + PowerPlatform
+  + PciPlatform
+  + StoragePlatform
+{
+}
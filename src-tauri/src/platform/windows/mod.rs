@@ -1,7 +1,10 @@
 use crate::enums::error::BackendError;
 use crate::enums::settings::TemperatureUnit;
-use crate::models::hardware::{GraphicInfo, NetworkInfo};
-use crate::platform::traits::{GpuPlatform, MemoryPlatform, NetworkPlatform, Platform};
+use crate::models::hardware::{GraphicInfo, HardwareMonitorState, NetworkInfo};
+use crate::platform::traits::{
+  BoardPlatform, CpuPlatform, FanPlatform, GpuPlatform, MemoryPlatform, NetworkPlatform,
+  PciPlatform, Platform, PowerPlatform, StoragePlatform, ThermalPlatform,
+};
 
 use std::future::Future;
 use std::pin::Pin;
@@ -18,6 +21,18 @@ impl WindowsPlatform {
   }
 }
 
+impl CpuPlatform for WindowsPlatform {
+  fn get_cpu_topology(&self) -> Result<crate::models::hardware::CpuTopology, String> {
+    Err("CPU topology is not implemented yet".to_string())
+  }
+}
+
+impl BoardPlatform for WindowsPlatform {
+  fn get_board_info(&self) -> Result<crate::models::hardware::BoardInfo, String> {
+    Err("Board information is not implemented yet".to_string())
+  }
+}
+
 impl MemoryPlatform for WindowsPlatform {
   fn get_memory_info(
     &self,
@@ -42,17 +57,25 @@ impl MemoryPlatform for WindowsPlatform {
   > {
     memory::get_memory_info_detail()
   }
+
+  fn get_memory_breakdown(
+    &self,
+  ) -> Result<crate::models::hardware::MemoryBreakdown, String> {
+    Err("Memory breakdown is not implemented yet".to_string())
+  }
 }
 
 impl GpuPlatform for WindowsPlatform {
   fn get_gpu_usage(
     &self,
+    _state: &HardwareMonitorState,
   ) -> Pin<Box<dyn Future<Output = Result<f32, String>> + Send + '_>> {
     Box::pin(gpu::get_gpu_usage())
   }
 
   fn get_gpu_temperature(
     &self,
+    _state: &HardwareMonitorState,
     temperature_unit: TemperatureUnit,
   ) -> Pin<
     Box<
@@ -66,6 +89,7 @@ impl GpuPlatform for WindowsPlatform {
 
   fn get_gpu_info(
     &self,
+    _state: &HardwareMonitorState,
   ) -> Pin<Box<dyn Future<Output = Result<Vec<GraphicInfo>, String>> + Send + '_>> {
     Box::pin(gpu::get_gpu_info())
   }
@@ -77,4 +101,81 @@ impl NetworkPlatform for WindowsPlatform {
   }
 }
 
+impl ThermalPlatform for WindowsPlatform {
+  fn get_cpu_temperature(
+    &self,
+    _temperature_unit: TemperatureUnit,
+  ) -> Pin<
+    Box<
+      dyn Future<Output = Result<Vec<crate::models::hardware::NameValue>, String>>
+        + Send
+        + '_,
+    >,
+  > {
+    Box::pin(async { Err("CPU temperature is not implemented yet".to_string()) })
+  }
+}
+
+impl FanPlatform for WindowsPlatform {
+  fn get_fans(
+    &self,
+  ) -> Pin<
+    Box<
+      dyn Future<Output = Result<Vec<crate::models::hardware::FanInfo>, String>>
+        + Send
+        + '_,
+    >,
+  > {
+    Box::pin(async { Err("Fan speed is not implemented yet".to_string()) })
+  }
+}
+
+impl PowerPlatform for WindowsPlatform {
+  fn get_power_supply_info(
+    &self,
+  ) -> Result<crate::models::hardware::PowerSupplyInfo, String> {
+    Err("Power supply is not implemented yet".to_string())
+  }
+}
+
+impl PciPlatform for WindowsPlatform {
+  fn get_pci_devices(
+    &self,
+  ) -> Pin<
+    Box<
+      dyn Future<Output = Result<Vec<crate::models::hardware::PciDevice>, String>>
+        + Send
+        + '_,
+    >,
+  > {
+    Box::pin(async { Err("PCI device list is not implemented yet".to_string()) })
+  }
+}
+
+impl StoragePlatform for WindowsPlatform {
+  fn get_disk_health(
+    &self,
+  ) -> Pin<
+    Box<
+      dyn Future<Output = Result<Vec<crate::models::hardware::DiskHealth>, String>>
+        + Send
+        + '_,
+    >,
+  > {
+    Box::pin(async { Err("Disk health is not implemented yet".to_string()) })
+  }
+
+  fn get_block_devices(
+    &self,
+  ) -> Pin<
+    Box<
+      dyn Future<Output = Result<Vec<crate::models::hardware::BlockDevice>, String>>
+        + Send
+        + '_,
+    >,
+  > {
+    Box::pin(async { Err("Block device tree is not implemented yet".to_string()) })
+  }
+}
+
 impl Platform for WindowsPlatform {}
@@ -10,16 +10,26 @@ const PROCESS_RECORD_LIMIT: usize = 5;
 enum ProcessRankingMetric {
   Cpu,
   Memory,
+  Gpu,
   ExecutionTime,
 }
 
 impl ProcessRankingMetric {
-  const ALL: [Self; 3] = [Self::Cpu, Self::Memory, Self::ExecutionTime];
+  const ALL: [Self; 4] = [Self::Cpu, Self::Memory, Self::Gpu, Self::ExecutionTime];
 }
 
 /// Hardware archive service that manages periodic data archiving to database.
 pub struct ArchiveService;
 
+/// Aggregated metrics stored in `DATA_ARCHIVE`
+struct HardwareMetrics {
+  cpu: models::hardware_archive::HardwareData,
+  memory: models::hardware_archive::HardwareData,
+  battery: models::hardware_archive::HardwareData,
+  battery_power: models::hardware_archive::HardwareData,
+  cpu_power: models::hardware_archive::PowerData,
+}
+
 /// Statistics calculator for hardware metrics
 struct StatsCalculator;
 
@@ -30,10 +40,17 @@ struct GpuMetricsCollector<'a> {
   memory_histories: &'a Arc<Mutex<HashMap<String, VecDeque<i32>>>>,
 }
 
+/// Per-process histories keyed by PID
+type ProcessHistories = Arc<Mutex<HashMap<sysinfo::Pid, VecDeque<f32>>>>;
+
 /// Process statistics collector and ranker
 struct ProcessStatsCollector<'a> {
-  cpu_histories: &'a Arc<Mutex<HashMap<sysinfo::Pid, VecDeque<f32>>>>,
-  memory_histories: &'a Arc<Mutex<HashMap<sysinfo::Pid, VecDeque<f32>>>>,
+  cpu_histories: &'a ProcessHistories,
+  memory_histories: &'a ProcessHistories,
+  #[cfg(target_os = "linux")]
+  gpu_histories: &'a ProcessHistories,
+  #[cfg(target_os = "linux")]
+  gpu_memory_histories: &'a ProcessHistories,
 }
 
 impl ArchiveService {
@@ -70,43 +87,79 @@ impl ArchiveService {
   ) {
     let hardware_data = Self::collect_hardware_metrics(resources);
     let gpu_data = GpuMetricsCollector::new(
-      &resources.nv_gpu_usage_histories,
-      &resources.nv_gpu_temperature_histories,
-      &resources.nv_gpu_dedicated_memory_histories,
+      &resources.gpu_usage_histories,
+      &resources.gpu_temperature_histories,
+      &resources.gpu_dedicated_memory_histories,
     )
     .collect_all();
     let process_stats = ProcessStatsCollector::new(
       &resources.process_cpu_histories,
       &resources.process_memory_histories,
+      #[cfg(target_os = "linux")]
+      &resources.process_gpu_histories,
+      #[cfg(target_os = "linux")]
+      &resources.process_gpu_memory_histories,
     )
     .collect_and_rank();
 
-    Self::persist_all_data(hardware_data.0, hardware_data.1, gpu_data, process_stats)
-      .await;
+    Self::persist_all_data(hardware_data, gpu_data, process_stats).await;
   }
 
-  /// Collects CPU and memory metrics
+  /// Collects CPU, memory and battery metrics
   fn collect_hardware_metrics(
     resources: &models::hardware_archive::MonitorResources,
-  ) -> (
-    models::hardware_archive::HardwareData,
-    models::hardware_archive::HardwareData,
-  ) {
-    (
-      StatsCalculator::calculate_hardware_stats(&resources.cpu_history),
-      StatsCalculator::calculate_hardware_stats(&resources.memory_history),
-    )
+  ) -> HardwareMetrics {
+    HardwareMetrics {
+      cpu: StatsCalculator::calculate_hardware_stats(&resources.cpu_history),
+      memory: StatsCalculator::calculate_hardware_stats(&resources.memory_history),
+      #[cfg(target_os = "linux")]
+      battery: StatsCalculator::calculate_hardware_stats(&resources.battery_history),
+      #[cfg(target_os = "linux")]
+      battery_power: StatsCalculator::calculate_hardware_stats(
+        &resources.battery_power_history,
+      ),
+      #[cfg(target_os = "linux")]
+      cpu_power: Self::collect_cpu_power_metrics(resources),
+      // Battery and RAPL are only sampled on Linux
+      #[cfg(not(target_os = "linux"))]
+      battery: Default::default(),
+      #[cfg(not(target_os = "linux"))]
+      battery_power: Default::default(),
+      #[cfg(not(target_os = "linux"))]
+      cpu_power: Default::default(),
+    }
+  }
+
+  /// Collects CPU package power and the energy consumed since the last archive
+  #[cfg(target_os = "linux")]
+  fn collect_cpu_power_metrics(
+    resources: &models::hardware_archive::MonitorResources,
+  ) -> models::hardware_archive::PowerData {
+    let stats = StatsCalculator::calculate_hardware_stats(&resources.cpu_power_history);
+    let joules = std::mem::take(&mut *resources.cpu_energy_joules.lock().unwrap());
+
+    models::hardware_archive::PowerData {
+      energy_wh: stats.avg.map(|_| joules / 3600.0),
+      avg: stats.avg,
+      max: stats.max,
+      min: stats.min,
+    }
   }
 
   /// Persists all collected data to the database
   async fn persist_all_data(
-    cpu_data: models::hardware_archive::HardwareData,
-    memory_data: models::hardware_archive::HardwareData,
+    hardware_data: HardwareMetrics,
     gpu_data_list: Vec<models::hardware_archive::GpuData>,
     process_stats: Vec<models::hardware_archive::ProcessStatData>,
   ) {
     Self::persist_with_error_handling(
-      database::hardware_archive::insert(cpu_data, memory_data),
+      database::hardware_archive::insert(
+        hardware_data.cpu,
+        hardware_data.memory,
+        hardware_data.battery,
+        hardware_data.battery_power,
+        hardware_data.cpu_power,
+      ),
       "hardware archive data",
     )
     .await;
@@ -293,12 +346,18 @@ impl<'a> GpuMetricsCollector<'a> {
 
 impl<'a> ProcessStatsCollector<'a> {
   fn new(
-    cpu_histories: &'a Arc<Mutex<HashMap<sysinfo::Pid, VecDeque<f32>>>>,
-    memory_histories: &'a Arc<Mutex<HashMap<sysinfo::Pid, VecDeque<f32>>>>,
+    cpu_histories: &'a ProcessHistories,
+    memory_histories: &'a ProcessHistories,
+    #[cfg(target_os = "linux")] gpu_histories: &'a ProcessHistories,
+    #[cfg(target_os = "linux")] gpu_memory_histories: &'a ProcessHistories,
   ) -> Self {
     Self {
       cpu_histories,
       memory_histories,
+      #[cfg(target_os = "linux")]
+      gpu_histories,
+      #[cfg(target_os = "linux")]
+      gpu_memory_histories,
     }
   }
 
@@ -321,12 +380,31 @@ impl<'a> ProcessStatsCollector<'a> {
   ) -> Vec<models::hardware_archive::ProcessStatData> {
     let cpu_histories = self.cpu_histories.lock().unwrap();
     let mem_histories = self.memory_histories.lock().unwrap();
+    #[cfg(target_os = "linux")]
+    let gpu_histories = self.gpu_histories.lock().unwrap();
+    #[cfg(target_os = "linux")]
+    let gpu_memory_histories = self.gpu_memory_histories.lock().unwrap();
 
     cpu_histories
       .iter()
       .filter_map(|(pid, cpu_history)| {
         mem_histories.get(pid).and_then(|mem_history| {
-          self.create_process_stat(*pid, cpu_history, mem_history, *num_cores, sys)
+          // Processes without a DRM client have no GPU history
+          #[cfg(target_os = "linux")]
+          let gpu_averages = (
+            self.calculate_gpu_average(gpu_histories.get(pid)),
+            self.calculate_gpu_average(gpu_memory_histories.get(pid)),
+          );
+          #[cfg(not(target_os = "linux"))]
+          let gpu_averages = (0.0, 0.0);
+          self.create_process_stat(
+            *pid,
+            cpu_history,
+            mem_history,
+            gpu_averages,
+            *num_cores,
+            sys,
+          )
         })
       })
       .collect()
@@ -337,12 +415,13 @@ impl<'a> ProcessStatsCollector<'a> {
     pid: sysinfo::Pid,
     cpu_history: &VecDeque<f32>,
     mem_history: &VecDeque<f32>,
+    (gpu_avg, gpu_mem_avg): (f32, f32),
     num_cores: f32,
     sys: &sysinfo::System,
   ) -> Option<models::hardware_archive::ProcessStatData> {
     let (cpu_avg, mem_avg) = self.calculate_process_averages(cpu_history, mem_history)?;
 
-    if cpu_avg == 0.0 && mem_avg == 0.0 {
+    if cpu_avg == 0.0 && mem_avg == 0.0 && gpu_avg == 0.0 {
       return None;
     }
 
@@ -358,10 +437,22 @@ impl<'a> ProcessStatsCollector<'a> {
       process_name: process.name().to_string_lossy().into_owned(),
       cpu_usage: cpu_avg / num_cores,
       memory_usage: mem_avg.round() as i32,
+      gpu_usage: gpu_avg,
+      gpu_memory_usage: gpu_mem_avg.round() as i32,
       execution_sec: exec_time,
     })
   }
 
+  #[cfg(target_os = "linux")]
+  fn calculate_gpu_average(&self, history: Option<&VecDeque<f32>>) -> f32 {
+    match history {
+      Some(history) if !history.is_empty() => {
+        history.iter().sum::<f32>() / history.len() as f32
+      }
+      _ => 0.0,
+    }
+  }
+
   fn calculate_process_averages(
     &self,
     cpu_history: &VecDeque<f32>,
@@ -412,6 +503,9 @@ impl<'a> ProcessStatsCollector<'a> {
       ProcessRankingMetric::Memory => {
         stats.sort_by(|a, b| b.memory_usage.cmp(&a.memory_usage));
       }
+      ProcessRankingMetric::Gpu => {
+        stats.sort_by(|a, b| b.gpu_usage.total_cmp(&a.gpu_usage));
+      }
       ProcessRankingMetric::ExecutionTime => {
         stats.sort_by(|a, b| b.execution_sec.cmp(&a.execution_sec));
       }
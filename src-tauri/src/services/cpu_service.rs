@@ -1,4 +1,6 @@
-use crate::models::hardware::HardwareMonitorState;
+use crate::constants::MAX_HISTORY_QUERY_DURATION_SECONDS;
+use crate::enums;
+use crate::models::hardware::{CpuCoreFrequency, CpuTimeBreakdown, HardwareMonitorState};
 
 ///
 /// ## Return overall CPU usage (%)
@@ -25,3 +27,82 @@ pub fn per_cpu_usage(state: &HardwareMonitorState) -> Vec<f32> {
   let system = state.system.lock().unwrap();
   system.cpus().iter().map(|c| c.cpu_usage()).collect()
 }
+
+///
+/// ## Return the latest CPU time breakdown
+///
+/// The aggregate (`cpu`) comes first, followed by each core in index order.
+/// Returns `BackendError::CpuTimeNotAvailable` until the second sample is taken.
+///
+pub fn cpu_time_breakdown(
+  state: &HardwareMonitorState,
+) -> Result<Vec<CpuTimeBreakdown>, enums::error::BackendError> {
+  let histories = state.cpu_time_histories.lock().unwrap();
+
+  let mut breakdowns: Vec<CpuTimeBreakdown> = histories
+    .values()
+    .filter_map(|history| history.back().cloned())
+    .collect();
+
+  if breakdowns.is_empty() {
+    return Err(enums::error::BackendError::CpuTimeNotAvailable);
+  }
+
+  // `cpu` has no index and sorts before `cpu0`
+  breakdowns
+    .sort_by_key(|breakdown| breakdown.cpu.strip_prefix("cpu")?.parse::<u32>().ok());
+  Ok(breakdowns)
+}
+
+///
+/// ## CPU time breakdown history of `cpu` (`cpu` or `cpuN`)
+///
+/// (Last `seconds` from newest, max MAX_HISTORY_QUERY_DURATION_SECONDS) collected in reverse order
+///
+pub fn cpu_time_breakdown_history(
+  state: &HardwareMonitorState,
+  cpu: &str,
+  seconds: u32,
+) -> Result<Vec<CpuTimeBreakdown>, enums::error::BackendError> {
+  let histories = state.cpu_time_histories.lock().unwrap();
+  let history = histories
+    .get(cpu)
+    .ok_or(enums::error::BackendError::CpuTimeNotAvailable)?;
+  let take_n = seconds.min(MAX_HISTORY_QUERY_DURATION_SECONDS) as usize;
+
+  Ok(history.iter().rev().take(take_n).cloned().collect())
+}
+
+///
+/// ## Return the latest frequency scaling status of every core
+///
+pub fn cpu_frequencies(
+  state: &HardwareMonitorState,
+) -> Result<Vec<CpuCoreFrequency>, enums::error::BackendError> {
+  let frequencies = state.cpu_frequencies.lock().unwrap();
+
+  if frequencies.is_empty() {
+    return Err(enums::error::BackendError::CpuFrequencyNotAvailable);
+  }
+
+  Ok(frequencies.clone())
+}
+
+///
+/// ## Frequency (MHz) history of a core
+///
+/// (Last `seconds` from newest, max MAX_HISTORY_QUERY_DURATION_SECONDS) collected in reverse order
+///
+pub fn cpu_frequency_history(
+  state: &HardwareMonitorState,
+  core: u32,
+  seconds: u32,
+) -> Result<Vec<f32>, enums::error::BackendError> {
+  let histories = state.cpu_frequency_histories.lock().unwrap();
+  let history = histories
+    .get(&core)
+    .ok_or(enums::error::BackendError::CpuFrequencyNotAvailable)?;
+  let take_n = seconds.min(MAX_HISTORY_QUERY_DURATION_SECONDS) as usize;
+
+  Ok(history.iter().rev().take(take_n).cloned().collect())
+}
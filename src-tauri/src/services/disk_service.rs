@@ -0,0 +1,44 @@
+use crate::constants::MAX_HISTORY_QUERY_DURATION_SECONDS;
+use crate::enums;
+use crate::models::hardware::{DiskUsage, HardwareMonitorState};
+
+///
+/// Get the latest I/O activity of every block device (sorted by device name)
+/// Returns `BackendError::DiskUsageNotAvailable` until the second sample is taken
+///
+pub fn disk_usage(
+  state: &HardwareMonitorState,
+) -> Result<Vec<DiskUsage>, enums::error::BackendError> {
+  let histories = state.disk_usage_histories.lock().unwrap();
+
+  let mut usages: Vec<DiskUsage> = histories
+    .values()
+    .filter_map(|history| history.back().cloned())
+    .collect();
+
+  if usages.is_empty() {
+    return Err(enums::error::BackendError::DiskUsageNotAvailable);
+  }
+
+  usages.sort_by(|a, b| a.device.cmp(&b.device));
+  Ok(usages)
+}
+
+///
+/// ## Disk I/O history of a block device
+///
+/// (Last `seconds` from newest, max MAX_HISTORY_QUERY_DURATION_SECONDS) collected in reverse order
+///
+pub fn disk_usage_history(
+  state: &HardwareMonitorState,
+  device: &str,
+  seconds: u32,
+) -> Result<Vec<DiskUsage>, enums::error::BackendError> {
+  let histories = state.disk_usage_histories.lock().unwrap();
+  let history = histories
+    .get(device)
+    .ok_or(enums::error::BackendError::DiskUsageNotAvailable)?;
+  let take_n = seconds.min(MAX_HISTORY_QUERY_DURATION_SECONDS) as usize;
+
+  Ok(history.iter().rev().take(take_n).cloned().collect())
+}
@@ -0,0 +1,15 @@
+use crate::models::hardware::FanInfo;
+use crate::platform::factory::PlatformFactory;
+
+///
+/// Get every fan (CPU / chassis / GPU) with RPM and PWM duty
+///
+pub async fn fetch_fans() -> Result<Vec<FanInfo>, String> {
+  let platform =
+    PlatformFactory::create().map_err(|e| format!("Failed to create platform: {e}"))?;
+
+  platform
+    .get_fans()
+    .await
+    .map_err(|e| format!("Failed to get fan status: {e:?}"))
+}
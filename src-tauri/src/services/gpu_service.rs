@@ -1,15 +1,16 @@
+use crate::constants::MAX_HISTORY_QUERY_DURATION_SECONDS;
 use crate::enums;
-use crate::models::hardware::NameValue;
+use crate::models::hardware::{GpuMetrics, GpuUsage, HardwareMonitorState, NameValue};
 use crate::platform::factory::PlatformFactory;
 
 ///
 /// Get GPU usage (%) and return as rounded integer
 /// For multiple GPUs, depends on Platform implementation policy
 ///
-pub async fn fetch_gpu_usage() -> Result<i32, String> {
+pub async fn fetch_gpu_usage(state: &HardwareMonitorState) -> Result<i32, String> {
   let platform =
     PlatformFactory::create().map_err(|e| format!("Failed to create platform: {e}"))?;
-  let usage = platform.get_gpu_usage().await?;
+  let usage = platform.get_gpu_usage(state).await?;
   Ok(usage.round() as i32)
 }
 
@@ -18,21 +19,101 @@ pub async fn fetch_gpu_usage() -> Result<i32, String> {
 /// `temperature_unit` assumes user setting (Celsius/Fahrenheit etc.)
 ///
 pub async fn fetch_gpu_temperature(
+  state: &HardwareMonitorState,
   temperature_unit: enums::settings::TemperatureUnit,
 ) -> Result<Vec<NameValue>, String> {
   let platform =
     PlatformFactory::create().map_err(|e| format!("Failed to create platform: {e}"))?;
 
   platform
-    .get_gpu_temperature(temperature_unit)
+    .get_gpu_temperature(state, temperature_unit)
     .await
     .map_err(|e| format!("Failed to get GPU temperature: {e:?}"))
 }
 
 ///
-/// Get NVIDIA GPU fan speed (not implemented)
-/// Always returns Err as planned for future implementation
+/// ## Get the latest usage / memory / temperature of every GPU
 ///
-pub async fn fetch_nvidia_gpu_cooler() -> Result<Vec<NameValue>, String> {
-  Err("Failed to get GPU cooler status: This function is not implemented".to_string())
+/// Built from the sampled histories, keyed by `GraphicInfo.id` on Linux (`cardN`).
+/// Sorted by card number.
+///
+pub fn gpu_usages(
+  state: &HardwareMonitorState,
+) -> Result<Vec<GpuUsage>, enums::error::BackendError> {
+  let usage_histories = state.gpu_usage_histories.lock().unwrap();
+  let temperature_histories = state.gpu_temperature_histories.lock().unwrap();
+  let metrics_histories = state.gpu_metrics_histories.lock().unwrap();
+
+  let mut ids: Vec<&String> = usage_histories
+    .keys()
+    .chain(temperature_histories.keys())
+    .chain(metrics_histories.keys())
+    .collect();
+  ids.sort_by_key(|id| (id.trim_start_matches("card").parse::<u32>().ok(), *id));
+  ids.dedup();
+
+  if ids.is_empty() {
+    return Err(enums::error::BackendError::GraphicInfoNotAvailable);
+  }
+
+  Ok(
+    ids
+      .into_iter()
+      .map(|id| {
+        let metrics = metrics_histories.get(id).and_then(|history| history.back());
+
+        GpuUsage {
+          id: id.clone(),
+          usage: usage_histories
+            .get(id)
+            .and_then(|history| history.back().copied()),
+          memory_used_mb: metrics.and_then(|metrics| metrics.vram_used_mb),
+          memory_total_mb: metrics.and_then(|metrics| metrics.vram_total_mb),
+          temperature: temperature_histories
+            .get(id)
+            .and_then(|history| history.back().copied()),
+        }
+      })
+      .collect(),
+  )
+}
+
+///
+/// ## Get the latest clocks / memory / power of every GPU (sorted by card)
+///
+pub fn gpu_metrics(
+  state: &HardwareMonitorState,
+) -> Result<Vec<GpuMetrics>, enums::error::BackendError> {
+  let histories = state.gpu_metrics_histories.lock().unwrap();
+
+  let mut metrics: Vec<GpuMetrics> = histories
+    .values()
+    .filter_map(|history| history.back().cloned())
+    .collect();
+
+  if metrics.is_empty() {
+    return Err(enums::error::BackendError::GpuMetricsNotAvailable);
+  }
+
+  metrics.sort_by(|a, b| a.card.cmp(&b.card));
+  Ok(metrics)
+}
+
+///
+/// ## Clocks / memory / power history of a GPU
+///
+/// (Last `seconds` from newest, max MAX_HISTORY_QUERY_DURATION_SECONDS) collected in reverse order
+///
+pub fn gpu_metrics_history(
+  state: &HardwareMonitorState,
+  card: &str,
+  seconds: u32,
+) -> Result<Vec<GpuMetrics>, enums::error::BackendError> {
+  let histories = state.gpu_metrics_histories.lock().unwrap();
+  let history = histories
+    .get(card)
+    .ok_or(enums::error::BackendError::GpuMetricsNotAvailable)?;
+  let take_n = seconds.min(MAX_HISTORY_QUERY_DURATION_SECONDS) as usize;
+
+  Ok(history.iter().rev().take(take_n).cloned().collect())
 }
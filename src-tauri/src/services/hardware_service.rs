@@ -1,5 +1,7 @@
 use crate::infrastructure;
-use crate::models::hardware::{HardwareMonitorState, SysInfo};
+use crate::models::hardware::{
+  BlockDevice, DiskHealth, HardwareMonitorState, PciDevice, SysInfo,
+};
 use crate::platform::factory::PlatformFactory;
 
 ///
@@ -12,19 +14,24 @@ use crate::platform::factory::PlatformFactory;
 pub async fn collect_hardware_info(
   state: &HardwareMonitorState,
 ) -> Result<SysInfo, String> {
+  let platform =
+    PlatformFactory::create().map_err(|e| format!("Failed to create platform: {e}"))?;
+
   let cpu = infrastructure::providers::sysinfo_provider::get_cpu_info(
     state.system.lock().unwrap(),
   )
-  .ok();
-
-  let platform =
-    PlatformFactory::create().map_err(|e| format!("Failed to create platform: {e}"))?;
+  .ok()
+  .map(|mut cpu| {
+    cpu.topology = platform.get_cpu_topology().ok();
+    cpu
+  });
 
   // Execute GPU / Memory in parallel
-  let (gpus_res, memory_res, storage_res) =
-    tokio::join!(platform.get_gpu_info(), platform.get_memory_info(), async {
-      infrastructure::providers::sysinfo_provider::get_storage_info()
-    });
+  let (gpus_res, memory_res, storage_res) = tokio::join!(
+    platform.get_gpu_info(state),
+    platform.get_memory_info(),
+    async { infrastructure::providers::sysinfo_provider::get_storage_info() }
+  );
 
   let gpus = gpus_res.ok();
   let memory = memory_res.ok();
@@ -34,10 +41,40 @@ pub async fn collect_hardware_info(
     return Err("Failed to get any hardware info".to_string());
   }
 
+  let board = platform.get_board_info().ok();
+
   Ok(SysInfo {
     cpu,
     memory,
     gpus,
     storage,
+    board,
   })
 }
+
+///
+/// ## Get every PCI device via Platform
+///
+pub async fn fetch_pci_devices() -> Result<Vec<PciDevice>, String> {
+  let platform =
+    PlatformFactory::create().map_err(|e| format!("Failed to create platform: {e}"))?;
+  platform.get_pci_devices().await
+}
+
+///
+/// ## Get health of every physical disk via Platform
+///
+pub async fn fetch_disk_health() -> Result<Vec<DiskHealth>, String> {
+  let platform =
+    PlatformFactory::create().map_err(|e| format!("Failed to create platform: {e}"))?;
+  platform.get_disk_health().await
+}
+
+///
+/// ## Get the block device tree via Platform
+///
+pub async fn fetch_block_devices() -> Result<Vec<BlockDevice>, String> {
+  let platform =
+    PlatformFactory::create().map_err(|e| format!("Failed to create platform: {e}"))?;
+  platform.get_block_devices().await
+}
@@ -1,4 +1,4 @@
-use crate::models::hardware::{HardwareMonitorState, MemoryInfo};
+use crate::models::hardware::{HardwareMonitorState, MemoryBreakdown, MemoryInfo};
 use crate::platform::factory::PlatformFactory;
 
 ///
@@ -26,3 +26,12 @@ pub async fn fetch_memory_detail() -> Result<MemoryInfo, String> {
     PlatformFactory::create().map_err(|e| format!("Failed to create platform: {e}"))?;
   platform.get_memory_info_detail().await
 }
+
+///
+/// ## Get breakdown of memory usage via Platform
+///
+pub fn fetch_memory_breakdown() -> Result<MemoryBreakdown, String> {
+  let platform =
+    PlatformFactory::create().map_err(|e| format!("Failed to create platform: {e}"))?;
+  platform.get_memory_breakdown()
+}
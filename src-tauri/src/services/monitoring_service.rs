@@ -1,21 +1,35 @@
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
+use std::hash::Hash;
 use std::sync::{Arc, Mutex};
+#[cfg(target_os = "linux")]
+use std::time::Instant;
 
+#[cfg(target_os = "linux")]
+use crate::constants::CPU_THROTTLE_WINDOW_SECONDS;
 use crate::constants::{
   HARDWARE_HISTORY_BUFFER_SIZE, MAX_HISTORY_QUERY_DURATION_SECONDS,
 };
+use crate::enums;
 use crate::models::hardware::HardwareMonitorState;
+#[cfg(target_os = "linux")]
+use crate::models::hardware::{
+  CpuTimeBreakdown, CpuTimes, DiskCounters, DiskUsage, NetworkCounters, NetworkUsage,
+  PressureInfo, RaplPower,
+};
 use crate::models::hardware_archive::MonitorResources;
+use crate::utils;
 
 /// System sampling for one cycle (CPU/memory/process)
 pub fn sample_system(resources: &MonitorResources) {
-  if let Some((cpu_usage, memory_usage, process_metrics)) =
+  if let Some((cpu_usage, memory_usage, swap_usage, process_metrics)) =
     resources.system.lock().ok().map(|mut sys| {
       sys.refresh_all();
 
       let cpu_usage = calculate_average_cpu_usage(sys.cpus());
       let memory_usage =
         calculate_memory_usage_percentage(sys.used_memory(), sys.total_memory());
+      let swap_usage =
+        calculate_memory_usage_percentage(sys.used_swap(), sys.total_swap());
 
       let process_metrics: Vec<_> = sys
         .processes()
@@ -25,13 +39,88 @@ pub fn sample_system(resources: &MonitorResources) {
         })
         .collect();
 
-      (cpu_usage, memory_usage, process_metrics)
+      (cpu_usage, memory_usage, swap_usage, process_metrics)
     })
   {
     push_history(&resources.cpu_history, cpu_usage);
     push_history(&resources.memory_history, memory_usage);
+    push_history(&resources.swap_history, swap_usage);
     update_process_histories(resources, &process_metrics);
   }
+
+  #[cfg(target_os = "linux")]
+  sample_cpu_times(resources);
+}
+
+///
+/// Sample the CPU time breakdown of all cores and each core
+///
+/// Shares are computed from the counter deltas, so the first tick only records
+/// the baseline.
+///
+#[cfg(target_os = "linux")]
+fn sample_cpu_times(resources: &MonitorResources) {
+  use crate::infrastructure::providers::procfs;
+
+  let Ok(times) = procfs::get_cpu_times() else {
+    return;
+  };
+
+  let mut prev_times = resources.cpu_times.lock().unwrap();
+  let mut histories = resources.cpu_time_histories.lock().unwrap();
+
+  // Drop cores that went offline (CPU hotplug)
+  prev_times.retain(|cpu, _| times.contains_key(cpu));
+  histories.retain(|cpu, _| times.contains_key(cpu));
+
+  for (cpu, curr) in times {
+    if let Some(breakdown) = prev_times
+      .get(&cpu)
+      .and_then(|prev| calculate_cpu_time_breakdown(&cpu, prev, &curr))
+    {
+      push_keyed_history(&mut histories, cpu.clone(), breakdown);
+    }
+
+    prev_times.insert(cpu, curr);
+  }
+}
+
+///
+/// Returns `None` when no time elapsed between the samples
+///
+#[cfg(target_os = "linux")]
+fn calculate_cpu_time_breakdown(
+  cpu: &str,
+  prev: &CpuTimes,
+  curr: &CpuTimes,
+) -> Option<CpuTimeBreakdown> {
+  let user = curr.user.saturating_sub(prev.user);
+  let nice = curr.nice.saturating_sub(prev.nice);
+  let system = curr.system.saturating_sub(prev.system);
+  let idle = curr.idle.saturating_sub(prev.idle);
+  let iowait = curr.iowait.saturating_sub(prev.iowait);
+  let irq = curr.irq.saturating_sub(prev.irq);
+  let softirq = curr.softirq.saturating_sub(prev.softirq);
+  let steal = curr.steal.saturating_sub(prev.steal);
+
+  let total = user + nice + system + idle + iowait + irq + softirq + steal;
+  if total == 0 {
+    return None;
+  }
+
+  let share = |value: u64| utils::rounding::round1(value as f32 / total as f32 * 100.0);
+
+  Some(CpuTimeBreakdown {
+    cpu: cpu.to_string(),
+    user: share(user),
+    nice: share(nice),
+    system: share(system),
+    idle: share(idle),
+    iowait: share(iowait),
+    irq: share(irq),
+    softirq: share(softirq),
+    steal: share(steal),
+  })
 }
 
 fn calculate_average_cpu_usage(cpus: &[sysinfo::Cpu]) -> f32 {
@@ -58,17 +147,74 @@ fn update_process_histories(
   process_metrics
     .iter()
     .for_each(|(pid, cpu_usage, memory_mb)| {
-      let cpu_history = cpu_histories.entry(*pid).or_default();
-      if cpu_history.len() >= HARDWARE_HISTORY_BUFFER_SIZE {
-        cpu_history.pop_front();
-      }
-      cpu_history.push_back(*cpu_usage);
-      let mem_history = mem_histories.entry(*pid).or_default();
-      if mem_history.len() >= HARDWARE_HISTORY_BUFFER_SIZE {
-        mem_history.pop_front();
-      }
-      mem_history.push_back(*memory_mb);
+      push_keyed_history(&mut cpu_histories, *pid, *cpu_usage);
+      push_keyed_history(&mut mem_histories, *pid, *memory_mb);
     });
+  drop(cpu_histories);
+  drop(mem_histories);
+
+  #[cfg(target_os = "linux")]
+  update_process_gpu_histories(resources, process_metrics);
+}
+
+///
+/// Read DRM fdinfo once per tick, keeping the previous snapshot
+///
+/// Must run before `sample_system` and `sample_gpu`, which both derive their
+/// GPU usage from these snapshots. Scanning `/proc` blocks, so it runs on the
+/// blocking thread pool.
+///
+#[cfg(target_os = "linux")]
+pub async fn sample_drm_clients(resources: &MonitorResources) {
+  use crate::infrastructure::providers::drm_fdinfo;
+
+  let Ok(clients) = tokio::task::spawn_blocking(drm_fdinfo::get_drm_clients).await else {
+    return;
+  };
+  let mut snapshots = resources.drm_clients.lock().unwrap();
+  snapshots.previous = snapshots
+    .latest
+    .replace((std::time::Instant::now(), clients));
+}
+
+///
+/// Sample GPU usage (%) and dedicated GPU memory (MB) of processes from DRM fdinfo
+///
+/// Only processes that have (or had) a DRM client get a history. Usage is derived
+/// from the snapshot of the previous tick, so the first tick records `0.0`.
+///
+#[cfg(target_os = "linux")]
+fn update_process_gpu_histories(
+  resources: &MonitorResources,
+  process_metrics: &[(sysinfo::Pid, f32, f32)],
+) {
+  use crate::infrastructure::providers::drm_fdinfo;
+
+  let snapshots = resources.drm_clients.lock().unwrap();
+  let memory = snapshots
+    .latest
+    .as_ref()
+    .map(|(_, clients)| drm_fdinfo::process_gpu_memory_mb(clients))
+    .unwrap_or_default();
+  let usages = snapshots
+    .delta()
+    .map(|(prev, curr, elapsed)| drm_fdinfo::process_gpu_percents(prev, curr, elapsed))
+    .unwrap_or_default();
+  drop(snapshots);
+
+  let mut gpu_histories = resources.process_gpu_histories.lock().unwrap();
+  let mut gpu_memory_histories = resources.process_gpu_memory_histories.lock().unwrap();
+
+  for (pid, _, _) in process_metrics {
+    let usage = usages.get(&pid.as_u32()).copied();
+    let memory_mb = memory.get(&pid.as_u32()).copied();
+    if usage.is_none() && memory_mb.is_none() && !gpu_histories.contains_key(pid) {
+      continue;
+    }
+
+    push_keyed_history(&mut gpu_histories, *pid, usage.unwrap_or(0.0));
+    push_keyed_history(&mut gpu_memory_histories, *pid, memory_mb.unwrap_or(0.0));
+  }
 }
 
 #[cfg(target_os = "windows")]
@@ -99,29 +245,506 @@ fn update_gpu_histories(
   resources: &MonitorResources,
   gpu_metrics: &[(String, f32, f32, f32)],
 ) {
-  let mut usage_histories = resources.nv_gpu_usage_histories.lock().unwrap();
-  let mut temp_histories = resources.nv_gpu_temperature_histories.lock().unwrap();
-  let mut mem_histories = resources.nv_gpu_dedicated_memory_histories.lock().unwrap();
+  let mut usage_histories = resources.gpu_usage_histories.lock().unwrap();
+  let mut temp_histories = resources.gpu_temperature_histories.lock().unwrap();
+  let mut mem_histories = resources.gpu_dedicated_memory_histories.lock().unwrap();
 
   gpu_metrics
     .iter()
     .for_each(|(name, usage, temperature, memory_usage)| {
-      let usage_history = usage_histories.entry(name.clone()).or_default();
-      if usage_history.len() >= HARDWARE_HISTORY_BUFFER_SIZE {
-        usage_history.pop_front();
-      }
-      usage_history.push_back(*usage);
-      let temp_history = temp_histories.entry(name.clone()).or_default();
-      if temp_history.len() >= HARDWARE_HISTORY_BUFFER_SIZE {
-        temp_history.pop_front();
+      push_keyed_history(&mut usage_histories, name.clone(), *usage);
+      push_keyed_history(&mut temp_histories, name.clone(), *temperature as i32);
+      push_keyed_history(&mut mem_histories, name.clone(), *memory_usage as i32);
+    });
+}
+
+///
+/// Query `nvidia-smi` and cache the status of every NVIDIA card
+///
+/// Runs on its own task so a slow `nvidia-smi` never delays the other samplers.
+/// `sample_gpu` and the GPU commands read the cached statuses. When `nvidia-smi`
+/// does not answer within `NVIDIA_SMI_TIMEOUT_SECONDS` the previous statuses are
+/// kept, and no new query starts until the hung one returns.
+///
+#[cfg(target_os = "linux")]
+pub async fn sample_nvidia_gpus(
+  statuses: &Mutex<HashMap<u8, crate::models::hardware::NvidiaGpuStatus>>,
+) {
+  use crate::constants::NVIDIA_SMI_TIMEOUT_SECONDS;
+  use crate::infrastructure::providers::{drm_sys, nvidia_smi};
+  use crate::{log_internal, log_warn};
+  use std::sync::atomic::{AtomicBool, Ordering};
+
+  static IN_FLIGHT: AtomicBool = AtomicBool::new(false);
+  static TIMEOUT_WARNED: std::sync::Once = std::sync::Once::new();
+
+  if IN_FLIGHT.swap(true, Ordering::AcqRel) {
+    return;
+  }
+
+  let query = tokio::task::spawn_blocking(|| {
+    let statuses = nvidia_smi::get_nvidia_card_statuses(
+      nvidia_smi::get_backend(),
+      &drm_sys::get_all_card_ids(),
+    );
+    IN_FLIGHT.store(false, Ordering::Release);
+    statuses
+  });
+
+  match tokio::time::timeout(
+    tokio::time::Duration::from_secs(NVIDIA_SMI_TIMEOUT_SECONDS),
+    query,
+  )
+  .await
+  {
+    Ok(Ok(latest)) => *statuses.lock().unwrap() = latest,
+    Ok(Err(_)) => IN_FLIGHT.store(false, Ordering::Release),
+    Err(_) => TIMEOUT_WARNED.call_once(|| {
+      log_warn!(
+        &format!("nvidia-smi did not answer within {NVIDIA_SMI_TIMEOUT_SECONDS}s"),
+        "sample_nvidia_gpus",
+        None::<&str>
+      );
+    }),
+  }
+}
+
+#[cfg(target_os = "linux")]
+pub fn sample_gpu(resources: &MonitorResources) {
+  use crate::infrastructure::providers::drm_sys;
+
+  let card_ids = drm_sys::get_all_card_ids();
+  prune_gpu_histories(resources, &card_ids);
+
+  let intel_usages =
+    drm_sys::get_intel_gpu_usages(&resources.drm_clients.lock().unwrap(), &card_ids);
+  let nvidia = resources.nvidia_gpu_statuses.lock().unwrap().clone();
+
+  let gpu_metrics: Vec<(String, f32)> = card_ids
+    .iter()
+    .copied()
+    .filter_map(|card_id| {
+      drm_sys::read_gpu_busy_percent(card_id)
+        .or_else(|| intel_usages.get(&card_id).copied())
+        .or_else(|| nvidia.get(&card_id).and_then(|status| status.usage))
+        .map(|usage| (format!("card{card_id}"), usage))
+    })
+    .collect();
+
+  // Same policy as `get_gpu_usage`: the busiest card is the overall usage
+  if let Some(usage) = gpu_metrics
+    .iter()
+    .map(|(_, usage)| *usage)
+    .max_by(f32::total_cmp)
+  {
+    push_history(&resources.gpu_history, usage);
+  }
+
+  update_gpu_usage_histories(resources, &gpu_metrics);
+  sample_gpu_temperatures(resources, &card_ids, &nvidia);
+  sample_gpu_metrics(resources, &card_ids, &nvidia);
+}
+
+///
+/// Drop the histories of cards that disappeared (hot-unplug, driver unbind)
+///
+/// Otherwise `gpu_usages` keeps reporting their last sample.
+///
+#[cfg(target_os = "linux")]
+fn prune_gpu_histories(resources: &MonitorResources, card_ids: &[u8]) {
+  let is_present = |card: &String| card_ids.iter().any(|id| *card == format!("card{id}"));
+
+  resources
+    .gpu_usage_histories
+    .lock()
+    .unwrap()
+    .retain(|card, _| is_present(card));
+  resources
+    .gpu_temperature_histories
+    .lock()
+    .unwrap()
+    .retain(|card, _| is_present(card));
+  resources
+    .gpu_metrics_histories
+    .lock()
+    .unwrap()
+    .retain(|card, _| is_present(card));
+}
+
+///
+/// Sample the first hwmon sensor of every card (Celsius)
+///
+/// The first sensor is the one describing the whole chip (amdgpu `edge`,
+/// nouveau / i915 `temp1`). NVIDIA cards use the driver temperature instead.
+///
+#[cfg(target_os = "linux")]
+fn sample_gpu_temperatures(
+  resources: &MonitorResources,
+  card_ids: &[u8],
+  nvidia: &std::collections::HashMap<u8, crate::models::hardware::NvidiaGpuStatus>,
+) {
+  use crate::infrastructure::providers::hwmon;
+
+  let mut histories = resources.gpu_temperature_histories.lock().unwrap();
+
+  for card_id in card_ids {
+    let Some(temperature) = hwmon::get_card_hwmon_dirs(*card_id)
+      .iter()
+      .find_map(|dir| hwmon::read_temperatures(dir).into_iter().next())
+      .map(|sensor| sensor.value)
+      .or_else(|| nvidia.get(card_id).and_then(|status| status.temperature))
+    else {
+      continue;
+    };
+
+    push_keyed_history(
+      &mut histories,
+      format!("card{card_id}"),
+      temperature.round() as i32,
+    );
+  }
+}
+
+///
+/// Sample clocks / memory / power of every amdgpu and NVIDIA card
+///
+#[cfg(target_os = "linux")]
+fn sample_gpu_metrics(
+  resources: &MonitorResources,
+  card_ids: &[u8],
+  nvidia: &std::collections::HashMap<u8, crate::models::hardware::NvidiaGpuStatus>,
+) {
+  use crate::infrastructure::providers::{amdgpu, drm_sys, nvidia_smi};
+
+  let mut histories = resources.gpu_metrics_histories.lock().unwrap();
+
+  for card_id in card_ids {
+    let metrics = match drm_sys::detect_gpu_vendor(*card_id) {
+      drm_sys::GpuVendor::Amd => amdgpu::get_amd_gpu_metrics(*card_id),
+      drm_sys::GpuVendor::Nvidia => match nvidia.get(card_id) {
+        Some(status) => nvidia_smi::to_gpu_metrics(&format!("card{card_id}"), status),
+        None => continue,
+      },
+      _ => continue,
+    };
+    push_keyed_history(&mut histories, metrics.card.clone(), metrics);
+  }
+}
+
+#[cfg(target_os = "linux")]
+fn update_gpu_usage_histories(
+  resources: &MonitorResources,
+  gpu_metrics: &[(String, f32)],
+) {
+  let mut usage_histories = resources.gpu_usage_histories.lock().unwrap();
+
+  gpu_metrics.iter().for_each(|(card, usage)| {
+    push_keyed_history(&mut usage_histories, card.clone(), *usage);
+  });
+}
+
+///
+/// Sample the hottest CPU sensor (Celsius)
+///
+#[cfg(target_os = "linux")]
+pub fn sample_cpu_temperature(resources: &MonitorResources) {
+  use crate::infrastructure::providers::thermal_sys;
+
+  if let Some(temperature) = thermal_sys::get_cpu_temperatures()
+    .iter()
+    .map(|sensor| sensor.value)
+    .max_by(f32::total_cmp)
+  {
+    push_history(&resources.cpu_temperature_history, temperature.round());
+  }
+}
+
+///
+/// Sample throughput of every network interface
+///
+/// Rates are derived from the counters of the previous tick, so the first tick only
+/// records the baseline.
+///
+#[cfg(target_os = "linux")]
+pub fn sample_network(resources: &MonitorResources) {
+  use crate::infrastructure::providers::net_sys;
+
+  let Ok(counters) = net_sys::get_network_counters() else {
+    return;
+  };
+
+  // Interfaces that no longer exist (e.g. container veth pairs) are dropped
+  update_counter_histories(
+    &mut resources.network_counters.lock().unwrap(),
+    &mut resources.network_usage_histories.lock().unwrap(),
+    counters,
+    |iface, prev, curr, elapsed| {
+      calculate_network_usage(iface, prev, curr, elapsed.as_secs_f32())
+    },
+  );
+}
+
+///
+/// Per-second throughput between two counter samples
+///
+/// Counters that went backwards (wraparound or interface reset) are treated as
+/// no traffic. Returns `None` when no time elapsed between the samples.
+///
+#[cfg(target_os = "linux")]
+pub fn calculate_network_usage(
+  interface: &str,
+  prev: &NetworkCounters,
+  curr: &NetworkCounters,
+  elapsed_secs: f32,
+) -> Option<NetworkUsage> {
+  if elapsed_secs <= 0.0 {
+    return None;
+  }
+
+  let rate = |prev: u64, curr: u64| curr.saturating_sub(prev) as f32 / elapsed_secs;
+
+  Some(NetworkUsage {
+    interface: interface.to_string(),
+    rx_bytes_per_sec: rate(prev.rx_bytes, curr.rx_bytes),
+    tx_bytes_per_sec: rate(prev.tx_bytes, curr.tx_bytes),
+    rx_packets_per_sec: rate(prev.rx_packets, curr.rx_packets),
+    tx_packets_per_sec: rate(prev.tx_packets, curr.tx_packets),
+    rx_errors_per_sec: rate(prev.rx_errors, curr.rx_errors),
+    tx_errors_per_sec: rate(prev.tx_errors, curr.tx_errors),
+    rx_dropped_per_sec: rate(prev.rx_dropped, curr.rx_dropped),
+    tx_dropped_per_sec: rate(prev.tx_dropped, curr.tx_dropped),
+  })
+}
+
+///
+/// Sample Pressure Stall Information and load average
+///
+#[cfg(target_os = "linux")]
+pub fn sample_pressure(resources: &MonitorResources) {
+  use crate::infrastructure::providers::procfs;
+
+  let Ok(load_average) = procfs::get_load_average() else {
+    return;
+  };
+
+  let info = PressureInfo {
+    cpu: procfs::get_pressure("cpu"),
+    memory: procfs::get_pressure("memory"),
+    io: procfs::get_pressure("io"),
+    load_average,
+  };
+
+  push_history(&resources.pressure_history, info);
+}
+
+///
+/// Sample battery level (%) and charge / discharge rate (W)
+///
+/// With multiple batteries the level is averaged and the rates are summed.
+/// Nothing is recorded on systems without a battery.
+///
+#[cfg(target_os = "linux")]
+pub fn sample_battery(resources: &MonitorResources) {
+  use crate::infrastructure::providers::power_supply;
+
+  let info = power_supply::get_power_supply_info();
+
+  let levels: Vec<f32> = info
+    .batteries
+    .iter()
+    .filter_map(|battery| battery.percentage)
+    .collect();
+  if !levels.is_empty() {
+    let level = levels.iter().sum::<f32>() / levels.len() as f32;
+    push_history(&resources.battery_history, utils::rounding::round1(level));
+  }
+
+  let rates: Vec<f32> = info
+    .batteries
+    .iter()
+    .filter_map(|battery| battery.power_watts)
+    .collect();
+  if !rates.is_empty() {
+    let rate = rates.iter().sum::<f32>();
+    push_history(
+      &resources.battery_power_history,
+      utils::rounding::round1(rate),
+    );
+  }
+}
+
+///
+/// Sample per-core frequency and detect thermal throttling
+///
+/// Throttle events are counted against the sample taken `CPU_THROTTLE_WINDOW_SECONDS`
+/// earlier, so a throttled core stays flagged for the whole window.
+///
+#[cfg(target_os = "linux")]
+pub fn sample_cpu_frequency(resources: &MonitorResources) {
+  use crate::infrastructure::providers::cpufreq;
+
+  let mut cores = cpufreq::get_cpu_frequencies();
+  if cores.is_empty() {
+    return;
+  }
+
+  let mut latest = resources.cpu_frequencies.lock().unwrap();
+  let mut histories = resources.cpu_frequency_histories.lock().unwrap();
+  let mut windows = resources.cpu_throttle_windows.lock().unwrap();
+
+  // Drop cores that went offline (CPU hotplug)
+  histories.retain(|index, _| cores.iter().any(|core| core.core == *index));
+  windows.retain(|index, _| cores.iter().any(|core| core.core == *index));
+
+  for core in cores.iter_mut() {
+    let window = windows.entry(core.core).or_default();
+    core.throttle_events = window
+      .front()
+      .map_or(0, |oldest| cpufreq::throttle_events(oldest, core));
+    core.is_throttled = core.throttle_events > 0;
+
+    if window.len() >= CPU_THROTTLE_WINDOW_SECONDS {
+      window.pop_front();
+    }
+    window.push_back(core.clone());
+
+    push_keyed_history(&mut histories, core.core, core.current_mhz);
+  }
+
+  *latest = cores;
+}
+
+///
+/// Sample power draw of every RAPL zone and the CPU package total
+///
+/// Like `sample_network`, the first tick only records the baseline counters.
+///
+#[cfg(target_os = "linux")]
+pub fn sample_cpu_power(resources: &MonitorResources) {
+  use crate::infrastructure::providers::powercap;
+  use crate::{log_internal, log_warn};
+
+  static PERMISSION_WARNED: std::sync::Once = std::sync::Once::new();
+
+  let counters = match powercap::get_rapl_counters() {
+    Ok(counters) if !counters.is_empty() => counters,
+    Ok(_) => return,
+    Err(e) => {
+      PERMISSION_WARNED.call_once(|| {
+        log_warn!("RAPL is not readable", "sample_cpu_power", Some(e));
+      });
+      return;
+    }
+  };
+
+  let mut package_joules = 0.0;
+  let mut package_watts = None;
+
+  update_counter_histories(
+    &mut resources.rapl_counters.lock().unwrap(),
+    &mut resources.rapl_power_histories.lock().unwrap(),
+    counters,
+    |zone, prev, curr, elapsed| {
+      let elapsed_secs = elapsed.as_secs_f64();
+      if elapsed_secs <= 0.0 {
+        return None;
       }
-      temp_history.push_back(*temperature as i32);
-      let mem_history = mem_histories.entry(name.clone()).or_default();
-      if mem_history.len() >= HARDWARE_HISTORY_BUFFER_SIZE {
-        mem_history.pop_front();
+
+      let joules = powercap::energy_delta_uj(
+        prev.energy_uj,
+        curr.energy_uj,
+        curr.max_energy_range_uj,
+      )? as f64
+        / 1_000_000.0;
+      let watts = (joules / elapsed_secs) as f32;
+
+      if powercap::is_package_zone(&curr.name) {
+        package_joules += joules;
+        package_watts = Some(package_watts.unwrap_or(0.0) + watts);
       }
-      mem_history.push_back(*memory_usage as i32);
-    });
+
+      Some(RaplPower {
+        zone: zone.to_string(),
+        name: curr.name.clone(),
+        watts: utils::rounding::round1(watts),
+      })
+    },
+  );
+
+  if let Some(watts) = package_watts {
+    push_history(&resources.cpu_power_history, utils::rounding::round1(watts));
+    *resources.cpu_energy_joules.lock().unwrap() += package_joules;
+  }
+}
+
+///
+/// Sample I/O activity of every block device
+///
+/// Like `sample_network`, the first tick only records the baseline counters.
+///
+#[cfg(target_os = "linux")]
+pub fn sample_disk(resources: &MonitorResources) {
+  use crate::infrastructure::providers::procfs;
+
+  let Ok(counters) = procfs::get_disk_counters() else {
+    return;
+  };
+
+  // Devices that were removed (e.g. unplugged USB drives) are dropped
+  update_counter_histories(
+    &mut resources.disk_counters.lock().unwrap(),
+    &mut resources.disk_usage_histories.lock().unwrap(),
+    counters,
+    |device, prev, curr, elapsed| {
+      calculate_disk_usage(device, prev, curr, elapsed.as_secs_f32())
+    },
+  );
+}
+
+///
+/// Per-second I/O activity between two `/proc/diskstats` samples
+///
+/// - Latency: time spent on the requests completed during the interval, per request
+/// - Utilization: share of the interval with I/O in flight (`io_ticks`)
+///
+/// Counters that went backwards (device re-attached) are treated as no activity.
+/// Returns `None` when no time elapsed between the samples.
+///
+#[cfg(target_os = "linux")]
+pub fn calculate_disk_usage(
+  device: &str,
+  prev: &DiskCounters,
+  curr: &DiskCounters,
+  elapsed_secs: f32,
+) -> Option<DiskUsage> {
+  use crate::infrastructure::providers::procfs::DISKSTATS_SECTOR_SIZE;
+
+  if elapsed_secs <= 0.0 {
+    return None;
+  }
+
+  let reads = curr.reads_completed.saturating_sub(prev.reads_completed);
+  let writes = curr.writes_completed.saturating_sub(prev.writes_completed);
+  let sectors_read = curr.sectors_read.saturating_sub(prev.sectors_read);
+  let sectors_written = curr.sectors_written.saturating_sub(prev.sectors_written);
+  let request_time_ms = curr.read_time_ms.saturating_sub(prev.read_time_ms)
+    + curr.write_time_ms.saturating_sub(prev.write_time_ms);
+  let io_time_ms = curr.io_time_ms.saturating_sub(prev.io_time_ms);
+
+  let avg_latency_ms = match reads + writes {
+    0 => 0.0,
+    requests => request_time_ms as f32 / requests as f32,
+  };
+
+  Some(DiskUsage {
+    device: device.to_string(),
+    read_bytes_per_sec: (sectors_read * DISKSTATS_SECTOR_SIZE) as f32 / elapsed_secs,
+    write_bytes_per_sec: (sectors_written * DISKSTATS_SECTOR_SIZE) as f32 / elapsed_secs,
+    read_iops: reads as f32 / elapsed_secs,
+    write_iops: writes as f32 / elapsed_secs,
+    avg_latency_ms,
+    utilization: (io_time_ms as f32 / (elapsed_secs * 1000.0) * 100.0).min(100.0),
+  })
 }
 
 ///
@@ -148,6 +771,54 @@ pub fn memory_usage_history(state: &HardwareMonitorState, seconds: u32) -> Vec<f
   history.iter().rev().take(take_n).cloned().collect()
 }
 
+///
+/// ## Swap usage history
+///
+/// (Last `seconds` from newest, max MAX_HISTORY_QUERY_DURATION_SECONDS) collected in reverse order
+///
+pub fn swap_usage_history(state: &HardwareMonitorState, seconds: u32) -> Vec<f32> {
+  let history = state.swap_history.lock().unwrap();
+  let take_n = seconds.min(MAX_HISTORY_QUERY_DURATION_SECONDS) as usize;
+
+  history.iter().rev().take(take_n).cloned().collect()
+}
+
+///
+/// ## Battery level history
+///
+/// (Last `seconds` from newest, max MAX_HISTORY_QUERY_DURATION_SECONDS) collected in reverse order
+///
+pub fn battery_history(state: &HardwareMonitorState, seconds: u32) -> Vec<f32> {
+  let history = state.battery_history.lock().unwrap();
+  let take_n = seconds.min(MAX_HISTORY_QUERY_DURATION_SECONDS) as usize;
+
+  history.iter().rev().take(take_n).cloned().collect()
+}
+
+///
+/// ## Battery charge (+) / discharge (-) rate history
+///
+/// (Last `seconds` from newest, max MAX_HISTORY_QUERY_DURATION_SECONDS) collected in reverse order
+///
+pub fn battery_power_history(state: &HardwareMonitorState, seconds: u32) -> Vec<f32> {
+  let history = state.battery_power_history.lock().unwrap();
+  let take_n = seconds.min(MAX_HISTORY_QUERY_DURATION_SECONDS) as usize;
+
+  history.iter().rev().take(take_n).cloned().collect()
+}
+
+///
+/// ## CPU package power history (W)
+///
+/// (Last `seconds` from newest, max MAX_HISTORY_QUERY_DURATION_SECONDS) collected in reverse order
+///
+pub fn cpu_power_history(state: &HardwareMonitorState, seconds: u32) -> Vec<f32> {
+  let history = state.cpu_power_history.lock().unwrap();
+  let take_n = seconds.min(MAX_HISTORY_QUERY_DURATION_SECONDS) as usize;
+
+  history.iter().rev().take(take_n).cloned().collect()
+}
+
 ///
 /// ## GPU usage history
 ///
@@ -160,10 +831,80 @@ pub fn gpu_usage_history(state: &HardwareMonitorState, seconds: u32) -> Vec<f32>
   history.iter().rev().take(take_n).cloned().collect()
 }
 
-fn push_history(history: &Arc<Mutex<VecDeque<f32>>>, value: f32) {
+///
+/// ## CPU temperature history
+///
+/// (Last `seconds` from newest, max MAX_HISTORY_QUERY_DURATION_SECONDS) collected in reverse order
+/// Values are converted from Celsius to `temperature_unit`
+///
+pub fn cpu_temperature_history(
+  state: &HardwareMonitorState,
+  seconds: u32,
+  temperature_unit: enums::settings::TemperatureUnit,
+) -> Vec<f32> {
+  let history = state.cpu_temperature_history.lock().unwrap();
+  let take_n = seconds.min(MAX_HISTORY_QUERY_DURATION_SECONDS) as usize;
+
+  history
+    .iter()
+    .rev()
+    .take(take_n)
+    .map(|value| {
+      utils::formatter::format_temperature(
+        enums::settings::TemperatureUnit::Celsius,
+        temperature_unit.clone(),
+        *value as i32,
+      ) as f32
+    })
+    .collect()
+}
+
+fn push_history<T>(history: &Arc<Mutex<VecDeque<T>>>, value: T) {
   let mut h = history.lock().unwrap();
   if h.len() >= HARDWARE_HISTORY_BUFFER_SIZE {
     h.pop_front();
   }
   h.push_back(value);
 }
+
+fn push_keyed_history<K: Eq + Hash, T>(
+  histories: &mut HashMap<K, VecDeque<T>>,
+  key: K,
+  value: T,
+) {
+  let history = histories.entry(key).or_default();
+  if history.len() >= HARDWARE_HISTORY_BUFFER_SIZE {
+    history.pop_front();
+  }
+  history.push_back(value);
+}
+
+///
+/// Derive a value per key from two samples of cumulative counters and record it
+///
+/// Keys missing from `counters` are dropped from both maps, and the first sample
+/// of a key only records the baseline. `calculate` gets the time elapsed since the
+/// previous sample and returns `None` to skip the tick.
+///
+#[cfg(target_os = "linux")]
+fn update_counter_histories<C, T>(
+  prev_counters: &mut HashMap<String, (Instant, C)>,
+  histories: &mut HashMap<String, VecDeque<T>>,
+  counters: HashMap<String, C>,
+  mut calculate: impl FnMut(&str, &C, &C, std::time::Duration) -> Option<T>,
+) {
+  let now = Instant::now();
+
+  prev_counters.retain(|key, _| counters.contains_key(key));
+  histories.retain(|key, _| counters.contains_key(key));
+
+  for (key, curr) in counters {
+    if let Some(value) = prev_counters.get(&key).and_then(|(sampled_at, prev)| {
+      calculate(&key, prev, &curr, now.duration_since(*sampled_at))
+    }) {
+      push_keyed_history(histories, key.clone(), value);
+    }
+
+    prev_counters.insert(key, (now, curr));
+  }
+}
@@ -1,5 +1,6 @@
+use crate::constants::MAX_HISTORY_QUERY_DURATION_SECONDS;
 use crate::enums;
-use crate::models::hardware::NetworkInfo;
+use crate::models::hardware::{HardwareMonitorState, NetworkInfo, NetworkUsage};
 use crate::platform::factory::PlatformFactory;
 
 ///
@@ -13,3 +14,44 @@ pub fn fetch_network_info() -> Result<Vec<NetworkInfo>, enums::error::BackendErr
     .get_network_info()
     .map_err(|_| enums::error::BackendError::UnexpectedError)
 }
+
+///
+/// Get the latest throughput of every interface (sorted by interface name)
+/// Returns `BackendError::NetworkUsageNotAvailable` until the second sample is taken
+///
+pub fn network_usage(
+  state: &HardwareMonitorState,
+) -> Result<Vec<NetworkUsage>, enums::error::BackendError> {
+  let histories = state.network_usage_histories.lock().unwrap();
+
+  let mut usages: Vec<NetworkUsage> = histories
+    .values()
+    .filter_map(|history| history.back().cloned())
+    .collect();
+
+  if usages.is_empty() {
+    return Err(enums::error::BackendError::NetworkUsageNotAvailable);
+  }
+
+  usages.sort_by(|a, b| a.interface.cmp(&b.interface));
+  Ok(usages)
+}
+
+///
+/// ## Network throughput history of an interface
+///
+/// (Last `seconds` from newest, max MAX_HISTORY_QUERY_DURATION_SECONDS) collected in reverse order
+///
+pub fn network_usage_history(
+  state: &HardwareMonitorState,
+  interface: &str,
+  seconds: u32,
+) -> Result<Vec<NetworkUsage>, enums::error::BackendError> {
+  let histories = state.network_usage_histories.lock().unwrap();
+  let history = histories
+    .get(interface)
+    .ok_or(enums::error::BackendError::NetworkUsageNotAvailable)?;
+  let take_n = seconds.min(MAX_HISTORY_QUERY_DURATION_SECONDS) as usize;
+
+  Ok(history.iter().rev().take(take_n).cloned().collect())
+}
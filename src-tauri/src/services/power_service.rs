@@ -0,0 +1,36 @@
+use crate::enums;
+use crate::models::hardware::{HardwareMonitorState, PowerSupplyInfo, RaplPower};
+use crate::platform::factory::PlatformFactory;
+
+///
+/// ## Get AC adapter and battery status via Platform
+///
+pub fn fetch_power_supply_info() -> Result<PowerSupplyInfo, String> {
+  let platform =
+    PlatformFactory::create().map_err(|e| format!("Failed to create platform: {e}"))?;
+  platform.get_power_supply_info()
+}
+
+///
+/// ## Get the latest power draw of every RAPL zone (sorted by zone id)
+///
+/// Returns `BackendError::CpuPowerNotAvailable` when RAPL is not readable
+/// (unsupported CPU or insufficient permission) or until the second sample is taken
+///
+pub fn cpu_power(
+  state: &HardwareMonitorState,
+) -> Result<Vec<RaplPower>, enums::error::BackendError> {
+  let histories = state.rapl_power_histories.lock().unwrap();
+
+  let mut powers: Vec<RaplPower> = histories
+    .values()
+    .filter_map(|history| history.back().cloned())
+    .collect();
+
+  if powers.is_empty() {
+    return Err(enums::error::BackendError::CpuPowerNotAvailable);
+  }
+
+  powers.sort_by(|a, b| a.zone.cmp(&b.zone));
+  Ok(powers)
+}
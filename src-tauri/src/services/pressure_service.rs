@@ -0,0 +1,29 @@
+use crate::constants::MAX_HISTORY_QUERY_DURATION_SECONDS;
+use crate::enums;
+use crate::models::hardware::{HardwareMonitorState, PressureInfo};
+
+///
+/// Get the latest Pressure Stall Information and load average
+///
+pub fn pressure_info(
+  state: &HardwareMonitorState,
+) -> Result<PressureInfo, enums::error::BackendError> {
+  let history = state.pressure_history.lock().unwrap();
+
+  history
+    .back()
+    .cloned()
+    .ok_or(enums::error::BackendError::PressureInfoNotAvailable)
+}
+
+///
+/// ## Pressure history
+///
+/// (Last `seconds` from newest, max MAX_HISTORY_QUERY_DURATION_SECONDS) collected in reverse order
+///
+pub fn pressure_history(state: &HardwareMonitorState, seconds: u32) -> Vec<PressureInfo> {
+  let history = state.pressure_history.lock().unwrap();
+  let take_n = seconds.min(MAX_HISTORY_QUERY_DURATION_SECONDS) as usize;
+
+  history.iter().rev().take(take_n).cloned().collect()
+}
@@ -12,6 +12,7 @@ const PROCESS_AVG_WINDOW: usize = 5;
 /// - Average the CPU / memory history of the most recent `PROCESS_AVG_WINDOW` samples for each process
 /// - Normalize CPU usage by core count and round to 1 decimal place
 /// - Convert memory from KB history to MB and round to 1 decimal place
+/// - Average GPU usage / GPU memory (MB) the same way (0 for processes without a DRM client)
 ///
 /// Return value: `Vec<ProcessInfo>` (use current value/0 if no history)
 ///
@@ -21,6 +22,8 @@ pub fn collect_process_list(state: &HardwareMonitorState) -> Vec<ProcessInfo> {
   let mut system = state.system.lock().unwrap();
   let process_cpu_histories = state.process_cpu_histories.lock().unwrap();
   let process_memory_histories = state.process_memory_histories.lock().unwrap();
+  let process_gpu_histories = state.process_gpu_histories.lock().unwrap();
+  let process_gpu_memory_histories = state.process_gpu_memory_histories.lock().unwrap();
 
   system.refresh_processes(ProcessesToUpdate::All, true);
   let num_cores = system.cpus().len() as f32;
@@ -62,11 +65,31 @@ pub fn collect_process_list(state: &HardwareMonitorState) -> Vec<ProcessInfo> {
         })
         .unwrap_or_else(|| process.memory() as f32 / 1024.0);
 
+      // GPU usage / GPU memory (MB) recent average
+      let recent_average = |hist: &std::collections::VecDeque<f32>| {
+        let len = hist.len().min(PROCESS_AVG_WINDOW);
+        if len == 0 {
+          return 0.0;
+        }
+        let sum: f32 = hist.iter().rev().take(len).sum();
+        rounding::round1(sum / len as f32)
+      };
+      let gpu_usage = process_gpu_histories
+        .get(&pid)
+        .map(recent_average)
+        .unwrap_or(0.0);
+      let gpu_memory_usage = process_gpu_memory_histories
+        .get(&pid)
+        .map(recent_average)
+        .unwrap_or(0.0);
+
       ProcessInfo {
         pid: pid.as_u32() as i32,
         name: process.name().to_string_lossy().into_owned(),
         cpu_usage,
         memory_usage,
+        gpu_usage,
+        gpu_memory_usage,
       }
     })
     .collect()
@@ -0,0 +1,19 @@
+use crate::enums;
+use crate::models::hardware::NameValue;
+use crate::platform::factory::PlatformFactory;
+
+///
+/// Get list of CPU temperatures
+/// `temperature_unit` assumes user setting (Celsius/Fahrenheit etc.)
+///
+pub async fn fetch_cpu_temperature(
+  temperature_unit: enums::settings::TemperatureUnit,
+) -> Result<Vec<NameValue>, String> {
+  let platform =
+    PlatformFactory::create().map_err(|e| format!("Failed to create platform: {e}"))?;
+
+  platform
+    .get_cpu_temperature(temperature_unit)
+    .await
+    .map_err(|e| format!("Failed to get CPU temperature: {e:?}"))
+}
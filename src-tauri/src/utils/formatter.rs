@@ -4,7 +4,6 @@ use serde::{Deserialize, Serialize};
 use specta::Type;
 use std::fmt;
 
-#[cfg(target_os = "windows")]
 use crate::enums;
 
 #[cfg(target_os = "windows")]
@@ -166,7 +165,6 @@ pub fn format_vendor_name(vendor_id: &str) -> String {
   }
 }
 
-#[cfg(target_os = "windows")]
 pub fn format_temperature(
   current_unit: enums::settings::TemperatureUnit,
   unit: enums::settings::TemperatureUnit,
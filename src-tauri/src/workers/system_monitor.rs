@@ -4,6 +4,8 @@ use crate::{log_internal, log_warn};
 
 pub struct SystemMonitorController {
   handle: tauri::async_runtime::JoinHandle<()>,
+  #[cfg(target_os = "linux")]
+  nvidia_handle: tauri::async_runtime::JoinHandle<()>,
   stop_tx: tokio::sync::watch::Sender<bool>,
 }
 
@@ -23,6 +25,31 @@ impl SystemMonitorController {
   pub fn setup(resources: models::hardware_archive::MonitorResources) -> Self {
     let (tx, mut rx) = tokio::sync::watch::channel(false);
 
+    // `nvidia-smi` can take seconds, so it is polled apart from the main tick
+    #[cfg(target_os = "linux")]
+    let nvidia_handle: tauri::async_runtime::JoinHandle<()> = {
+      let statuses = std::sync::Arc::clone(&resources.nvidia_gpu_statuses);
+      let mut rx = rx.clone();
+
+      tauri::async_runtime::spawn(async move {
+        let mut ticker = tokio::time::interval(tokio::time::Duration::from_secs(
+          SYSTEM_INFO_INIT_INTERVAL,
+        ));
+        ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+
+        loop {
+          tokio::select! {
+            _ = ticker.tick() => monitoring_service::sample_nvidia_gpus(&statuses).await,
+            result = rx.changed() => {
+              if result.is_err() || *rx.borrow() {
+                break;
+              }
+            }
+          }
+        }
+      })
+    };
+
     let handle: tauri::async_runtime::JoinHandle<()> =
       tauri::async_runtime::spawn(async move {
         let mut ticker = tokio::time::interval(tokio::time::Duration::from_secs(
@@ -37,7 +64,16 @@ impl SystemMonitorController {
         }
         #[cfg(target_os = "linux")]
         {
+          monitoring_service::sample_drm_clients(&resources).await;
           monitoring_service::sample_system(&resources);
+          monitoring_service::sample_gpu(&resources);
+          monitoring_service::sample_cpu_temperature(&resources);
+          monitoring_service::sample_network(&resources);
+          monitoring_service::sample_disk(&resources);
+          monitoring_service::sample_pressure(&resources);
+          monitoring_service::sample_battery(&resources);
+          monitoring_service::sample_cpu_power(&resources);
+          monitoring_service::sample_cpu_frequency(&resources);
         }
         #[cfg(target_os = "macos")]
         {
@@ -56,7 +92,16 @@ impl SystemMonitorController {
               }
               #[cfg(target_os = "linux")]
               {
+                monitoring_service::sample_drm_clients(&resources).await;
                 monitoring_service::sample_system(&resources);
+                      monitoring_service::sample_gpu(&resources);
+                monitoring_service::sample_cpu_temperature(&resources);
+                monitoring_service::sample_network(&resources);
+                monitoring_service::sample_disk(&resources);
+                monitoring_service::sample_pressure(&resources);
+                monitoring_service::sample_battery(&resources);
+                monitoring_service::sample_cpu_power(&resources);
+                monitoring_service::sample_cpu_frequency(&resources);
               }
 
               let elapsed = start.elapsed();
@@ -81,11 +126,15 @@ impl SystemMonitorController {
     Self {
       stop_tx: tx,
       handle,
+      #[cfg(target_os = "linux")]
+      nvidia_handle,
     }
   }
 
   pub async fn terminate(self) {
     let _ = self.stop_tx.send(true);
     let _ = self.handle.await;
+    #[cfg(target_os = "linux")]
+    let _ = self.nvidia_handle.await;
   }
 }
@@ -28,7 +28,7 @@ vi.mock("@/rspc/bindings", () => ({
     getCpuUsage: vi.fn(),
     getMemoryUsage: vi.fn(),
     getGpuUsage: vi.fn(),
-    getNvidiaGpuCooler: vi.fn(),
+    getFans: vi.fn(),
     getGpuTemperature: vi.fn(),
   },
 }));
@@ -118,9 +118,24 @@ describe("useHardwareUpdater", () => {
   });
 
   it("gpuFanSpeedAtom is updated when 'gpu', 'fan'", async () => {
-    (commands.getNvidiaGpuCooler as Mock).mockResolvedValue({
+    (commands.getFans as Mock).mockResolvedValue({
       status: "ok",
-      data: [{ name: "test1", value: 100 }],
+      data: [
+        {
+          chip: "amdgpu",
+          label: "test1",
+          rpm: 100,
+          pwmPercent: 40,
+          isGpu: true,
+        },
+        {
+          chip: "nct6798",
+          label: "CPU Fan",
+          rpm: 900,
+          pwmPercent: null,
+          isGpu: false,
+        },
+      ],
     });
 
     const { result } = renderHook(
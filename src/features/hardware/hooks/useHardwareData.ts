@@ -89,6 +89,21 @@ export const useUsageUpdater = (dataType: ChartDataHardwareType) => {
   }, [setHistory, getUsage]);
 };
 
+/**
+ * Fetch GPU fans as `NameValue` (RPM)
+ */
+const getGpuFanSpeeds = async (): Promise<Result<NameValue[], string>> => {
+  const result = await commands.getFans();
+  if (isError(result)) return result;
+
+  return {
+    status: "ok",
+    data: result.data
+      .filter((fan) => fan.isGpu && fan.rpm !== null)
+      .map((fan) => ({ name: fan.label, value: fan.rpm ?? 0 })),
+  };
+};
+
 export const useHardwareUpdater = (
   hardType: Exclude<ChartDataType, "memory">,
   dataType: "temp" | "fan",
@@ -121,7 +136,7 @@ export const useHardwareUpdater = (
     gpu: {
       fan: {
         atom: gpuFanSpeedAtom,
-        action: commands.getNvidiaGpuCooler,
+        action: getGpuFanSpeeds,
       },
       temp: {
         atom: gpuTempAtom,
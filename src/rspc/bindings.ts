@@ -59,6 +59,42 @@ async getHardwareInfo() : Promise<Result<SysInfo, string>> {
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * ## Get PCI device list (vendor / device names, driver and link status)
+ * 
+ */
+async getPciDevices() : Promise<Result<PciDevice[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_pci_devices") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * ## Get SMART / NVMe health of every physical disk
+ * 
+ */
+async getDiskHealth() : Promise<Result<DiskHealth[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_disk_health") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * ## Get physical disks with their partitions, dm / md layers and mount points
+ * 
+ */
+async getBlockDevices() : Promise<Result<BlockDevice[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_block_devices") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 /**
  * ## Get detailed memory information
  * 
@@ -83,10 +119,22 @@ async getMemoryInfoDetail() : Promise<Result<MemoryInfo, string>> {
 async getMemoryUsage() : Promise<number> {
     return await TAURI_INVOKE("get_memory_usage");
 },
+/**
+ * ## Get breakdown of memory usage (cache / buffers / slab / swap ...)
+ * 
+ */
+async getMemoryBreakdown() : Promise<Result<MemoryBreakdown, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_memory_breakdown") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 /**
  * ## Get GPU usage (%)
  * 
- * - param state: `tauri::State<AppState>` Application state
+ * - param state: `tauri::State<HardwareMonitorState>` Hardware monitor state
  * - return: `i32` GPU usage (%)
  * 
  */
@@ -101,6 +149,62 @@ async getGpuUsage() : Promise<Result<number, string>> {
 async getProcessorsUsage() : Promise<number[]> {
     return await TAURI_INVOKE("get_processors_usage");
 },
+/**
+ * ## Get CPU time breakdown (user / system / iowait / steal ...) of all cores and each core
+ * 
+ */
+async getCpuTimeBreakdown() : Promise<Result<CpuTimeBreakdown[], BackendError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_cpu_time_breakdown") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * ## Get CPU time breakdown history
+ * 
+ * - param state: `tauri::State<AppState>` Application state
+ * - param cpu: `String` `cpu` for all cores or `cpuN` for a single core
+ * - param seconds: `u32` Number of seconds to retrieve
+ * 
+ */
+async getCpuTimeBreakdownHistory(cpu: string, seconds: number) : Promise<Result<CpuTimeBreakdown[], BackendError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_cpu_time_breakdown_history", { cpu, seconds }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * ## Get current frequency, governor and throttling status of every core
+ * 
+ */
+async getCpuFrequencies() : Promise<Result<CpuCoreFrequency[], BackendError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_cpu_frequencies") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * ## Get frequency history of a core
+ * 
+ * - param state: `tauri::State<AppState>` Application state
+ * - param core: `u32` Core index (`N` of `cpuN`)
+ * - param seconds: `u32` Number of seconds to retrieve
+ * 
+ */
+async getCpuFrequencyHistory(core: number, seconds: number) : Promise<Result<number[], BackendError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_cpu_frequency_history", { core, seconds }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
 /**
  * ## Get GPU temperature
  * 
@@ -114,12 +218,26 @@ async getGpuTemperature() : Promise<Result<NameValue[], string>> {
 }
 },
 /**
- * ## Get GPU fan speed
+ * ## Get CPU temperature
  * 
  */
-async getNvidiaGpuCooler() : Promise<Result<NameValue[], string>> {
+async getCpuTemperature() : Promise<Result<NameValue[], string>> {
     try {
-    return { status: "ok", data: await TAURI_INVOKE("get_nvidia_gpu_cooler") };
+    return { status: "ok", data: await TAURI_INVOKE("get_cpu_temperature") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * ## Get fan speed
+ * 
+ * - return: `Vec<FanInfo>` Every fan (CPU / chassis / GPU) with RPM and PWM duty
+ * 
+ */
+async getFans() : Promise<Result<FanInfo[], string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_fans") };
 } catch (e) {
     if(e instanceof Error) throw e;
     else return { status: "error", error: e  as any };
@@ -145,6 +263,16 @@ async getCpuUsageHistory(seconds: number) : Promise<number[]> {
 async getMemoryUsageHistory(seconds: number) : Promise<number[]> {
     return await TAURI_INVOKE("get_memory_usage_history", { seconds });
 },
+/**
+ * ## Get swap usage history
+ * 
+ * - param state: `tauri::State<AppState>` Application state
+ * - param seconds: `u32` Number of seconds to retrieve
+ * 
+ */
+async getSwapUsageHistory(seconds: number) : Promise<number[]> {
+    return await TAURI_INVOKE("get_swap_usage_history", { seconds });
+},
 /**
  * ## Get GPU usage history
  * 
@@ -155,6 +283,58 @@ async getMemoryUsageHistory(seconds: number) : Promise<number[]> {
 async getGpuUsageHistory(seconds: number) : Promise<number[]> {
     return await TAURI_INVOKE("get_gpu_usage_history", { seconds });
 },
+/**
+ * ## Get usage, memory and temperature of every GPU
+ * 
+ * Unlike `get_gpu_usage`, every card is reported separately.
+ * 
+ */
+async getGpuUsages() : Promise<Result<GpuUsage[], BackendError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_gpu_usages") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * ## Get clocks, memory and power of every GPU
+ * 
+ */
+async getGpuMetrics() : Promise<Result<GpuMetrics[], BackendError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_gpu_metrics") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * ## Get clocks, memory and power history of a GPU
+ * 
+ * - param state: `tauri::State<AppState>` Application state
+ * - param card: `String` DRM card name (e.g. `card1`)
+ * - param seconds: `u32` Number of seconds to retrieve
+ * 
+ */
+async getGpuMetricsHistory(card: string, seconds: number) : Promise<Result<GpuMetrics[], BackendError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_gpu_metrics_history", { card, seconds }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * ## Get CPU temperature history
+ * 
+ * - param state: `tauri::State<AppState>` Application state
+ * - param seconds: `u32` Number of seconds to retrieve
+ * 
+ */
+async getCpuTemperatureHistory(seconds: number) : Promise<number[]> {
+    return await TAURI_INVOKE("get_cpu_temperature_history", { seconds });
+},
 /**
  * ## Get network information
  * 
@@ -167,6 +347,141 @@ async getNetworkInfo() : Promise<Result<NetworkInfo[], BackendError>> {
     else return { status: "error", error: e  as any };
 }
 },
+/**
+ * ## Get network throughput of every interface
+ * 
+ */
+async getNetworkUsage() : Promise<Result<NetworkUsage[], BackendError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_network_usage") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * ## Get network throughput history
+ * 
+ * - param state: `tauri::State<AppState>` Application state
+ * - param interface: `String` Interface name (e.g. `eth0`)
+ * - param seconds: `u32` Number of seconds to retrieve
+ * 
+ */
+async getNetworkUsageHistory(interface: string, seconds: number) : Promise<Result<NetworkUsage[], BackendError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_network_usage_history", { interface, seconds }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * ## Get I/O activity of every block device
+ * 
+ */
+async getDiskUsage() : Promise<Result<DiskUsage[], BackendError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_disk_usage") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * ## Get I/O activity history of a block device
+ * 
+ * - param state: `tauri::State<AppState>` Application state
+ * - param device: `String` Block device name (e.g. `nvme0n1`)
+ * - param seconds: `u32` Number of seconds to retrieve
+ * 
+ */
+async getDiskUsageHistory(device: string, seconds: number) : Promise<Result<DiskUsage[], BackendError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_disk_usage_history", { device, seconds }) };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * ## Get Pressure Stall Information and load average
+ * 
+ */
+async getPressureInfo() : Promise<Result<PressureInfo, BackendError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_pressure_info") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * ## Get Pressure Stall Information and load average history
+ * 
+ * - param state: `tauri::State<AppState>` Application state
+ * - param seconds: `u32` Number of seconds to retrieve
+ * 
+ */
+async getPressureHistory(seconds: number) : Promise<PressureInfo[]> {
+    return await TAURI_INVOKE("get_pressure_history", { seconds });
+},
+/**
+ * ## Get AC adapter and battery status
+ * 
+ */
+async getPowerSupplyInfo() : Promise<Result<PowerSupplyInfo, string>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_power_supply_info") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * ## Get battery level history
+ * 
+ * - param state: `tauri::State<AppState>` Application state
+ * - param seconds: `u32` Number of seconds to retrieve
+ * 
+ */
+async getBatteryHistory(seconds: number) : Promise<number[]> {
+    return await TAURI_INVOKE("get_battery_history", { seconds });
+},
+/**
+ * ## Get battery charge (+) / discharge (-) rate history in watts
+ * 
+ * - param state: `tauri::State<AppState>` Application state
+ * - param seconds: `u32` Number of seconds to retrieve
+ * 
+ */
+async getBatteryPowerHistory(seconds: number) : Promise<number[]> {
+    return await TAURI_INVOKE("get_battery_power_history", { seconds });
+},
+/**
+ * ## Get power draw of every RAPL zone (package / core / uncore / dram)
+ * 
+ * `energy_uj` is only readable by root since Linux 5.10, so this returns
+ * `cpuPowerNotAvailable` when the app runs unprivileged (the reason is logged once).
+ * 
+ */
+async getCpuPower() : Promise<Result<RaplPower[], BackendError>> {
+    try {
+    return { status: "ok", data: await TAURI_INVOKE("get_cpu_power") };
+} catch (e) {
+    if(e instanceof Error) throw e;
+    else return { status: "error", error: e  as any };
+}
+},
+/**
+ * ## Get CPU package power history in watts
+ * 
+ * - param state: `tauri::State<AppState>` Application state
+ * - param seconds: `u32` Number of seconds to retrieve
+ * 
+ */
+async getCpuPowerHistory(seconds: number) : Promise<number[]> {
+    return await TAURI_INVOKE("get_cpu_power_history", { seconds });
+},
 async getSettings() : Promise<Result<ClientSettings, string>> {
     try {
     return { status: "ok", data: await TAURI_INVOKE("get_settings") };
@@ -472,13 +787,97 @@ async restartApp() : Promise<void> {
 
 /** user-defined types **/
 
-export type BackendError = "cpuInfoNotAvailable" | "storageInfoNotAvailable" | "memoryInfoNotAvailable" | "graphicInfoNotAvailable" | "networkInfoNotAvailable" | "networkUsageNotAvailable" | "unexpectedError"
+export type BackendError = "cpuInfoNotAvailable" | "storageInfoNotAvailable" | "memoryInfoNotAvailable" | "graphicInfoNotAvailable" | "networkInfoNotAvailable" | "networkUsageNotAvailable" | "diskUsageNotAvailable" | "cpuTimeNotAvailable" | "pressureInfoNotAvailable" | "cpuPowerNotAvailable" | "cpuFrequencyNotAvailable" | "gpuMetricsNotAvailable" | "unexpectedError"
 /**
  * - `file_id` : Image file ID
  * - `image_data` : Base64 string of image data
  * 
  */
 export type BackgroundImage = { fileId: string; imageData: string }
+/**
+ * Status of a battery (`/sys/class/power_supply/BAT*`)
+ * 
+ */
+export type BatteryInfo = { 
+/**
+ * e.g. `BAT0`
+ */
+name: string; 
+/**
+ * `Charging` / `Discharging` / `Full` / `Not charging` / `Unknown`
+ */
+status: string; percentage: number | null; 
+/**
+ * Positive while charging, negative while discharging (W)
+ */
+powerWatts: number | null; 
+/**
+ * Current voltage (V)
+ */
+voltage: number | null; cycleCount: number | null; energyFullWh: number | null; energyFullDesignWh: number | null; 
+/**
+ * `energy_full_wh` / `energy_full_design_wh` (%)
+ */
+health: number | null; 
+/**
+ * Only reported while discharging
+ */
+timeToEmptyMinutes: number | null }
+/**
+ * A node of the block device tree (disk -> partitions -> dm / md layers)
+ * 
+ */
+export type BlockDevice = { 
+/**
+ * Kernel name (e.g. `nvme0n1`, `nvme0n1p2`, `dm-0`)
+ */
+name: string; kind: BlockDeviceKind; 
+/**
+ * Device mapper name (e.g. `cryptroot`, `vg0-root`) or md RAID level
+ */
+label: string | null; 
+/**
+ * Disk model (disks only)
+ */
+model: string | null; size: number; sizeUnit: SizeUnit; 
+/**
+ * `None` for partitions, which share the queue of their disk
+ */
+rotational: boolean | null; 
+/**
+ * Active I/O scheduler (e.g. `mq-deadline`, `none`)
+ */
+scheduler: string | null; mountPoints: string[]; fileSystem: string | null; 
+/**
+ * Partitions, then the dm / md devices stacked on this device
+ */
+children: BlockDevice[] }
+/**
+ * Kind of a node in the block device tree
+ * 
+ */
+export type BlockDeviceKind = "disk" | "partition" | 
+/**
+ * Device mapper (LVM, LUKS ...)
+ */
+"deviceMapper" | 
+/**
+ * Software RAID (md)
+ */
+"raid" | "other"
+/**
+ * Motherboard, firmware and chassis information (DMI / SMBIOS)
+ * 
+ */
+export type BoardInfo = { boardVendor: string | null; boardName: string | null; boardVersion: string | null; biosVendor: string | null; biosVersion: string | null; 
+/**
+ * As reported by the firmware (usually `MM/DD/YYYY`)
+ */
+biosDate: string | null; systemVendor: string | null; productName: string | null; 
+/**
+ * e.g. `Desktop`, `Laptop`, `Rack Mount Chassis`
+ */
+chassisType: string | null }
 export type BurnInShiftMode = "jump" | "drift"
 export type BurnInShiftOptions = { 
 /**
@@ -499,11 +898,234 @@ idleThresholdMs: number | null;
 driftDurationSec: number | null }
 export type BurnInShiftPreset = "gentle" | "balanced" | "aggressive"
 export type ClientSettings = { version: string; language: string; theme: Theme; displayTargets: HardwareType[]; graphSize: GraphSize; lineGraphType: LineGraphType; lineGraphBorder: boolean; lineGraphFill: boolean; lineGraphColor: LineGraphColorStringSettings; lineGraphMix: boolean; lineGraphShowLegend: boolean; lineGraphShowScale: boolean; lineGraphShowTooltip: boolean; backgroundImgOpacity: number; selectedBackgroundImg: string | null; temperatureUnit: TemperatureUnit; hardwareArchive: HardwareArchiveSettings; burnInShift: boolean; burnInShiftMode: BurnInShiftMode; burnInShiftPreset: BurnInShiftPreset; burnInShiftIdleOnly: boolean; burnInShiftOptions: BurnInShiftOptions | null }
-export type CpuInfo = { name: string; vendor: string; coreCount: number; clock: number; clockUnit: string; cpuName: string }
+export type CpuCache = { level: number; 
+/**
+ * `Data` / `Instruction` / `Unified`
+ */
+cacheType: string; 
+/**
+ * Size of a single instance (KiB)
+ */
+sizeKb: number; 
+/**
+ * Number of distinct instances across the system
+ */
+instances: number; 
+/**
+ * Logical CPUs sharing one instance
+ */
+sharedCpuCount: number }
+/**
+ * Frequency scaling status of a core (`/sys/devices/system/cpu/cpuN`)
+ * 
+ */
+export type CpuCoreFrequency = { core: number; currentMhz: number; 
+/**
+ * Scaling limits set by the governor / policy
+ */
+minMhz: number | null; maxMhz: number | null; 
+/**
+ * e.g. `performance`, `powersave`, `schedutil`
+ */
+governor: string | null; 
+/**
+ * Thermal throttling events since boot (Intel `thermal_throttle`)
+ */
+coreThrottleCount: number | null; packageThrottleCount: number | null; 
+/**
+ * Throttle events over the last `CPU_THROTTLE_WINDOW_SECONDS`
+ */
+throttleEvents: number; 
+/**
+ * `throttle_events` is non-zero
+ */
+isThrottled: boolean }
+export type CpuCoreSet = { 
+/**
+ * `Performance` / `Efficiency`
+ */
+kind: string; 
+/**
+ * Kernel CPU list format (e.g. `0-15`)
+ */
+cpuList: string; logicalCores: number }
+export type CpuInfo = { name: string; vendor: string; coreCount: number; clock: number; clockUnit: string; cpuName: string; 
+/**
+ * Detailed layout (Linux only)
+ */
+topology: CpuTopology | null }
+/**
+ * Share of CPU time (%) spent in each state since the previous sample
+ * 
+ */
+export type CpuTimeBreakdown = { 
+/**
+ * `cpu` for the aggregate of all cores, `cpuN` for each core
+ */
+cpu: string; user: number; nice: number; system: number; idle: number; 
+/**
+ * Idle while waiting for I/O to complete
+ */
+iowait: number; irq: number; softirq: number; 
+/**
+ * Time stolen by the hypervisor for other guests
+ */
+steal: number }
+export type CpuTopology = { sockets: number; physicalCores: number; logicalCores: number; smtEnabled: boolean; 
+/**
+ * Hybrid core sets (P-cores / E-cores), empty on non-hybrid CPUs
+ */
+coreSets: CpuCoreSet[]; caches: CpuCache[]; numaNodes: NumaNode[]; 
+/**
+ * Notable ISA extensions (e.g. `avx2`, `avx512f`, `sve`)
+ */
+isaFlags: string[] }
+/**
+ * Health of a physical disk from `smartctl --json` and hwmon
+ * 
+ */
+export type DiskHealth = { 
+/**
+ * Physical block device (e.g. `nvme0n1`, `sda`)
+ */
+device: string; model: string | null; serial: string | null; firmware: string | null; 
+/**
+ * Mount points of the partitions and dm / md devices on this disk
+ */
+mountPoints: string[]; 
+/**
+ * Overall SMART self-assessment
+ */
+smartPassed: boolean | null; powerOnHours: number | null; 
+/**
+ * Celsius
+ */
+temperature: number | null; 
+/**
+ * ATA attribute 5 (Reallocated Sector Count)
+ */
+reallocatedSectors: number | null; 
+/**
+ * ATA attribute 197 (Current Pending Sector Count)
+ */
+pendingSectors: number | null; 
+/**
+ * ATA attribute 198 (Offline Uncorrectable Sector Count)
+ */
+uncorrectableSectors: number | null; 
+/**
+ * NVMe `Percentage Used` of the rated endurance (may exceed 100)
+ */
+percentageUsed: number | null; 
+/**
+ * NVMe `Media and Data Integrity Errors`
+ */
+mediaErrors: number | null; 
+/**
+ * NVMe `Critical Warning` bits
+ */
+criticalWarning: number | null; status: DiskHealthStatus }
+/**
+ * Health verdict of a physical disk (SMART / NVMe health log)
+ * 
+ */
+export type DiskHealthStatus = "good" | 
+/**
+ * Degrading (reallocated / pending sectors, media errors, worn out soon)
+ */
+"warning" | 
+/**
+ * SMART failed, NVMe critical warning, or rated endurance exceeded
+ */
+"critical" | 
+/**
+ * SMART data is not readable
+ */
+"unknown"
 export type DiskKind = "hdd" | "ssd" | "other"
+/**
+ * Per-second I/O activity of a block device
+ * 
+ */
+export type DiskUsage = { 
+/**
+ * Block device name (e.g. `nvme0n1`, `sda1`, `dm-0`)
+ */
+device: string; readBytesPerSec: number; writeBytesPerSec: number; readIops: number; writeIops: number; 
+/**
+ * Average time per completed request (ms)
+ */
+avgLatencyMs: number; 
+/**
+ * Percentage of time the device had I/O in flight
+ */
+utilization: number }
 export type DownloadEvent = { event: "started"; data: { contentLength: string | null } } | { event: "progress"; data: { chunkLength: string } } | { event: "finished" }
+export type FanInfo = { 
+/**
+ * Sensor chip the fan is attached to (e.g. `nct6798`, `amdgpu`)
+ */
+chip: string; 
+/**
+ * Fan label (e.g. `CPU Fan`, `fan1`)
+ */
+label: string; 
+/**
+ * Current speed (RPM)
+ */
+rpm: number | null; 
+/**
+ * PWM duty cycle (%)
+ */
+pwmPercent: number | null; 
+/**
+ * Whether the fan is controlled by a GPU
+ */
+isGpu: boolean }
+/**
+ * Clocks, memory and power of a GPU read from sysfs
+ * 
+ */
+export type GpuMetrics = { 
+/**
+ * e.g. `card1`
+ */
+card: string; coreClockMhz: number | null; memoryClockMhz: number | null; vramUsedMb: number | null; vramTotalMb: number | null; 
+/**
+ * System memory mapped by the GPU (Graphics Translation Table)
+ */
+gttUsedMb: number | null; gttTotalMb: number | null; powerWatts: number | null; powerCapWatts: number | null; 
+/**
+ * Fan duty cycle (%) reported by the driver (NVIDIA)
+ */
+fanSpeedPercent: number | null }
+/**
+ * Latest usage, memory and temperature of a single GPU
+ * 
+ */
+export type GpuUsage = { 
+/**
+ * Same as `GraphicInfo.id` (e.g. `card1`)
+ */
+id: string; 
+/**
+ * Usage (%)
+ */
+usage: number | null; memoryUsedMb: number | null; memoryTotalMb: number | null; 
+/**
+ * Celsius
+ */
+temperature: number | null }
 export type GraphSize = "sm" | "md" | "lg" | "xl" | "2xl"
-export type GraphicInfo = { id: string; name: string; vendorName: string; clock: number; memorySize: string; memorySizeDedicated: string }
+export type GraphicInfo = { id: string; name: string; vendorName: string; clock: number; memorySize: string; memorySizeDedicated: string; 
+/**
+ * `None` for integrated GPUs and on platforms without sysfs
+ */
+pcieLink?: PcieLinkStatus | null; 
+/**
+ * Live clocks / memory / power (Linux only)
+ */
+metrics?: GpuMetrics | null }
 export type HardwareArchiveSettings = { enabled: boolean; scheduledDataDeletion: boolean; refreshIntervalDays: number }
 export type HardwareType = "cpu" | "memory" | "gpu"
 /**
@@ -512,9 +1134,195 @@ export type HardwareType = "cpu" | "memory" | "gpu"
  */
 export type LineGraphColorStringSettings = { cpu: string; memory: string; gpu: string }
 export type LineGraphType = "default" | "step" | "linear" | "basis"
-export type MemoryInfo = { size: string; clock: number; clockUnit: string; memoryCount: number; totalSlots: number; memoryType: string; isDetailed: boolean }
+/**
+ * `/proc/loadavg`
+ * 
+ */
+export type LoadAverage = { one: number; five: number; fifteen: number; 
+/**
+ * Currently runnable scheduling entities
+ */
+running: number; 
+/**
+ * Scheduling entities that exist on the system
+ */
+total: number }
+/**
+ * Breakdown of system memory (`/proc/meminfo`)
+ * 
+ * All sizes are in MiB
+ * 
+ */
+export type MemoryBreakdown = { total: number; free: number; 
+/**
+ * Estimate of memory available for new workloads without swapping
+ */
+available: number; buffers: number; 
+/**
+ * Page cache (excluding swap cache)
+ */
+cached: number; swapCached: number; 
+/**
+ * Waiting to be written back to disk
+ */
+dirty: number; 
+/**
+ * Actively being written back to disk
+ */
+writeback: number; 
+/**
+ * Kernel data structures cache
+ */
+slab: number; 
+/**
+ * Part of `slab` that can be reclaimed under pressure
+ */
+slabReclaimable: number; 
+/**
+ * tmpfs and shared memory
+ */
+shmem: number; swapTotal: number; swapFree: number; 
+/**
+ * Memory the kernel has promised to allocations (`Committed_AS`)
+ */
+committed: number; commitLimit: number; hugepagesTotal: number; hugepagesFree: number; hugepageSize: number }
+export type MemoryInfo = { size: string; clock: number; clockUnit: string; memoryCount: number; totalSlots: number; memoryType: string; isDetailed: boolean; 
+/**
+ * Per-slot details (empty when only the aggregate is available)
+ */
+modules?: MemoryModule[] }
+/**
+ * A memory slot (SMBIOS Memory Device, Type 17)
+ * 
+ */
+export type MemoryModule = { 
+/**
+ * Slot name (e.g. `DIMM_A1`, `ChannelA-DIMM0`)
+ */
+locator: string; bankLocator: string | null; 
+/**
+ * No module is installed in the slot
+ */
+isEmpty: boolean; sizeMb: number | null; 
+/**
+ * e.g. `DDR4`, `DDR5`, `LPDDR5`
+ */
+memoryType: string | null; 
+/**
+ * Maximum speed supported by the module (MT/s)
+ */
+ratedSpeedMts: number | null; 
+/**
+ * Speed the memory controller actually runs at (MT/s)
+ */
+configuredSpeedMts: number | null; manufacturer: string | null; partNumber: string | null; serialNumber: string | null; rank: number | null }
 export type NameValue = { name: string; value: number }
 export type NetworkInfo = { description: string | null; macAddress: string | null; ipv4: string[]; ipv6: string[]; linkLocalIpv6: string[]; ipSubnet: string[]; defaultIpv4Gateway: string[]; defaultIpv6Gateway: string[] }
+/**
+ * Per-second network throughput of an interface
+ * 
+ */
+export type NetworkUsage = { interface: string; rxBytesPerSec: number; txBytesPerSec: number; rxPacketsPerSec: number; txPacketsPerSec: number; rxErrorsPerSec: number; txErrorsPerSec: number; rxDroppedPerSec: number; txDroppedPerSec: number }
+export type NumaNode = { id: number; 
+/**
+ * Kernel CPU list format (e.g. `0-7,16-23`)
+ */
+cpuList: string; logicalCores: number }
+/**
+ * PCI device enumerated from `/sys/bus/pci/devices`
+ * 
+ * IDs are lowercase hex without `0x` (e.g. `10de`). Names are resolved from
+ * `pci.ids` and are `None` when the database is not installed.
+ * 
+ */
+export type PciDevice = { 
+/**
+ * e.g. `0000:01:00.0`
+ */
+address: string; vendorId: string; deviceId: string; subsystemVendorId: string | null; subsystemDeviceId: string | null; 
+/**
+ * Class / subclass / prog-if (e.g. `030000`)
+ */
+classCode: string; className: string | null; vendorName: string | null; deviceName: string | null; subsystemName: string | null; 
+/**
+ * Bound kernel driver (e.g. `amdgpu`, `nvme`)
+ */
+driver: string | null; 
+/**
+ * PCIe link speed in GT/s
+ */
+currentLinkSpeed: number | null; maxLinkSpeed: number | null; currentLinkWidth: number | null; maxLinkWidth: number | null }
+/**
+ * Negotiated PCIe link against what the device supports
+ * 
+ */
+export type PcieLinkStatus = { 
+/**
+ * PCIe generation (1 = 2.5 GT/s ... 6 = 64 GT/s)
+ */
+currentGeneration: number | null; maxGeneration: number | null; currentWidth: number | null; maxWidth: number | null; 
+/**
+ * Maximum link of the port the device is plugged into (root port / switch)
+ */
+slotMaxGeneration: number | null; slotMaxWidth: number | null; 
+/**
+ * The slot supports fewer lanes or a lower generation than the device
+ * (e.g. x16 card in an x4 slot)
+ * 
+ * Derived from the maximum links of both ends, so it does not change at runtime.
+ */
+isSlotLimited: boolean; 
+/**
+ * Currently running with fewer lanes than the device supports
+ */
+isWidthDegraded: boolean; 
+/**
+ * Currently running below the speed the device supports
+ * 
+ * GPUs lower the link speed while idle to save power, so this is transient
+ * and only meaningful under load.
+ */
+isSpeedDegraded: boolean }
+export type PowerSupplyInfo = { 
+/**
+ * `None` when the system has no AC adapter (e.g. desktop without power_supply class)
+ */
+acOnline: boolean | null; batteries: BatteryInfo[] }
+/**
+ * Resource contention of the system
+ * 
+ * PSI fields are `None` when the kernel is built without `CONFIG_PSI`
+ * or booted with `psi=0`.
+ * 
+ */
+export type PressureInfo = { cpu: PressureResource | null; memory: PressureResource | null; io: PressureResource | null; loadAverage: LoadAverage }
+/**
+ * Pressure Stall Information of a resource (cpu / memory / io)
+ * 
+ */
+export type PressureResource = { 
+/**
+ * At least one task was stalled on the resource
+ */
+some: PressureStall; 
+/**
+ * All non-idle tasks were stalled at the same time
+ * (not reported for cpu before Linux 5.13)
+ */
+full: PressureStall | null }
+/**
+ * A `some` / `full` line of `/proc/pressure/*`
+ * 
+ */
+export type PressureStall = { 
+/**
+ * Share of time (%) stalled over the last 10 / 60 / 300 seconds
+ */
+avg10: number; avg60: number; avg300: number; 
+/**
+ * Cumulative stall time (ms)
+ */
+totalMs: number }
 export type ProcessInfo = { 
 /**
  * Process ID
@@ -531,10 +1339,35 @@ cpuUsage: number;
 /**
  * Memory usage
  */
-memoryUsage: number }
+memoryUsage: number; 
+/**
+ * GPU usage (busiest engine, DRM fdinfo)
+ */
+gpuUsage: number; 
+/**
+ * Dedicated GPU memory usage (MB)
+ */
+gpuMemoryUsage: number }
+/**
+ * Power draw of a RAPL zone
+ * 
+ */
+export type RaplPower = { 
+/**
+ * Zone id (e.g. `intel-rapl:0`, `intel-rapl:0:0`)
+ */
+zone: string; 
+/**
+ * Domain name (e.g. `package-0`, `core`, `dram`)
+ */
+name: string; watts: number }
 export type SizeUnit = "B" | "KB" | "MB" | "GB"
-export type StorageInfo = { name: string; size: number; sizeUnit: SizeUnit; free: number; freeUnit: SizeUnit; storageType: DiskKind; fileSystem: string }
-export type SysInfo = { cpu: CpuInfo | null; memory: MemoryInfo | null; gpus: GraphicInfo[] | null; storage: StorageInfo[] }
+export type StorageInfo = { name: string; size: number; sizeUnit: SizeUnit; free: number; freeUnit: SizeUnit; storageType: DiskKind; fileSystem: string; 
+/**
+ * PCIe link of the NVMe controller (Linux only)
+ */
+pcieLink: PcieLinkStatus | null }
+export type SysInfo = { cpu: CpuInfo | null; memory: MemoryInfo | null; gpus: GraphicInfo[] | null; storage: StorageInfo[]; board: BoardInfo | null }
 export type TAURI_CHANNEL<TSend> = null
 export type TemperatureUnit = "C" | "F"
 export type Theme = "system" | "light" | "dark" | "darkPlus" | "ocean" | "grove" | "sunset" | "nebula" | "orbit" | "cappuccino" | "espresso"